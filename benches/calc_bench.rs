@@ -1,4 +1,5 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use hd_cli::astro_calc::{CalcOptions, HdPlanet};
 use hd_cli::calc::build_chart;
 
 fn bench_build_chart_basic(c: &mut Criterion) {
@@ -10,9 +11,12 @@ fn bench_build_chart_basic(c: &mut Criterion) {
                 black_box(15),
                 black_box(14),
                 black_box(30),
+                black_box(0),
                 black_box(3.0),
+                black_box(&HdPlanet::all()),
                 black_box(false), // short mode
                 black_box("ru"),
+                black_box(&CalcOptions::default()),
             )
         })
     });
@@ -27,9 +31,12 @@ fn bench_build_chart_full(c: &mut Criterion) {
                 black_box(15),
                 black_box(14),
                 black_box(30),
+                black_box(0),
                 black_box(3.0),
+                black_box(&HdPlanet::all()),
                 black_box(true), // full descriptions
                 black_box("ru"),
+                black_box(&CalcOptions::default()),
             )
         })
     });
@@ -8,6 +8,8 @@ const FILES: &[&str] = &[
     "gates_database_en.json",
     "gates_database_es.json",
 ];
+/// Databases that may not be published yet; the app falls back to English without them
+const OPTIONAL_FILES: &[&str] = &["gates_database_zh.json"];
 
 fn main() {
     let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR not set");
@@ -58,6 +60,44 @@ fn main() {
         println!("cargo:rerun-if-changed={}", dest.display());
     }
 
+    // Optional databases are copied to OUT_DIR (empty when unavailable) so they can be
+    // embedded unconditionally
+    let out_dir = std::env::var("OUT_DIR").expect("OUT_DIR not set");
+    for file_name in OPTIONAL_FILES {
+        let dest = data_dir.join(file_name);
+        if !dest.exists() {
+            let url = format!("{}{}", GITHUB_RAW_BASE, file_name);
+            let downloaded = Command::new("curl")
+                .args([
+                    "-fsSL",
+                    "--connect-timeout", "15",
+                    "--max-time", "60",
+                    "-o", dest.to_str().expect("Path transition failed"),
+                    &url,
+                ])
+                .status()
+                .map(|status| status.success())
+                .unwrap_or(false);
+            if !downloaded {
+                let _ = fs::remove_file(&dest);
+                println!("cargo:warning={} not available, falling back to English data", file_name);
+            }
+        }
+        let content = fs::read_to_string(&dest).unwrap_or_default();
+        fs::write(Path::new(&out_dir).join(file_name), content)
+            .expect("Failed to write optional database to OUT_DIR");
+    }
+    // Picks up optional databases added to data/ later
+    println!("cargo:rerun-if-changed={}", data_dir.display());
+
+    // libswe location for the `swisseph` feature (default linker paths otherwise)
+    if std::env::var_os("CARGO_FEATURE_SWISSEPH").is_some() {
+        println!("cargo:rerun-if-env-changed=SWISSEPH_LIB_DIR");
+        if let Ok(dir) = std::env::var("SWISSEPH_LIB_DIR") {
+            println!("cargo:rustc-link-search=native={}", dir);
+        }
+    }
+
     // Re-run build.rs if build.rs changes
     println!("cargo:rerun-if-changed=build.rs");
 }
@@ -1,3 +1,4 @@
+use hd_cli::astro_calc::{CalcOptions, HdPlanet};
 use hd_cli::calc::build_chart;
 use rayon::prelude::*;
 use std::time::Instant;
@@ -13,9 +14,11 @@ fn main() {
         .into_par_iter()
         .map(|_| {
             build_chart(
-                1990, 5, 15, 14, 30, 3.0,
+                1990, 5, 15, 14, 30, 0, 3.0,
+                &HdPlanet::all(),
                 false, // short mode (faster)
                 "ru",
+                &CalcOptions::default(),
             )
         })
         .collect();
@@ -22,7 +22,7 @@ fn month_from_u8(m: u8) -> time::Month {
 }
 
 /// HD Planet names
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, clap::ValueEnum)]
 pub enum HdPlanet {
     Sun,
     Earth,
@@ -37,6 +37,29 @@ pub enum HdPlanet {
     Uranus,
     Neptune,
     Pluto,
+    /// Optional bodies (`--extra-planets`): shown with the chart, define no gates
+    #[value(skip)]
+    Chiron,
+    #[value(skip)]
+    Lilith,
+}
+
+/// Optional body added to the chart tables with `--extra-planets`
+#[derive(Debug, Clone, Copy, PartialEq, clap::ValueEnum)]
+pub enum ExtraPlanet {
+    /// Chiron, from its mean orbit (about a degree of accuracy)
+    Chiron,
+    /// Black Moon Lilith: the mean lunar apogee
+    Lilith,
+}
+
+impl ExtraPlanet {
+    pub fn planet(&self) -> HdPlanet {
+        match self {
+            ExtraPlanet::Chiron => HdPlanet::Chiron,
+            ExtraPlanet::Lilith => HdPlanet::Lilith,
+        }
+    }
 }
 
 impl HdPlanet {
@@ -55,6 +78,8 @@ impl HdPlanet {
             HdPlanet::Uranus => "♅",
             HdPlanet::Neptune => "♆",
             HdPlanet::Pluto => "♇",
+            HdPlanet::Chiron => "⚷",
+            HdPlanet::Lilith => "⚸",
         }.to_string()
     }
 
@@ -73,11 +98,38 @@ impl HdPlanet {
             HdPlanet::Uranus => rust_i18n::t!("planet.Uranus").to_string(),
             HdPlanet::Neptune => rust_i18n::t!("planet.Neptune").to_string(),
             HdPlanet::Pluto => rust_i18n::t!("planet.Pluto").to_string(),
+            HdPlanet::Chiron => rust_i18n::t!("planet.Chiron").to_string(),
+            HdPlanet::Lilith => rust_i18n::t!("planet.Lilith").to_string(),
         }
     }
 
+    /// Stable key used in database files and locale entries, e.g. "NorthNode"
+    pub fn key(&self) -> &'static str {
+        match self {
+            HdPlanet::Sun => "Sun",
+            HdPlanet::Earth => "Earth",
+            HdPlanet::Moon => "Moon",
+            HdPlanet::NorthNode => "NorthNode",
+            HdPlanet::SouthNode => "SouthNode",
+            HdPlanet::Mercury => "Mercury",
+            HdPlanet::Venus => "Venus",
+            HdPlanet::Mars => "Mars",
+            HdPlanet::Jupiter => "Jupiter",
+            HdPlanet::Saturn => "Saturn",
+            HdPlanet::Uranus => "Uranus",
+            HdPlanet::Neptune => "Neptune",
+            HdPlanet::Pluto => "Pluto",
+            HdPlanet::Chiron => "Chiron",
+            HdPlanet::Lilith => "Lilith",
+        }
+    }
+
+    /// Optional body outside the 13 HD activations (never defines gates or channels)
+    pub fn is_extra(&self) -> bool {
+        matches!(self, HdPlanet::Chiron | HdPlanet::Lilith)
+    }
+
     /// All planets in HD order
-    #[allow(dead_code)]
     pub fn all() -> Vec<HdPlanet> {
         vec![
             HdPlanet::Sun,
@@ -97,96 +149,90 @@ impl HdPlanet {
     }
 }
 
-/// Calculate Julian Day from date, time and UTC offset
-pub fn calc_julian_day(year: i32, month: u8, day: u8, hour: u8, min: u8, utc_offset: f64) -> f64 {
+/// Calendar of the dates given as input
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum Calendar {
+    /// Julian before the Gregorian reform (1582-10-15), Gregorian from then on
+    #[default]
+    Auto,
+    /// Julian calendar for every date
+    Julian,
+    /// Proleptic Gregorian calendar for every date
+    Gregorian,
+}
+
+impl Calendar {
+    pub fn key(&self) -> &'static str {
+        match self {
+            Calendar::Auto => "auto",
+            Calendar::Julian => "julian",
+            Calendar::Gregorian => "gregorian",
+        }
+    }
+
+    /// Whether a calendar date is read as a Julian calendar date
+    pub fn is_julian(&self, year: i32, month: u8, day: u8) -> bool {
+        match self {
+            Calendar::Auto => (year, month, day) < GREGORIAN_REFORM,
+            Calendar::Julian => true,
+            Calendar::Gregorian => false,
+        }
+    }
+}
+
+/// First day of the Gregorian calendar (the day after Julian 1582-10-04)
+const GREGORIAN_REFORM: (i32, u8, u8) = (1582, 10, 15);
+
+/// Calculate Julian Day from a (year, month, day) date read in `calendar`,
+/// an (hour, minute, second) time and the UTC offset
+pub fn calc_julian_day(date: (i32, u8, u8), time: (u8, u8, u8), utc_offset: f64, calendar: Calendar) -> f64 {
+    let ((year, month, day), (hour, min, sec)) = (date, time);
     // Convert to UTC
-    let total_hours = hour as f64 + min as f64 / 60.0 - utc_offset;
+    let total_hours = hour as f64 + min as f64 / 60.0 + sec as f64 / 3600.0 - utc_offset;
+    let julian = calendar.is_julian(year, month, day);
 
+    // Day adjustment when crossing midnight
+    let ((adj_year, adj_month, adj_day), hours) = if total_hours < 0.0 {
+        (prev_day(year, month, day, julian), total_hours + 24.0)
+    } else if total_hours >= 24.0 {
+        (next_day(year, month, day, julian), total_hours - 24.0)
+    } else {
+        ((year, month, day), total_hours)
+    };
+
+    let hr = hours.floor();
+    let minutes = (hours - hr) * 60.0;
     let day_of_month = time::DayOfMonth {
-        day: day,
-        hr: total_hours.floor() as u8,
-        min: ((total_hours.fract().abs()) * 60.0).floor() as u8,
-        sec: 0.0,
+        day: adj_day,
+        hr: hr as u8,
+        min: minutes.floor() as u8,
+        sec: minutes.fract() * 60.0,
         time_zone: 0.0,
     };
 
-    // Day adjustment when crossing midnight
-    let mut adj_year = year;
-    let mut adj_month = month;
-
-
-    if total_hours < 0.0 {
-        // Previous day
-        let (y, m, d) = prev_day(year, month, day);
-        adj_year = y;
-        adj_month = m;
-        let adj_day = d;
-
-        let corrected_hours = total_hours + 24.0;
-        let day_of_month = time::DayOfMonth {
-            day: adj_day,
-            hr: corrected_hours.floor() as u8,
-            min: ((corrected_hours.fract()) * 60.0).floor() as u8,
-            sec: 0.0,
-            time_zone: 0.0,
-        };
-
-        let date = time::Date {
-            year: adj_year as i16,
-            month: month_from_u8(adj_month),
-            decimal_day: time::decimal_day(&day_of_month),
-            cal_type: time::CalType::Gregorian,
-        };
-        return time::julian_day(&date);
-    }
-
-    if total_hours >= 24.0 {
-        let (y, m, d) = next_day(year, month, day);
-        adj_year = y;
-        adj_month = m;
-        let adj_day = d;
-
-        let corrected_hours = total_hours - 24.0;
-        let day_of_month = time::DayOfMonth {
-            day: adj_day,
-            hr: corrected_hours.floor() as u8,
-            min: ((corrected_hours.fract()) * 60.0).floor() as u8,
-            sec: 0.0,
-            time_zone: 0.0,
-        };
-
-        let date = time::Date {
-            year: adj_year as i16,
-            month: month_from_u8(adj_month),
-            decimal_day: time::decimal_day(&day_of_month),
-            cal_type: time::CalType::Gregorian,
-        };
-        return time::julian_day(&date);
-    }
-
     let date = time::Date {
         year: adj_year as i16,
         month: month_from_u8(adj_month),
         decimal_day: time::decimal_day(&day_of_month),
-        cal_type: time::CalType::Gregorian,
+        cal_type: if julian { time::CalType::Julian } else { time::CalType::Gregorian },
     };
     time::julian_day(&date)
 }
 
-fn prev_day(year: i32, month: u8, day: u8) -> (i32, u8, u8) {
+fn prev_day(year: i32, month: u8, day: u8, julian: bool) -> (i32, u8, u8) {
     if day > 1 {
         (year, month, day - 1)
     } else if month > 1 {
         let prev_month = month - 1;
-        let days = days_in_month(year, prev_month);
+        let days = days_in_month(year, prev_month, julian);
         (year, prev_month, days)
     } else {
         (year - 1, 12, 31)
     }
 }
 
-fn next_day(year: i32, month: u8, day: u8) -> (i32, u8, u8) {
-    let max = days_in_month(year, month);
+fn next_day(year: i32, month: u8, day: u8, julian: bool) -> (i32, u8, u8) {
+    let max = days_in_month(year, month, julian);
     if day < max {
         (year, month, day + 1)
     } else if month < 12 {
@@ -196,12 +242,13 @@ fn next_day(year: i32, month: u8, day: u8) -> (i32, u8, u8) {
     }
 }
 
-fn days_in_month(year: i32, month: u8) -> u8 {
+fn days_in_month(year: i32, month: u8, julian: bool) -> u8 {
     match month {
         1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
         4 | 6 | 9 | 11 => 30,
         2 => {
-            if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) {
+            // The Julian calendar has no century exception
+            if year % 4 == 0 && (julian || year % 100 != 0 || year % 400 == 0) {
                 29
             } else {
                 28
@@ -219,67 +266,426 @@ pub struct PlanetCalcResult {
 }
 
 /// Calculate positions of all planets for given Julian Day
-pub fn calc_planet_positions(jd: f64) -> Vec<PlanetCalcResult> {
-    let mut results = Vec::new();
-
-    // Earth (heliocentric, needed for recalculation)
-    let (earth_l, earth_b, earth_r) = planet::heliocent_coords(&planet::Planet::Earth, jd);
-
-    // Sun (geocentric)
-    let (sun_ecl, _rad_vec) = sun::geocent_ecl_pos(jd);
-    let sun_lng = sun_ecl.long.to_degrees();
-    let sun_lng = normalize_deg(sun_lng);
-
-    results.push(PlanetCalcResult { planet: HdPlanet::Sun, ecliptic_lng: sun_lng });
-
-    // Earth = Sun + 180°
-    let earth_lng = normalize_deg(sun_lng + 180.0);
-    results.push(PlanetCalcResult { planet: HdPlanet::Earth, ecliptic_lng: earth_lng });
-
-    // Moon (geocentric)
-    let (moon_ecl, _) = lunar::geocent_ecl_pos(jd);
-    let moon_lng = normalize_deg(moon_ecl.long.to_degrees());
-    results.push(PlanetCalcResult { planet: HdPlanet::Moon, ecliptic_lng: moon_lng });
-
-    // Lunar nodes (mean)
-    let jc = time::julian_cent(jd);
-    let mn_asc_node = lunar::mn_ascend_node(jc);
-    let nn_lng = normalize_deg(mn_asc_node.to_degrees());
-    let sn_lng = normalize_deg(nn_lng + 180.0);
-    results.push(PlanetCalcResult { planet: HdPlanet::NorthNode, ecliptic_lng: nn_lng });
-    results.push(PlanetCalcResult { planet: HdPlanet::SouthNode, ecliptic_lng: sn_lng });
-
-    // Inner and outer planets
-    let planets_list = vec![
-        (HdPlanet::Mercury, planet::Planet::Mercury),
-        (HdPlanet::Venus, planet::Planet::Venus),
-        (HdPlanet::Mars, planet::Planet::Mars),
-        (HdPlanet::Jupiter, planet::Planet::Jupiter),
-        (HdPlanet::Saturn, planet::Planet::Saturn),
-        (HdPlanet::Uranus, planet::Planet::Uranus),
-        (HdPlanet::Neptune, planet::Planet::Neptune),
-    ];
-
-    for (hd_planet, astro_planet) in &planets_list {
-        let (p_l, p_b, p_r) = planet::heliocent_coords(astro_planet, jd);
-        // Geocentric ecliptic coordinates
-        let (ecl_lng, _ecl_lat, _dist, _lt) =
-            planet::geocent_geomet_ecl_coords(earth_l, earth_b, earth_r, p_l, p_b, p_r);
-        let lng = normalize_deg(ecl_lng.to_degrees());
-        results.push(PlanetCalcResult { planet: *hd_planet, ecliptic_lng: lng });
-    }
-
-    // Pluto
-    let (pluto_l, pluto_b, pluto_r) = pluto::heliocent_pos(jd);
-    let (pluto_ecl_lng, _pluto_ecl_lat, _pluto_dist, _pluto_lt) =
-        planet::geocent_geomet_ecl_coords(earth_l, earth_b, earth_r, pluto_l, pluto_b, pluto_r);
-    let pluto_lng = normalize_deg(pluto_ecl_lng.to_degrees());
-    results.push(PlanetCalcResult { planet: HdPlanet::Pluto, ecliptic_lng: pluto_lng });
-
-    results
-}
-
-fn normalize_deg(deg: f64) -> f64 {
+/// Sun and Earth are always calculated: profile, cross and the Design moment depend on them
+pub fn calc_planet_positions(jd: f64, bodies: &[HdPlanet], calc_opts: &CalcOptions) -> Vec<PlanetCalcResult> {
+    // Optional bodies come after the HD planets
+    let selected: Vec<HdPlanet> = HdPlanet::all()
+        .into_iter()
+        .filter(|p| matches!(p, HdPlanet::Sun | HdPlanet::Earth) || bodies.contains(p))
+        .chain(bodies.iter().copied().filter(HdPlanet::is_extra))
+        .collect();
+    positions_for(jd, &selected, calc_opts)
+}
+
+/// Calculate positions of exactly the given bodies (in the given order)
+/// with the engine selected in `calc_opts`
+pub fn positions_for(jd: f64, bodies: &[HdPlanet], calc_opts: &CalcOptions) -> Vec<PlanetCalcResult> {
+    calc_opts.engine.engine().positions(jd, bodies, calc_opts)
+}
+
+/// Half of the interval daily speeds are differenced over: an hour either side
+const SPEED_HALF_STEP_DAYS: f64 = 1.0 / 24.0;
+
+/// Longitudinal speed of the given bodies at `jd` in degrees per day, negative while
+/// retrograde (central difference of the positions an hour before and after)
+pub fn daily_speeds(jd: f64, bodies: &[HdPlanet], calc_opts: &CalcOptions) -> Vec<f64> {
+    let before = positions_for(jd - SPEED_HALF_STEP_DAYS, bodies, calc_opts);
+    let after = positions_for(jd + SPEED_HALF_STEP_DAYS, bodies, calc_opts);
+    before
+        .iter()
+        .zip(&after)
+        .map(|(b, a)| {
+            // Handle crossing 0°/360°
+            let delta = normalize_deg(a.ecliptic_lng - b.ecliptic_lng + 180.0) - 180.0;
+            delta / (2.0 * SPEED_HALF_STEP_DAYS)
+        })
+        .collect()
+}
+
+/// Source of geocentric ecliptic longitudes. `AstroCrateEngine` (VSOP87/ELP series of
+/// the `astro` crate) is always built in; the `swisseph` feature adds the Swiss Ephemeris.
+/// Node model, ΔT and apparent positions are taken from the given `CalcOptions`.
+pub trait AstroEngine: Sync {
+    /// Positions of exactly the given bodies, in the given order
+    fn positions(&self, jd: f64, bodies: &[HdPlanet], calc_opts: &CalcOptions) -> Vec<PlanetCalcResult>;
+
+    /// Geocentric longitude of the Sun at `jd`, degrees
+    fn sun_longitude(&self, jd: f64, calc_opts: &CalcOptions) -> f64 {
+        self.positions(jd, &[HdPlanet::Sun], calc_opts)[0].ecliptic_lng
+    }
+
+    /// Julian Day when the Sun was `arc` degrees before `birth_sun_lng` (Design moment)
+    fn design_jd(&self, birth_jd: f64, birth_sun_lng: f64, arc: f64, calc_opts: &CalcOptions) -> Result<f64, String> {
+        solve_design_jd(|jd| self.sun_longitude(jd, calc_opts), birth_jd, birth_sun_lng, arc)
+    }
+}
+
+/// Default engine: the `astro` crate.
+/// Shared intermediate results (Sun, heliocentric Earth, lunar node) are computed once
+/// and only when a requested body needs them.
+pub struct AstroCrateEngine;
+
+impl AstroEngine for AstroCrateEngine {
+    fn positions(&self, jd: f64, bodies: &[HdPlanet], calc_opts: &CalcOptions) -> Vec<PlanetCalcResult> {
+        // The series are evaluated in Terrestrial Time
+        let jd = terrestrial_jd(jd, calc_opts);
+        let apparent = calc_opts.apparent;
+        // Equinox of date: nutation shifts every longitude alike
+        let nutation = if apparent { nutation_in_longitude(jd) } else { 0.0 };
+        let mut sun_lng: Option<f64> = None;
+        let mut earth_helio: Option<(f64, f64, f64)> = None;
+        let mut node_lng: Option<f64> = None;
+
+        let mut sun = || *sun_lng.get_or_insert_with(|| sun_at(jd, apparent));
+        let mut node = || {
+            *node_lng.get_or_insert_with(|| {
+                // Lunar nodes (mean, or true with `--node true`)
+                let jc = time::julian_cent(jd);
+                let mean = lunar::mn_ascend_node(jc).to_degrees();
+                match calc_opts.node {
+                    NodeModel::Mean => normalize_deg(mean + nutation),
+                    NodeModel::True => normalize_deg(mean + true_node_correction(jc) + nutation),
+                }
+            })
+        };
+        // Geocentric longitude from heliocentric coordinates (inner and outer planets, Pluto)
+        let mut geocentric = |p_l: f64, p_b: f64, p_r: f64| {
+            let (earth_l, earth_b, earth_r) = *earth_helio
+                .get_or_insert_with(|| planet::heliocent_coords(&planet::Planet::Earth, jd));
+            let (ecl_lng, ecl_lat, _dist, _lt) =
+                planet::geocent_geomet_ecl_coords(earth_l, earth_b, earth_r, p_l, p_b, p_r);
+            let lng = ecl_lng.to_degrees();
+            if !apparent {
+                return normalize_deg(lng);
+            }
+            // Annual aberration; the geometric Sun is opposite the heliocentric Earth
+            let sun_lng = earth_l.to_degrees() + 180.0;
+            let aberration = -ABERRATION_CONSTANT * (sun_lng - lng).to_radians().cos() / ecl_lat.cos();
+            normalize_deg(lng + nutation + aberration)
+        };
+
+        bodies
+            .iter()
+            .map(|&body| {
+                let ecliptic_lng = match body {
+                    HdPlanet::Sun => sun(),
+                    // Earth = Sun + 180°
+                    HdPlanet::Earth => normalize_deg(sun() + 180.0),
+                    HdPlanet::Moon => {
+                        // Moon (geocentric)
+                        let (moon_ecl, _) = lunar::geocent_ecl_pos(jd);
+                        normalize_deg(moon_ecl.long.to_degrees() + nutation)
+                    }
+                    HdPlanet::NorthNode => node(),
+                    HdPlanet::SouthNode => normalize_deg(node() + 180.0),
+                    HdPlanet::Pluto => {
+                        let (pluto_l, pluto_b, pluto_r) = pluto::heliocent_pos(jd);
+                        geocentric(pluto_l, pluto_b, pluto_r)
+                    }
+                    HdPlanet::Chiron => {
+                        let (chiron_l, chiron_b, chiron_r) = chiron_heliocent_pos(jd);
+                        geocentric(chiron_l, chiron_b, chiron_r)
+                    }
+                    HdPlanet::Lilith => normalize_deg(mean_lunar_apogee(jd) + nutation),
+                    _ => {
+                        let astro_planet = match body {
+                            HdPlanet::Mercury => planet::Planet::Mercury,
+                            HdPlanet::Venus => planet::Planet::Venus,
+                            HdPlanet::Mars => planet::Planet::Mars,
+                            HdPlanet::Jupiter => planet::Planet::Jupiter,
+                            HdPlanet::Saturn => planet::Planet::Saturn,
+                            HdPlanet::Uranus => planet::Planet::Uranus,
+                            _ => planet::Planet::Neptune,
+                        };
+                        let (p_l, p_b, p_r) = planet::heliocent_coords(&astro_planet, jd);
+                        geocentric(p_l, p_b, p_r)
+                    }
+                };
+                PlanetCalcResult {
+                    planet: body,
+                    ecliptic_lng,
+                }
+            })
+            .collect()
+    }
+
+    fn sun_longitude(&self, jd: f64, calc_opts: &CalcOptions) -> f64 {
+        sun_at(terrestrial_jd(jd, calc_opts), calc_opts.apparent)
+    }
+}
+
+/// Geocentric longitude of the Sun at `jde` (TT), apparent or true geometric
+fn sun_at(jde: f64, apparent: bool) -> f64 {
+    let (sun_ecl, rad_vec) = sun::geocent_ecl_pos(jde);
+    let lng = sun_ecl.long.to_degrees();
+    if apparent {
+        // Aberration of the Sun: -20.4898″ at 1 AU
+        normalize_deg(lng + nutation_in_longitude(jde) - 20.4898 / 3600.0 / rad_vec)
+    } else {
+        normalize_deg(lng)
+    }
+}
+
+/// Constant of annual aberration κ, degrees (20.49552″)
+const ABERRATION_CONSTANT: f64 = 20.49552 / 3600.0;
+
+/// Nutation in longitude Δψ at `jde`, degrees (Meeus ch. 22 short series, 0.5″)
+fn nutation_in_longitude(jde: f64) -> f64 {
+    let t = (jde - 2451545.0) / 36525.0;
+    let omega = (125.04452 - 1934.136261 * t).to_radians();
+    let sun_mean = (280.4665 + 36000.7698 * t).to_radians();
+    let moon_mean = (218.3165 + 481267.8813 * t).to_radians();
+    let arcsec = -17.20 * omega.sin() - 1.32 * (2.0 * sun_mean).sin() - 0.23 * (2.0 * moon_mean).sin()
+        + 0.21 * (2.0 * omega).sin();
+    arcsec / 3600.0
+}
+
+/// ΔT = TT − UT in seconds at `jd` (UT): Espenak & Meeus polynomials
+/// (NASA Five Millennium Canon), valid from -1999 to 3000 and parabolic beyond
+pub fn delta_t(jd: f64) -> f64 {
+    let y = 2000.0 + (jd - 2451545.0) / 365.25;
+    let long_term = |y: f64| {
+        let u = (y - 1820.0) / 100.0;
+        -20.0 + 32.0 * u * u
+    };
+    match y {
+        y if y < -500.0 => long_term(y),
+        y if y < 500.0 => {
+            let u = y / 100.0;
+            10583.6 - 1014.41 * u + 33.78311 * u.powi(2) - 5.952053 * u.powi(3) - 0.1798452 * u.powi(4)
+                + 0.022174192 * u.powi(5)
+                + 0.0090316521 * u.powi(6)
+        }
+        y if y < 1600.0 => {
+            let u = (y - 1000.0) / 100.0;
+            1574.2 - 556.01 * u + 71.23472 * u.powi(2) + 0.319781 * u.powi(3) - 0.8503463 * u.powi(4)
+                - 0.005050998 * u.powi(5)
+                + 0.0083572073 * u.powi(6)
+        }
+        y if y < 1700.0 => {
+            let t = y - 1600.0;
+            120.0 - 0.9808 * t - 0.01532 * t.powi(2) + t.powi(3) / 7129.0
+        }
+        y if y < 1800.0 => {
+            let t = y - 1700.0;
+            8.83 + 0.1603 * t - 0.0059285 * t.powi(2) + 0.00013336 * t.powi(3) - t.powi(4) / 1174000.0
+        }
+        y if y < 1860.0 => {
+            let t = y - 1800.0;
+            13.72 - 0.332447 * t + 0.0068612 * t.powi(2) + 0.0041116 * t.powi(3) - 0.00037436 * t.powi(4)
+                + 0.0000121272 * t.powi(5)
+                - 0.0000001699 * t.powi(6)
+                + 0.000000000875 * t.powi(7)
+        }
+        y if y < 1900.0 => {
+            let t = y - 1860.0;
+            7.62 + 0.5737 * t - 0.251754 * t.powi(2) + 0.01680668 * t.powi(3) - 0.0004473624 * t.powi(4)
+                + t.powi(5) / 233174.0
+        }
+        y if y < 1920.0 => {
+            let t = y - 1900.0;
+            -2.79 + 1.494119 * t - 0.0598939 * t.powi(2) + 0.0061966 * t.powi(3) - 0.000197 * t.powi(4)
+        }
+        y if y < 1941.0 => {
+            let t = y - 1920.0;
+            21.20 + 0.84493 * t - 0.076100 * t.powi(2) + 0.0020936 * t.powi(3)
+        }
+        y if y < 1961.0 => {
+            let t = y - 1950.0;
+            29.07 + 0.407 * t - t.powi(2) / 233.0 + t.powi(3) / 2547.0
+        }
+        y if y < 1986.0 => {
+            let t = y - 1975.0;
+            45.45 + 1.067 * t - t.powi(2) / 260.0 - t.powi(3) / 718.0
+        }
+        y if y < 2005.0 => {
+            let t = y - 2000.0;
+            63.86 + 0.3345 * t - 0.060374 * t.powi(2) + 0.0017275 * t.powi(3) + 0.000651814 * t.powi(4)
+                + 0.00002373599 * t.powi(5)
+        }
+        y if y < 2050.0 => {
+            let t = y - 2000.0;
+            62.92 + 0.32217 * t + 0.005589 * t.powi(2)
+        }
+        y if y < 2150.0 => long_term(y) - 0.5628 * (2150.0 - y),
+        y => long_term(y),
+    }
+}
+
+/// Terrestrial Time Julian Day (JDE) for a UT Julian Day, the UT moment itself when
+/// the ΔT correction is disabled
+pub fn terrestrial_jd(jd: f64, calc_opts: &CalcOptions) -> f64 {
+    if calc_opts.delta_t {
+        jd + delta_t(jd) / 86400.0
+    } else {
+        jd
+    }
+}
+
+/// Ephemeris engine, see `AstroEngine`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum EngineKind {
+    /// `astro` crate (VSOP87/ELP), built in
+    Astro,
+    /// Swiss Ephemeris (`swisseph` build feature)
+    #[cfg(feature = "swisseph")]
+    Swisseph,
+}
+
+impl EngineKind {
+    pub fn key(&self) -> &'static str {
+        match self {
+            EngineKind::Astro => "astro",
+            #[cfg(feature = "swisseph")]
+            EngineKind::Swisseph => "swisseph",
+        }
+    }
+
+    /// The engine itself
+    pub fn engine(&self) -> &'static dyn AstroEngine {
+        match self {
+            EngineKind::Astro => &AstroCrateEngine,
+            #[cfg(feature = "swisseph")]
+            EngineKind::Swisseph => &crate::swisseph::SwissEphEngine,
+        }
+    }
+}
+
+impl Default for EngineKind {
+    /// The most precise engine compiled in
+    fn default() -> Self {
+        #[cfg(feature = "swisseph")]
+        return EngineKind::Swisseph;
+        #[cfg(not(feature = "swisseph"))]
+        EngineKind::Astro
+    }
+}
+
+/// Lunar node used for the North/South Node activations
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NodeModel {
+    /// Mean node (the standard)
+    #[default]
+    Mean,
+    /// True (osculating) node, as used by some HD software
+    True,
+}
+
+impl NodeModel {
+    pub fn key(&self) -> &'static str {
+        match self {
+            NodeModel::Mean => "mean",
+            NodeModel::True => "true",
+        }
+    }
+}
+
+/// Calculation settings of a chart or report: engine, corrections, input calendar and
+/// the expert overrides. Passed along with every calculation, so charts with different
+/// settings can be built side by side (e.g. by the server)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CalcOptions {
+    pub engine: EngineKind,
+    pub node: NodeModel,
+    /// Convert civil (UT) moments to Terrestrial Time, off with `--no-delta-t`
+    pub delta_t: bool,
+    /// Apparent positions (nutation and aberration applied), off with `--true-positions`
+    pub apparent: bool,
+    /// Calendar of the dates given as input
+    pub calendar: Calendar,
+    /// Solar arc between the Design and the birth moment, degrees
+    pub design_arc: f64,
+    /// Shift of the gate wheel anchor, degrees (research option)
+    pub wheel_offset: f64,
+    /// Gate wheel moved to the sidereal zodiac of this ayanamsa, on top of `wheel_offset`
+    pub sidereal_wheel: Option<Ayanamsa>,
+}
+
+impl Default for CalcOptions {
+    fn default() -> Self {
+        Self {
+            engine: EngineKind::default(),
+            node: NodeModel::default(),
+            delta_t: true,
+            apparent: true,
+            calendar: Calendar::default(),
+            design_arc: DEFAULT_DESIGN_ARC,
+            wheel_offset: 0.0,
+            sidereal_wheel: None,
+        }
+    }
+}
+
+impl CalcOptions {
+    /// Total shift of the gate wheel anchor at `jd`, degrees: the research offset plus
+    /// the ayanamsa of a sidereal wheel (which grows with precession)
+    pub fn wheel_shift(&self, jd: f64) -> f64 {
+        self.wheel_offset + self.sidereal_wheel.map_or(0.0, |a| a.degrees(jd))
+    }
+}
+
+/// True minus mean ascending node in degrees (Meeus, chapter 47), for Julian
+/// centuries `jc` since J2000; stays within about 1.7°
+fn true_node_correction(jc: f64) -> f64 {
+    let d = (297.8501921 + 445267.1114034 * jc - 0.0018819 * jc * jc).to_radians();
+    let m = (357.5291092 + 35999.0502909 * jc - 0.0001536 * jc * jc).to_radians();
+    let m_moon = (134.9633964 + 477198.8675055 * jc + 0.0087414 * jc * jc).to_radians();
+    let f = (93.2720950 + 483202.0175233 * jc - 0.0036539 * jc * jc).to_radians();
+    -1.4979 * (2.0 * (d - f)).sin() - 0.1500 * m.sin() - 0.1226 * (2.0 * d).sin()
+        + 0.1176 * (2.0 * f).sin()
+        - 0.0801 * (2.0 * (m_moon - f)).sin()
+}
+
+/// Osculating elements of Chiron (J2000 ecliptic, epoch JD 2451600.5): semi-major
+/// axis (AU), eccentricity, inclination, ascending node, argument of perihelion and
+/// mean anomaly at the epoch (degrees). A two-body orbit from these stays within
+/// about a degree of the ephemeris for 1940–2060
+const CHIRON_ELEMENTS: [f64; 6] = [13.6486, 0.38316, 6.9347, 209.3945, 339.4680, 27.23];
+const CHIRON_EPOCH_JD: f64 = 2451600.5;
+
+/// General precession in longitude, degrees per Julian year
+const PRECESSION_DEG_PER_YEAR: f64 = 50.29 / 3600.0;
+
+/// Heliocentric ecliptic longitude, latitude (radians, equinox of date) and radius (AU) of Chiron
+fn chiron_heliocent_pos(jd: f64) -> (f64, f64, f64) {
+    let [a, e, incl, node, peri, mean_anomaly] = CHIRON_ELEMENTS;
+    let daily_motion = 360.0 / (a.powf(1.5) * 365.25);
+    let m = normalize_deg(mean_anomaly + daily_motion * (jd - CHIRON_EPOCH_JD)).to_radians();
+
+    // Kepler's equation by Newton iteration
+    let mut ecc_anomaly = m;
+    for _ in 0..30 {
+        let delta = (ecc_anomaly - e * ecc_anomaly.sin() - m) / (1.0 - e * ecc_anomaly.cos());
+        ecc_anomaly -= delta;
+        if delta.abs() < 1e-12 {
+            break;
+        }
+    }
+    let true_anomaly = 2.0
+        * ((1.0 + e).sqrt() * (ecc_anomaly / 2.0).sin())
+            .atan2((1.0 - e).sqrt() * (ecc_anomaly / 2.0).cos());
+    let r = a * (1.0 - e * ecc_anomaly.cos());
+
+    let (node, incl) = (node.to_radians(), incl.to_radians());
+    let u = peri.to_radians() + true_anomaly;
+    let x = r * (node.cos() * u.cos() - node.sin() * u.sin() * incl.cos());
+    let y = r * (node.sin() * u.cos() + node.cos() * u.sin() * incl.cos());
+    let z = r * u.sin() * incl.sin();
+
+    // J2000 equinox to the equinox of date, as for the other planets
+    let precession = (jd - 2451545.0) / 365.25 * PRECESSION_DEG_PER_YEAR;
+    let lng = y.atan2(x) + precession.to_radians();
+    (lng, (z / r).asin(), r)
+}
+
+/// Black Moon Lilith: longitude of the mean lunar apogee (Meeus, mean perigee + 180°)
+fn mean_lunar_apogee(jd: f64) -> f64 {
+    let t = time::julian_cent(jd);
+    normalize_deg(263.3532465 + 4069.0137287 * t - 0.01032 * t * t)
+}
+
+pub(crate) fn normalize_deg(deg: f64) -> f64 {
     let mut d = deg % 360.0;
     if d < 0.0 {
         d += 360.0;
@@ -287,22 +693,122 @@ fn normalize_deg(deg: f64) -> f64 {
     d
 }
 
-/// Find Julian Day when Sun was 88° earlier (Design calculation)
-/// Using iterative search method
-pub fn find_design_jd(birth_jd: f64, birth_sun_lng: f64) -> f64 {
-    // Target Sun degree = birth_sun - 88°
-    let target = normalize_deg(birth_sun_lng - 88.0);
+/// Sidereal zodiac reference (ayanamsa) for the experimental sidereal mode
+#[derive(Debug, Clone, Copy, PartialEq, clap::ValueEnum)]
+pub enum Ayanamsa {
+    Lahiri,
+    Raman,
+    Krishnamurti,
+    #[value(alias = "fagan")]
+    FaganBradley,
+}
 
-    // Approximate Sun speed ~0.9856°/day
-    // 88° ≈ 89.3 days ago
-    let mut jd = birth_jd - 89.3;
+impl Ayanamsa {
+    pub fn key(&self) -> &'static str {
+        match self {
+            Ayanamsa::Lahiri => "lahiri",
+            Ayanamsa::Raman => "raman",
+            Ayanamsa::Krishnamurti => "krishnamurti",
+            Ayanamsa::FaganBradley => "fagan_bradley",
+        }
+    }
 
-    // Iterative search (Newton-like method)
-    for _ in 0..50 {
-        let (sun_ecl, _) = sun::geocent_ecl_pos(jd);
-        let current_lng = normalize_deg(sun_ecl.long.to_degrees());
+    /// Offset of the sidereal zodiac from the tropical one at `jd`, degrees.
+    /// Value at J2000 advanced by general precession (50.29″ per year), accurate to
+    /// well under a minute of arc over the last and next centuries.
+    pub fn degrees(&self, jd: f64) -> f64 {
+        let at_j2000 = match self {
+            Ayanamsa::Lahiri => 23.853,
+            Ayanamsa::Raman => 22.410,
+            Ayanamsa::Krishnamurti => 23.757,
+            Ayanamsa::FaganBradley => 24.740,
+        };
+        let years = (jd - 2451545.0) / 365.25;
+        at_j2000 + years * 50.29 / 3600.0
+    }
+}
+
+/// Standard Design solar arc: the Design moment is when the Sun was 88° earlier
+pub const DEFAULT_DESIGN_ARC: f64 = 88.0;
+
+/// Find Julian Day when Sun was `arc` degrees earlier (Design calculation)
+pub fn find_design_jd(birth_jd: f64, birth_sun_lng: f64, arc: f64, calc_opts: &CalcOptions) -> Result<f64, String> {
+    calc_opts.engine.engine().design_jd(birth_jd, birth_sun_lng, arc, calc_opts)
+}
 
-        let mut diff = target - current_lng;
+/// Mean apparent speed of the Sun, degrees per day
+const MEAN_SUN_SPEED: f64 = 0.9856;
+
+/// The Design Sun is solved to within this many degrees of the arc (about 0.1 s of time)
+pub const DESIGN_TOLERANCE_DEG: f64 = 1e-6;
+
+/// Half-width of the search bracket around the mean-speed estimate. The Sun moves
+/// 0.95–1.02°/day, so for any arc up to 360° the estimate is off by under 13 days
+const DESIGN_BRACKET_DAYS: f64 = 15.0;
+
+/// Bisection halves a 30-day bracket below `DESIGN_TOLERANCE_DEG` well within this
+const DESIGN_MAX_ITERATIONS: usize = 100;
+
+/// Bracketed solver for the Design moment. The Sun never retrogrades, so its
+/// longitude minus the target changes sign exactly once inside the bracket; Newton
+/// steps at the mean speed are taken while they stay inside it, bisection otherwise,
+/// which always converges. Fails when the engine's Sun does not cross the target
+/// within the bracket (no finite or monotonic longitudes).
+fn solve_design_jd(
+    sun_lng: impl Fn(f64) -> f64,
+    birth_jd: f64,
+    birth_sun_lng: f64,
+    arc: f64,
+) -> Result<f64, String> {
+    let target = normalize_deg(birth_sun_lng - arc);
+    // Degrees still to go from `jd` to the target, wrapped to (-180°, 180°]
+    let remaining = |jd: f64| {
+        let diff = normalize_deg(target - sun_lng(jd));
+        if diff > 180.0 {
+            diff - 360.0
+        } else {
+            diff
+        }
+    };
+    let unconverged = || {
+        rust_i18n::t!("error.design_unconverged", arc = arc, jd = format!("{:.6}", birth_jd)).to_string()
+    };
+
+    let estimate = birth_jd - arc / MEAN_SUN_SPEED;
+    let (mut lo, mut hi) = (estimate - DESIGN_BRACKET_DAYS, estimate + DESIGN_BRACKET_DAYS);
+    if !(remaining(lo) > 0.0 && remaining(hi) < 0.0) {
+        return Err(unconverged());
+    }
+
+    let mut jd = estimate;
+    for _ in 0..DESIGN_MAX_ITERATIONS {
+        let diff = remaining(jd);
+        if diff.abs() <= DESIGN_TOLERANCE_DEG {
+            return Ok(jd);
+        }
+        if diff > 0.0 {
+            lo = jd;
+        } else if diff < 0.0 {
+            hi = jd;
+        } else {
+            // NaN from the engine
+            return Err(unconverged());
+        }
+        let newton = jd + diff / MEAN_SUN_SPEED;
+        jd = if newton > lo && newton < hi {
+            newton
+        } else {
+            (lo + hi) / 2.0
+        };
+    }
+    Err(unconverged())
+}
+
+/// Iterative search (Newton-like method) from `jd` for the moment the Sun, as given
+/// by `sun_lng`, reaches `target`
+fn refine_sun_jd(sun_lng: impl Fn(f64) -> f64, mut jd: f64, target: f64) -> f64 {
+    for _ in 0..50 {
+        let mut diff = target - sun_lng(jd);
         // Handle crossing 0°/360°
         if diff > 180.0 {
             diff -= 360.0;
@@ -315,9 +821,80 @@ pub fn find_design_jd(birth_jd: f64, birth_sun_lng: f64) -> f64 {
             break;
         }
 
-        // Correction: Sun moves ~0.9856°/day
-        jd += diff / 0.9856;
+        jd += diff / MEAN_SUN_SPEED;
     }
 
     jd
 }
+
+/// Find the first Julian Day after `after_jd` when the Sun reaches `target_lng`
+/// (solar return when `target_lng` is the natal Sun)
+pub fn find_next_sun_longitude(after_jd: f64, target_lng: f64, calc_opts: &CalcOptions) -> f64 {
+    let engine = calc_opts.engine.engine();
+    let ahead = normalize_deg(target_lng - engine.sun_longitude(after_jd, calc_opts));
+    refine_sun_jd(
+        |jd| engine.sun_longitude(jd, calc_opts),
+        after_jd + ahead / MEAN_SUN_SPEED,
+        target_lng,
+    )
+}
+
+/// Julian Day of the solar return in `year`: the Sun back at its longitude at `natal_jd`
+pub fn find_solar_return(natal_jd: f64, year: i32, calc_opts: &CalcOptions) -> f64 {
+    let natal_lng = calc_opts.engine.engine().sun_longitude(natal_jd, calc_opts);
+    let new_year = calc_julian_day((year, 1, 1), (0, 0, 0), 0.0, calc_opts.calendar);
+    find_next_sun_longitude(new_year, natal_lng, calc_opts)
+}
+
+/// Julian Day of the Unix epoch (1970-01-01 00:00 UTC)
+const UNIX_EPOCH_JD: f64 = 2440587.5;
+
+/// Julian Day for the current moment (UTC)
+pub fn now_julian_day() -> f64 {
+    let secs = chrono::Utc::now().timestamp() as f64;
+    UNIX_EPOCH_JD + secs / 86400.0
+}
+
+/// Convert Julian Day back to a UTC calendar datetime
+pub fn jd_to_utc(jd: f64) -> chrono::NaiveDateTime {
+    let secs = ((jd - UNIX_EPOCH_JD) * 86400.0).round() as i64;
+    chrono::DateTime::from_timestamp(secs, 0)
+        .map(|dt| dt.naive_utc())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn design_sun_is_88_degrees_before_birth() {
+        // 1990-05-15 14:30 UTC+3: Sun in Taurus, Design in mid-February
+        let calc_opts = CalcOptions::default();
+        let birth_jd = calc_julian_day((1990, 5, 15), (14, 30, 0), 3.0, calc_opts.calendar);
+        let engine = calc_opts.engine.engine();
+        let birth_sun = engine.sun_longitude(birth_jd, &calc_opts);
+        let design_jd = find_design_jd(birth_jd, birth_sun, DEFAULT_DESIGN_ARC, &calc_opts).unwrap();
+
+        let arc = normalize_deg(birth_sun - engine.sun_longitude(design_jd, &calc_opts));
+        assert!((arc - DEFAULT_DESIGN_ARC).abs() <= DESIGN_TOLERANCE_DEG, "arc {}", arc);
+        let design = jd_to_utc(design_jd).format("%Y-%m-%d").to_string();
+        assert_eq!(design, "1990-02-15");
+    }
+
+    #[test]
+    fn delta_t_at_j2000() {
+        assert!((delta_t(2451545.0) - 63.8).abs() < 0.5, "ΔT {}", delta_t(2451545.0));
+    }
+
+    #[test]
+    fn julian_day_continues_across_the_gregorian_reform() {
+        let last_julian = calc_julian_day((1582, 10, 4), (0, 0, 0), 0.0, Calendar::Auto);
+        let first_gregorian = calc_julian_day((1582, 10, 15), (0, 0, 0), 0.0, Calendar::Auto);
+        assert_eq!(first_gregorian, 2299160.5);
+        assert_eq!(first_gregorian - last_julian, 1.0);
+        // A UTC offset that moves the time back over midnight stays on the Julian side
+        let evening = calc_julian_day((1582, 10, 15), (2, 0, 0), 3.0, Calendar::Auto);
+        assert!((evening - (first_gregorian - 1.0 / 24.0)).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,333 @@
+/// Text bodygraph for the terminal report: the nine centers as boxes (double border
+/// when defined) listing their active gates, joined by the chart's defined channels,
+/// with a legend of the active gates and their keynotes underneath
+use crate::data::centers::{gates_for_center, Center};
+use crate::models::HdChart;
+use crate::render::{activation_text_color, RenderOptions};
+use crate::render::style::*;
+use std::collections::HashMap;
+use unicode_width::UnicodeWidthChar;
+
+/// Canvas size in terminal columns and rows
+pub const CANVAS_WIDTH: usize = 76;
+const CANVAS_HEIGHT: usize = 41;
+
+/// Active gates per row inside a center box
+const GATES_PER_ROW: usize = 4;
+
+/// Horizontal center and top row of each center's box. Tops leave room for the
+/// tallest box (Throat, Sacral and Root can hold three rows of gates); the Heart
+/// sits far enough right to clear the Throat–Solar Plexus channels
+fn anchor(center: &Center) -> (usize, usize) {
+    match center {
+        Center::Head => (38, 0),
+        Center::Ajna => (38, 6),
+        Center::Throat => (38, 12),
+        Center::G => (38, 19),
+        Center::Heart => (62, 20),
+        Center::Spleen => (9, 28),
+        Center::SolarPlexus => (64, 28),
+        Center::Sacral => (38, 28),
+        Center::Root => (38, 35),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Ink {
+    Channel,
+    Defined,
+    Open,
+    Gate(&'static str),
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Cell {
+    ch: char,
+    ink: Option<Ink>,
+    /// Right half of a double-width character
+    continuation: bool,
+}
+
+const BLANK: Cell = Cell {
+    ch: ' ',
+    ink: None,
+    continuation: false,
+};
+
+struct Canvas {
+    cells: Vec<Vec<Cell>>,
+}
+
+impl Canvas {
+    fn new() -> Self {
+        Self {
+            cells: vec![vec![BLANK; CANVAS_WIDTH]; CANVAS_HEIGHT],
+        }
+    }
+
+    fn put(&mut self, col: usize, row: usize, ch: char, ink: Ink) {
+        if row < CANVAS_HEIGHT && col < CANVAS_WIDTH {
+            self.cells[row][col] = Cell {
+                ch,
+                ink: Some(ink),
+                continuation: false,
+            };
+        }
+    }
+
+    /// Write `text` from `col`, double-width characters taking two cells
+    fn text(&mut self, mut col: usize, row: usize, text: &str, ink: Ink) {
+        for ch in text.chars() {
+            self.put(col, row, ch, ink);
+            let width = ch.width().unwrap_or(1);
+            if width == 2 && col + 1 < CANVAS_WIDTH && row < CANVAS_HEIGHT {
+                self.cells[row][col + 1] = Cell {
+                    continuation: true,
+                    ..BLANK
+                };
+            }
+            col += width;
+        }
+    }
+
+    /// Straight line between two cells; diagonal steps use slashes, `double`
+    /// draws the horizontal steps with a double stroke
+    fn line(&mut self, from: (i32, i32), to: (i32, i32), double: bool, ascii: bool) {
+        let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+        let steps = dx.abs().max(dy.abs()).max(1);
+        let (mut prev_x, mut prev_y) = from;
+        for step in 1..=steps {
+            let x = from.0 + (dx * step + dx.signum() * steps / 2) / steps;
+            let y = from.1 + (dy * step + dy.signum() * steps / 2) / steps;
+            let ch = match (x != prev_x, y != prev_y, double, ascii) {
+                (true, true, ..) if (x - prev_x) * (y - prev_y) < 0 => '/',
+                (true, true, ..) => '\\',
+                (true, false, false, false) => '─',
+                (true, false, false, true) => '-',
+                (true, false, true, false) => '═',
+                (true, false, true, true) => '=',
+                (false, _, _, false) => '│',
+                (false, _, _, true) => '|',
+            };
+            if x >= 0 && y >= 0 {
+                self.put(x as usize, y as usize, ch, Ink::Channel);
+            }
+            (prev_x, prev_y) = (x, y);
+        }
+    }
+
+    fn render(&self, color: bool) -> String {
+        let mut out = String::new();
+        for row in &self.cells {
+            let mut line = String::from("  ");
+            for cell in row.iter().filter(|c| !c.continuation) {
+                let text = cell.ch.to_string();
+                let styled = match (color, cell.ink) {
+                    (true, Some(Ink::Channel)) => text.truecolor(95, 158, 160),
+                    (true, Some(Ink::Defined)) => text.truecolor(255, 215, 0).bold(),
+                    (true, Some(Ink::Open)) => text.truecolor(128, 128, 128),
+                    (true, Some(Ink::Gate(side))) => text.color(activation_text_color(side)).bold(),
+                    _ => text.normal(),
+                };
+                line.push_str(&styled.to_string());
+            }
+            out.push_str(line.trim_end());
+            out.push('\n');
+        }
+        // Drop the empty rows left below short boxes
+        while out.ends_with("\n\n") {
+            out.pop();
+        }
+        out
+    }
+}
+
+/// Box of one center: name on the first row, its active gates below
+struct CenterBox {
+    name: String,
+    gates: Vec<(u8, &'static str)>,
+    defined: bool,
+}
+
+impl CenterBox {
+    fn gate_rows(&self) -> Vec<&[(u8, &'static str)]> {
+        self.gates.chunks(GATES_PER_ROW).collect()
+    }
+
+    fn inner_width(&self) -> usize {
+        let gates = self
+            .gate_rows()
+            .iter()
+            .map(|row| row.iter().map(|(g, _)| g.to_string().len() + 1).sum::<usize>() - 1)
+            .max()
+            .unwrap_or(0);
+        unicode_width::UnicodeWidthStr::width(self.name.as_str()).max(gates) + 2
+    }
+
+    /// Top-left corner and size for a box centered on `anchor`
+    fn frame(&self, anchor: (usize, usize)) -> (usize, usize, usize, usize) {
+        let width = self.inner_width() + 2;
+        let height = self.gate_rows().len() + 3;
+        (anchor.0.saturating_sub(width / 2), anchor.1, width, height)
+    }
+
+    fn draw(&self, canvas: &mut Canvas, anchor: (usize, usize), ascii: bool) {
+        let (left, top, width, height) = self.frame(anchor);
+        let ink = if self.defined { Ink::Defined } else { Ink::Open };
+        let [tl, tr, bl, br, h, v] = match (self.defined, ascii) {
+            (true, false) => ['╔', '╗', '╚', '╝', '═', '║'],
+            (false, false) => ['┌', '┐', '└', '┘', '─', '│'],
+            (true, true) => ['#', '#', '#', '#', '=', '#'],
+            (false, true) => ['+', '+', '+', '+', '-', '|'],
+        };
+        let right = left + width - 1;
+        let bottom = top + height - 1;
+        for row in top..=bottom {
+            for col in left..=right {
+                let ch = match (row == top || row == bottom, col == left || col == right) {
+                    (true, true) if row == top && col == left => tl,
+                    (true, true) if row == top => tr,
+                    (true, true) if col == left => bl,
+                    (true, true) => br,
+                    (true, false) => h,
+                    (false, true) => v,
+                    (false, false) => ' ',
+                };
+                canvas.put(col, row, ch, ink);
+            }
+        }
+
+        let inner = width - 2;
+        let name_width = unicode_width::UnicodeWidthStr::width(self.name.as_str());
+        canvas.text(left + 1 + (inner - name_width) / 2, top + 1, &self.name, ink);
+        for (i, gates) in self.gate_rows().iter().enumerate() {
+            let row_width = gates.iter().map(|(g, _)| g.to_string().len() + 1).sum::<usize>() - 1;
+            let mut col = left + 1 + (inner - row_width) / 2;
+            for (gate, side) in gates.iter() {
+                let text = gate.to_string();
+                canvas.text(col, top + 2 + i, &text, Ink::Gate(side));
+                col += text.len() + 1;
+            }
+        }
+    }
+}
+
+fn side_key(activation: &str) -> &'static str {
+    match activation {
+        "personality" => "personality",
+        "design" => "design",
+        _ => "both",
+    }
+}
+
+/// Bodygraph drawing, empty when the output is narrower than the canvas
+pub fn render_bodygraph(chart: &HdChart, opts: &RenderOptions) -> String {
+    if opts.resolved_width() < CANVAS_WIDTH + 2 {
+        return String::new();
+    }
+
+    let activations: HashMap<u8, &'static str> = chart
+        .active_gates
+        .iter()
+        .map(|g| (g.gate, side_key(&g.activation)))
+        .collect();
+    let boxes: HashMap<Center, CenterBox> = chart
+        .centers
+        .iter()
+        .map(|c| {
+            let mut gates: Vec<(u8, &'static str)> = gates_for_center(&c.key)
+                .into_iter()
+                .filter_map(|g| activations.get(&g).map(|side| (g, *side)))
+                .collect();
+            gates.sort_unstable();
+            let center_box = CenterBox {
+                name: c.name.clone(),
+                gates,
+                defined: c.defined,
+            };
+            (c.key, center_box)
+        })
+        .collect();
+
+    let mut canvas = Canvas::new();
+
+    // Defined channels between the middles of their centers' boxes. Channels
+    // sharing a pair of centers are drawn side by side when the line is steep,
+    // otherwise as a single double-stroke line
+    let frame = |center: &Center| {
+        boxes
+            .get(center)
+            .map(|b| b.frame(anchor(center)))
+            .unwrap_or((anchor(center).0, anchor(center).1, 1, 1))
+    };
+    let middle = |center: &Center| -> (i32, i32) {
+        let (left, top, width, height) = frame(center);
+        ((left + width / 2) as i32, (top + height / 2) as i32)
+    };
+    let mut per_pair: HashMap<(Center, Center), Vec<&str>> = HashMap::new();
+    for ch in &chart.channels {
+        if let [a, b] = ch.centers[..] {
+            let pair = if a.key() < b.key() { (a, b) } else { (b, a) };
+            per_pair.entry(pair).or_default().push(&ch.key);
+        }
+    }
+    for ((a, b), keys) in &per_pair {
+        let (from, to) = (middle(a), middle(b));
+        let steep = (to.1 - from.1).abs() * 2 >= (to.0 - from.0).abs();
+        // Throat–Sacral (20-34) would run underneath the G box, so it detours
+        // along the box's left side
+        let detour = matches!(
+            (a, b),
+            (Center::Sacral, Center::Throat) | (Center::Throat, Center::Sacral)
+        )
+        .then(|| frame(&Center::G).0 as i32 - 3);
+        let (n, double) = if steep {
+            (keys.len() as i32, false)
+        } else {
+            (1, keys.len() > 1)
+        };
+        for i in 0..n {
+            let ox = 2 * i - (n - 1);
+            let (start, end) = ((from.0 + ox, from.1), (to.0 + ox, to.1));
+            match detour {
+                Some(x) => {
+                    let x = x + ox;
+                    canvas.line(start, (x, start.1), double, opts.ascii);
+                    canvas.line((x, start.1), (x, end.1), double, opts.ascii);
+                    canvas.line((x, end.1), end, double, opts.ascii);
+                }
+                None => canvas.line(start, end, double, opts.ascii),
+            }
+        }
+    }
+
+    for center in Center::all() {
+        if let Some(center_box) = boxes.get(center) {
+            center_box.draw(&mut canvas, anchor(center), opts.ascii);
+        }
+    }
+
+    let mut out = canvas.render(opts.color);
+    out.push_str(&legend(chart, opts.color));
+    out
+}
+
+/// Legend under the drawing: every active gate with its name and keynote
+fn legend(chart: &HdChart, color: bool) -> String {
+    let mut out = String::from("\n");
+    for ag in &chart.active_gates {
+        let gate = format!("{:>2}", ag.gate);
+        let gate = if color {
+            gate.color(activation_text_color(side_key(&ag.activation))).bold().to_string()
+        } else {
+            gate
+        };
+        let line = match ag.keynote {
+            Some(ref k) if !k.is_empty() => format!("  {}  {}: {}", gate, ag.gate_name, k),
+            _ => format!("  {}  {}", gate, ag.gate_name),
+        };
+        out.push_str(&line);
+        out.push('\n');
+    }
+    out
+}
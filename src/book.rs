@@ -0,0 +1,262 @@
+/// Markdown reference book generated from a gates database (mdBook / wiki layout)
+use crate::calc;
+use crate::data::centers::{self, Center};
+use crate::data::channels::{self, ChannelDef};
+use crate::data::database::HdDatabase;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+const TYPE_KEYS: [&str; 5] = [
+    "manifestor",
+    "generator",
+    "manifesting_generator",
+    "projector",
+    "reflector",
+];
+
+/// Write the book into `out_dir` (created if missing); returns the number of files written
+pub fn write_book(db: &HdDatabase, lang: &str, out_dir: &Path) -> Result<usize, String> {
+    for sub in ["types", "centers", "channels", "gates"] {
+        fs::create_dir_all(out_dir.join(sub)).map_err(|e| e.to_string())?;
+    }
+
+    let mut files: Vec<(String, String)> = Vec::new();
+    let mut summary = String::new();
+    writeln!(summary, "# {}\n", rust_i18n::t!("book.title")).unwrap();
+    writeln!(summary, "[{}](README.md)", rust_i18n::t!("book.title")).unwrap();
+
+    writeln!(summary, "\n# {}\n", rust_i18n::t!("book.types")).unwrap();
+    for key in TYPE_KEYS {
+        let Some(meta) = db.types.get(key) else {
+            continue;
+        };
+        let path = format!("types/{}.md", key);
+        writeln!(summary, "- [{}]({})", meta.name, path).unwrap();
+        files.push((path, type_page(db, key)));
+    }
+
+    writeln!(summary, "\n# {}\n", rust_i18n::t!("book.centers")).unwrap();
+    for center in Center::all() {
+        let Some(data) = db.centers.get(center.key()) else {
+            continue;
+        };
+        let path = format!("centers/{}.md", center.key());
+        writeln!(summary, "- [{}]({})", data.name, path).unwrap();
+        files.push((path, center_page(db, center)));
+    }
+
+    writeln!(summary, "\n# {}\n", rust_i18n::t!("book.channels")).unwrap();
+    for ch in channels::unique_channels(channels::all_channels()) {
+        let path = format!("channels/{}.md", ch.key());
+        writeln!(summary, "- [{}]({})", channel_label(db, &ch), path).unwrap();
+        files.push((path, channel_page(db, &ch)));
+    }
+
+    writeln!(summary, "\n# {}\n", rust_i18n::t!("book.gates")).unwrap();
+    for gate in 1..=64u8 {
+        let Some(data) = db.gates.get(&gate.to_string()) else {
+            continue;
+        };
+        let path = gate_path(gate);
+        writeln!(summary, "- [{} {}]({})", gate, data.name, path).unwrap();
+        files.push((path, gate_page(db, gate)));
+    }
+
+    let mut readme = String::new();
+    writeln!(readme, "# {}\n", rust_i18n::t!("book.title")).unwrap();
+    writeln!(readme, "{}", rust_i18n::t!("book.intro", lang = lang)).unwrap();
+    files.push(("README.md".to_string(), readme));
+    files.push(("SUMMARY.md".to_string(), summary));
+
+    for (path, content) in &files {
+        fs::write(out_dir.join(path), content).map_err(|e| e.to_string())?;
+    }
+    Ok(files.len())
+}
+
+fn gate_path(gate: u8) -> String {
+    format!("gates/{:02}.md", gate)
+}
+
+/// Link to a gate page from another section's page
+fn gate_link(db: &HdDatabase, gate: u8) -> String {
+    let name = db
+        .gates
+        .get(&gate.to_string())
+        .map(|g| g.name.clone())
+        .unwrap_or_default();
+    format!("[{} {}](../{})", gate, name, gate_path(gate))
+}
+
+fn center_link(db: &HdDatabase, center: &Center) -> String {
+    let name = db
+        .centers
+        .get(center.key())
+        .map(|c| c.name.clone())
+        .unwrap_or_else(|| center.key().to_string());
+    format!("[{}](../centers/{}.md)", name, center.key())
+}
+
+/// "key name", or just the key for channels without a name in the database
+fn channel_label(db: &HdDatabase, ch: &ChannelDef) -> String {
+    let name = calc::channel_name(db, ch);
+    if name == ch.key() {
+        name
+    } else {
+        format!("{} {}", ch.key(), name)
+    }
+}
+
+fn channel_link(db: &HdDatabase, ch: &ChannelDef) -> String {
+    format!("[{}](../channels/{}.md)", channel_label(db, ch), ch.key())
+}
+
+/// `## heading` followed by a paragraph, skipped when the text is empty
+fn section(out: &mut String, heading: &str, text: &str) {
+    if !text.trim().is_empty() {
+        writeln!(out, "\n## {}\n\n{}", heading, text.trim()).unwrap();
+    }
+}
+
+fn type_page(db: &HdDatabase, key: &str) -> String {
+    let mut out = String::new();
+    let meta = &db.types[key];
+    writeln!(out, "# {}\n\n{}", meta.name, meta.description.trim()).unwrap();
+    if let Some(strategy) = db.strategies.get(key) {
+        section(&mut out, &rust_i18n::t!("book.strategy"), strategy);
+    }
+    out
+}
+
+fn center_page(db: &HdDatabase, center: &Center) -> String {
+    let mut out = String::new();
+    let data = &db.centers[center.key()];
+    writeln!(out, "# {}\n\n{}", data.name, data.description.trim()).unwrap();
+    section(&mut out, &rust_i18n::t!("book.normal"), &data.normal);
+    section(&mut out, &rust_i18n::t!("book.distorted"), &data.distorted);
+    if let Some(ref question) = data.not_self_question {
+        section(&mut out, &rust_i18n::t!("book.not_self_question"), question);
+    }
+    if let Some(ref wisdom) = data.wisdom {
+        section(&mut out, &rust_i18n::t!("book.wisdom"), wisdom);
+    }
+
+    writeln!(out, "\n## {}\n", rust_i18n::t!("book.gates")).unwrap();
+    for gate in centers::gates_for_center(center) {
+        writeln!(out, "- {}", gate_link(db, gate)).unwrap();
+    }
+
+    writeln!(out, "\n## {}\n", rust_i18n::t!("book.channels")).unwrap();
+    for ch in channels::unique_channels(channels::all_channels())
+        .iter()
+        .filter(|ch| ch.center_a == *center || ch.center_b == *center)
+    {
+        writeln!(out, "- {}", channel_link(db, ch)).unwrap();
+    }
+    out
+}
+
+fn channel_page(db: &HdDatabase, ch: &ChannelDef) -> String {
+    let mut out = String::new();
+    let data = db
+        .channels
+        .get(&ch.key())
+        .or_else(|| db.channels.get(&format!("{}-{}", ch.gate_b, ch.gate_a)));
+    writeln!(
+        out,
+        "# {}",
+        rust_i18n::t!(
+            "book.channel_title",
+            key = ch.key(),
+            name = calc::channel_name(db, ch)
+        )
+    )
+    .unwrap();
+    if let Some(data) = data {
+        writeln!(out, "\n{}", data.description.trim()).unwrap();
+    }
+
+    writeln!(
+        out,
+        "\n- {}: {}, {}",
+        rust_i18n::t!("book.gates"),
+        gate_link(db, ch.gate_a),
+        gate_link(db, ch.gate_b)
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "- {}: {}, {}",
+        rust_i18n::t!("book.centers"),
+        center_link(db, &ch.center_a),
+        center_link(db, &ch.center_b)
+    )
+    .unwrap();
+    if let Some(circuit) = data.and_then(|d| d.circuit.as_ref()) {
+        let meta = db.circuits.get(circuit);
+        let mut name = meta.map(|m| m.name.clone()).unwrap_or_else(|| circuit.clone());
+        if let Some(sub) = data.and_then(|d| d.sub_circuit.as_ref()) {
+            let sub_name = meta
+                .and_then(|m| m.sub_circuits.get(sub))
+                .map(|s| s.name.clone())
+                .unwrap_or_else(|| sub.clone());
+            name = format!("{} / {}", name, sub_name);
+        }
+        writeln!(out, "- {}: {}", rust_i18n::t!("book.circuit"), name).unwrap();
+    }
+    out
+}
+
+fn gate_page(db: &HdDatabase, gate: u8) -> String {
+    let mut out = String::new();
+    let data = &db.gates[&gate.to_string()];
+    writeln!(
+        out,
+        "# {}",
+        rust_i18n::t!("book.gate_title", gate = gate, name = data.name)
+    )
+    .unwrap();
+    if let Some(keynote) = data.keynote.as_deref().filter(|k| !k.is_empty()) {
+        writeln!(out, "\n> {}", keynote).unwrap();
+    }
+    writeln!(out, "\n{}", data.description.trim()).unwrap();
+
+    if let Some(center) = Center::all()
+        .iter()
+        .find(|c| centers::gates_for_center(c).contains(&gate))
+    {
+        writeln!(out, "\n- {}: {}", rust_i18n::t!("book.center"), center_link(db, center)).unwrap();
+    }
+    for ch in channels::unique_channels(channels::all_channels())
+        .iter()
+        .filter(|ch| ch.gate_a == gate || ch.gate_b == gate)
+    {
+        writeln!(out, "- {}: {}", rust_i18n::t!("book.channel"), channel_link(db, ch)).unwrap();
+    }
+
+    writeln!(out, "\n## {}", rust_i18n::t!("book.lines")).unwrap();
+    for line in 1..=6 {
+        if let Some(line_data) = data.lines.get(&line.to_string()) {
+            writeln!(
+                out,
+                "\n### {}\n\n{}",
+                rust_i18n::t!("book.line", line = line),
+                line_data.description.trim()
+            )
+            .unwrap();
+        }
+    }
+
+    for (key, text) in [
+        ("book.fear", &data.fear),
+        ("book.sexuality", &data.sexuality),
+        ("book.love", &data.love),
+        ("book.business", &data.business),
+    ] {
+        if let Some(text) = text {
+            section(&mut out, &rust_i18n::t!(key), text);
+        }
+    }
+    out
+}
@@ -0,0 +1,97 @@
+/// Opt-in disk cache of computed charts, keyed by a fingerprint of everything that shapes them
+use crate::models::HdChart;
+use directories::ProjectDirs;
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::PathBuf;
+
+/// Number and total size of cached charts
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CacheStats {
+    pub entries: usize,
+    pub bytes: u64,
+}
+
+/// Directory of cached charts, one JSON file per fingerprint
+#[derive(Debug, Clone)]
+pub struct ChartCache {
+    dir: PathBuf,
+}
+
+/// Stable key for a chart request. The crate version is always included, so
+/// charts cached by older builds (with older data or calculations) are never reused.
+pub fn fingerprint(parts: &[&str]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(env!("CARGO_PKG_VERSION"));
+    for part in parts {
+        hasher.update([0u8]);
+        hasher.update(part);
+    }
+    hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+impl ChartCache {
+    /// Cache under the project cache directory; `None` when it can't be determined
+    pub fn open() -> Option<Self> {
+        ProjectDirs::from("com", "nimblemo", "hd-cli").map(|proj_dirs| Self {
+            dir: proj_dirs.cache_dir().join("charts"),
+        })
+    }
+
+    pub fn dir(&self) -> &PathBuf {
+        &self.dir
+    }
+
+    /// Cached chart for `key`; unreadable or outdated entries count as misses
+    pub fn get(&self, key: &str) -> Option<HdChart> {
+        let content = fs::read_to_string(self.path(key)).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    /// Store a chart under `key`
+    pub fn put(&self, key: &str, chart: &HdChart) -> Result<(), String> {
+        fs::create_dir_all(&self.dir).map_err(|e| e.to_string())?;
+        let content = serde_json::to_string(chart).map_err(|e| e.to_string())?;
+        fs::write(self.path(key), content).map_err(|e| e.to_string())
+    }
+
+    /// Remove every cached chart, returning how many there were
+    pub fn clear(&self) -> Result<usize, String> {
+        let entries = self.entries();
+        for path in &entries {
+            fs::remove_file(path).map_err(|e| e.to_string())?;
+        }
+        Ok(entries.len())
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        let entries = self.entries();
+        CacheStats {
+            entries: entries.len(),
+            bytes: entries
+                .iter()
+                .filter_map(|path| fs::metadata(path).ok())
+                .map(|m| m.len())
+                .sum(),
+        }
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{}.json", key))
+    }
+
+    fn entries(&self) -> Vec<PathBuf> {
+        fs::read_dir(&self.dir)
+            .map(|dir| {
+                dir.filter_map(|entry| entry.ok())
+                    .map(|entry| entry.path())
+                    .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
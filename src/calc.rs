@@ -1,11 +1,24 @@
-use crate::astro_calc::{self, HdPlanet};
+use crate::astro_calc::{self, CalcOptions, HdPlanet};
 use crate::circuit_score;
-use crate::data::centers::Center;
+use crate::data::centers::{self, Center};
 use crate::data::channels::{self, ChannelDef};
-use crate::data::database::{self, HdDatabase};
+use crate::data::database::{self, HdDatabase, MetaObject};
+use crate::data::awareness;
+use crate::data::crosses;
+use crate::data::dreamrave;
 use crate::data::gates;
+use crate::data::penta;
 use crate::models::*;
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+/// Birth time is entered to the minute, so the true moment lies within this window
+const BIRTH_TIME_UNCERTAINTY_DAYS: f64 = 1.0 / 1440.0;
+
+/// Time sensitivity looks this far around the birth time for a Sun/Moon gate or line change
+const SENSITIVITY_SCAN_DAYS: f64 = 1.0;
+
+/// Time sensitivity scan step: the Moon needs close to two hours to cross a line
+const SENSITIVITY_STEP_DAYS: f64 = 10.0 / 1440.0;
 
 pub fn build_chart(
     year: i32,
@@ -13,31 +26,128 @@ pub fn build_chart(
     day: u8,
     hour: u8,
     min: u8,
+    sec: u8,
     utc_offset: f64,
+    bodies: &[HdPlanet],
     full: bool,
     lang: &str,
-) -> HdChart {
-    let db = database::get_database(lang);
+    calc_opts: &CalcOptions,
+) -> Result<HdChart, String> {
+    let personality_jd =
+        astro_calc::calc_julian_day((year, month, day), (hour, min, sec), utc_offset, calc_opts.calendar);
+    let mut chart = build_chart_from_jd(personality_jd, bodies, full, lang, calc_opts)?;
 
-    let personality_jd = astro_calc::calc_julian_day(year, month, day, hour, min, utc_offset);
-    let personality_positions = astro_calc::calc_planet_positions(personality_jd);
+    // Keep the birth moment as entered (local time)
+    chart.birth_date = format!("{:04}-{:02}-{:02}", year, month, day);
+    chart.birth_time = if sec == 0 {
+        format!("{:02}:{:02}", hour, min)
+    } else {
+        format!("{:02}:{:02}:{:02}", hour, min, sec)
+    };
+    chart.utc_offset = utc_offset;
+    Ok(chart)
+}
 
-    let sun_pos = personality_positions
-        .iter()
-        .find(|p| p.planet == HdPlanet::Sun)
-        .unwrap();
-    let design_jd = astro_calc::find_design_jd(personality_jd, sun_pos.ecliptic_lng);
+/// Birth moment and options for library callers, as an alternative to
+/// passing the separate date/time components to `build_chart`
+#[derive(Debug, Clone)]
+pub struct ChartRequest {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub min: u8,
+    pub sec: u8,
+    pub utc_offset: f64,
+    pub bodies: Vec<HdPlanet>,
+    /// Include descriptions (the CLI's default, i.e. not `--short`)
+    pub full: bool,
+    /// Database language ("en" by default); interface strings follow the rust-i18n locale
+    pub lang: String,
+    /// Engine, corrections and expert overrides, the standard calculation by default
+    pub calc_opts: CalcOptions,
+}
 
-    let design_positions = astro_calc::calc_planet_positions(design_jd);
+impl ChartRequest {
+    /// Request from a zoned datetime; the zone's offset at that moment becomes `utc_offset`
+    pub fn from_datetime<Tz: chrono::TimeZone>(dt: &chrono::DateTime<Tz>) -> Self {
+        use chrono::Offset;
+        let offset_secs = dt.offset().fix().local_minus_utc();
+        Self::from_naive(dt.naive_local(), offset_secs as f64 / 3600.0)
+    }
 
-    let pers_gates: Vec<_> = personality_positions
-        .iter()
-        .map(|p| (p.planet, gates::degree_to_gate(p.ecliptic_lng)))
-        .collect();
-    let des_gates: Vec<_> = design_positions
-        .iter()
-        .map(|p| (p.planet, gates::degree_to_gate(p.ecliptic_lng)))
-        .collect();
+    /// Request from a local datetime and its UTC offset in hours
+    pub fn from_naive(dt: chrono::NaiveDateTime, utc_offset: f64) -> Self {
+        use chrono::{Datelike, Timelike};
+        Self {
+            year: dt.year(),
+            month: dt.month() as u8,
+            day: dt.day() as u8,
+            hour: dt.hour() as u8,
+            min: dt.minute() as u8,
+            sec: dt.second() as u8,
+            utc_offset,
+            bodies: HdPlanet::all(),
+            full: true,
+            lang: "en".to_string(),
+            calc_opts: CalcOptions::default(),
+        }
+    }
+
+    /// Same request with descriptions from the `lang` database ("en", "ru", "es", "zh")
+    pub fn with_lang(mut self, lang: &str) -> Self {
+        self.lang = lang.to_string();
+        self
+    }
+
+    /// Personality Julian Day of the requested moment
+    pub fn julian_day(&self) -> f64 {
+        astro_calc::calc_julian_day(
+            (self.year, self.month, self.day),
+            (self.hour, self.min, self.sec),
+            self.utc_offset,
+            self.calc_opts.calendar,
+        )
+    }
+
+    pub fn build(&self) -> Result<HdChart, String> {
+        build_chart(
+            self.year,
+            self.month,
+            self.day,
+            self.hour,
+            self.min,
+            self.sec,
+            self.utc_offset,
+            &self.bodies,
+            self.full,
+            &self.lang,
+            &self.calc_opts,
+        )
+    }
+}
+
+/// Build a chart directly from the Personality Julian Day (UT);
+/// birth date/time are reported in UTC
+pub fn build_chart_from_jd(
+    personality_jd: f64,
+    bodies: &[HdPlanet],
+    full: bool,
+    lang: &str,
+    calc_opts: &CalcOptions,
+) -> Result<HdChart, String> {
+    use chrono::Timelike;
+    let db = &*database::get_database(lang);
+
+    let pers_positions = gate_activations(personality_jd, bodies, calc_opts);
+    let design_jd = natal_design_jd(personality_jd, &pers_positions, calc_opts)?;
+    let des_positions = gate_activations(design_jd, bodies, calc_opts);
+    // Optional bodies are listed with the planets but define no gates
+    let hd_only = |positions: &Activations| -> Activations {
+        positions.iter().filter(|(p, _)| !p.is_extra()).cloned().collect()
+    };
+    let pers_gates = hd_only(&pers_positions);
+    let des_gates = hd_only(&des_positions);
 
     let mut all_active_gates: Vec<u8> = Vec::new();
     for (_, gp) in &pers_gates {
@@ -53,7 +163,8 @@ pub fn build_chart(
     let active_channels = channels::unique_channels(active_channels);
 
     let defined_centers = find_defined_centers(&active_channels);
-    let type_key = determine_type(&defined_centers, &active_channels);
+    let type_kind = determine_type(&defined_centers, &active_channels);
+    let type_key = type_kind.key().to_string();
     let type_meta = db.types.get(&type_key);
     let hd_type = type_meta
         .map(|m| m.name.clone())
@@ -64,13 +175,19 @@ pub fn build_chart(
         None
     };
 
-    let authority_key = determine_authority(&defined_centers);
-    let authority_meta = db.authorities.get(&authority_key);
-    let authority = authority_meta
+    let authority_kind = determine_authority(&defined_centers, type_kind);
+    let authority_key = authority_kind.key().to_string();
+    let authority_name_key = format!("authority.{}", authority_key);
+    let authority = db
+        .authorities
+        .get(&authority_key)
         .map(|m| m.name.clone())
-        .unwrap_or_else(|| authority_key.clone());
+        .unwrap_or_else(|| rust_i18n::t!(&authority_name_key).to_string());
     let authority_description = if full {
-        authority_meta.map(|m| m.description.clone())
+        db.authorities
+            .get(&authority_key)
+            .or_else(|| db.authorities.get(authority_kind.legacy_key()))
+            .map(|m| m.description.clone())
     } else {
         None
     };
@@ -82,6 +199,21 @@ pub fn build_chart(
         None
     };
 
+    let not_self_key = format!("not_self.{}", type_key);
+    let not_self_theme = rust_i18n::t!(&not_self_key).to_string();
+    let not_self_description = if full {
+        db.not_self_themes.get(&type_key).cloned()
+    } else {
+        None
+    };
+    let signature_key = format!("signature.{}", type_key);
+    let signature = rust_i18n::t!(&signature_key).to_string();
+    let signature_description = if full {
+        db.signatures.get(&type_key).cloned()
+    } else {
+        None
+    };
+
     let pers_sun_gp = pers_gates
         .iter()
         .find(|(p, _)| *p == HdPlanet::Sun)
@@ -97,6 +229,14 @@ pub fn build_chart(
     } else {
         None
     };
+    let (personality_line, design_line) = if full {
+        (
+            profile_line_item(db, pers_sun_gp.1.line, &rust_i18n::t!("cli.label.personality")),
+            profile_line_item(db, des_sun_gp.1.line, &rust_i18n::t!("cli.label.design")),
+        )
+    } else {
+        (None, None)
+    };
 
     let pers_earth_gp = pers_gates
         .iter()
@@ -114,24 +254,50 @@ pub fn build_chart(
         _ => "right_angle", // Fallback
     };
 
-    let cross_db_key_opt = find_cross_key_in_db(db, &pers_sun_gp.1.gate.to_string(), angle_key);
+    let chart_cross_gates = [pers_sun_gp.1.gate, pers_earth_gp.1.gate, des_sun_gp.1.gate, des_earth_gp.1.gate];
+    let catalog_cross = crosses::lookup(angle_key, pers_sun_gp.1.gate);
+    let (cross_meta, catalog_meta) = find_cross_in_db(db, angle_key, chart_cross_gates, catalog_cross);
 
-    let (cross_name, cross_desc) = if let Some(ref key) = cross_db_key_opt {
-        let meta = db.crosses.get(key);
-        (
-            meta.map(|m| m.name.clone()),
-            if full {
-                meta.map(|m| m.description.clone())
-            } else {
-                None
-            },
-        )
+    // A database entry under the catalog key names the cross, otherwise the built-in catalog does
+    let cross_name = if catalog_meta {
+        cross_meta.map(|m| m.name.clone())
     } else {
-        (None, None)
+        catalog_cross.map(|c| c.name())
+    };
+    let cross_desc = if full {
+        cross_meta.map(|m| m.description.clone())
+    } else {
+        None
     };
 
     let cross_description = cross_desc;
 
+    let cross_gates = if full {
+        Some(
+            [
+                ("personality", pers_sun_gp),
+                ("personality", pers_earth_gp),
+                ("design", des_sun_gp),
+                ("design", des_earth_gp),
+            ]
+            .iter()
+            .map(|(side, (planet, gp))| {
+                let gate_data = db.gates.get(&gp.gate.to_string());
+                CrossGate {
+                    side: side.to_string(),
+                    planet: planet.name(),
+                    planet_symbol: planet.symbol(),
+                    gate: gp.gate,
+                    gate_name: gate_data.map(|g| g.name.clone()),
+                    keynote: gate_data.map(|g| g.keynote_or_summary()),
+                }
+            })
+            .collect(),
+        )
+    } else {
+        None
+    };
+
     let incarnation_cross = if let Some(name) = cross_name {
         format!(
             "{} ({}/{} | {}/{})",
@@ -249,6 +415,31 @@ pub fn build_chart(
         None
     };
 
+    let cognition = {
+        const SENSES: [&str; 6] = ["smell", "taste", "outer_vision", "inner_vision", "feeling", "touch"];
+        let key = SENSES[(des_sun_tone.clamp(1, 6) - 1) as usize];
+        let meta = db.cognition.get(&des_sun_tone.to_string());
+        let name_key = format!("cli.cognition.{}", key);
+        Cognition {
+            tone: des_sun_tone,
+            key: key.to_string(),
+            name: meta
+                .map(|m| m.name.clone())
+                .unwrap_or_else(|| rust_i18n::t!(&name_key).to_string()),
+            description: meta.map(|m| m.description.clone()),
+        }
+    };
+
+    let variable = match (des_node_gp, pers_node_gp) {
+        (Some((_, des_node)), Some((_, pers_node))) => Some(determine_variable(
+            pers_sun_gp.1.tone,
+            pers_node.tone,
+            des_sun_tone,
+            des_node.tone,
+        )),
+        _ => None,
+    };
+
     let mut fears = Vec::new();
     let mut sexualities = Vec::new();
     let mut loves = Vec::new();
@@ -268,7 +459,7 @@ pub fn build_chart(
 
     for gate_id in &all_active_gates {
         if let Some(gate_data) = db.gates.get(&gate_id.to_string()) {
-            let mut planets = HashSet::new();
+            let mut planets = BTreeSet::new();
             for (planet, gate) in &pers_gates {
                 if gate.gate == *gate_id {
                     planets.insert(PlanetShortInfo {
@@ -337,8 +528,15 @@ pub fn build_chart(
     };
     let love = if loves.is_empty() { None } else { Some(loves) };
 
-    let personality = build_planet_positions(&pers_gates, db, full);
-    let design = build_planet_positions(&des_gates, db, full);
+    let awareness_streams = build_awareness_streams(&all_active_gates, db, full);
+    let nodes = build_node_themes(&pers_gates, &des_gates, db, full);
+    let active_gates = build_active_gates(&pers_gates, &des_gates, db);
+    let emphasis = build_gate_emphasis(&active_gates);
+    let hanging_gates = build_hanging_gates(&active_gates, &active_channels, db);
+
+    let personality =
+        build_planet_positions(&pers_positions, personality_jd, "personality", db, full, calc_opts);
+    let design = build_planet_positions(&des_positions, design_jd, "design", db, full, calc_opts);
 
     let circuit_scores = if full {
         Some(circuit_score::calculate_circuit_scores(
@@ -367,11 +565,37 @@ pub fn build_chart(
                 .get(&key_min_max)
                 .or_else(|| db.channels.get(&key_max_min));
 
+            let gates: Vec<GateActivation> = [min, max]
+                .iter()
+                .map(|&gate| GateActivation {
+                    gate,
+                    activation: gate_activation_side(gate, &pers_gates, &des_gates).to_string(),
+                })
+                .collect();
+            let awareness = channel_awareness(&gates).to_string();
+
+            let circuit = ch_data.and_then(|c| c.circuit.clone());
+            let sub_circuit = ch_data.and_then(|c| c.sub_circuit.clone());
+            let circuit_meta = circuit.as_ref().and_then(|c| db.circuits.get(c));
+            let circuit_name = circuit_meta.map(|m| m.name.clone()).or(circuit.clone());
+            let sub_circuit_name = sub_circuit
+                .as_ref()
+                .and_then(|s| circuit_meta.and_then(|m| m.sub_circuits.get(s)))
+                .map(|m| m.name.clone())
+                .or(sub_circuit.clone());
+
             ChannelInfo {
                 key: key_min_max.clone(),
                 name: ch_data
                     .and_then(|c| c.name.clone())
                     .unwrap_or_else(|| key_min_max.clone()),
+                gates,
+                awareness,
+                centers: vec![ch.center_a, ch.center_b],
+                circuit,
+                circuit_name,
+                sub_circuit,
+                sub_circuit_name,
                 description: if full {
                     ch_data.map(|c| c.description.clone())
                 } else {
@@ -402,11 +626,35 @@ pub fn build_chart(
                 (None, None)
             };
 
+            // Open-center analysis: conditioning question and potential wisdom
+            let open_data = center_data_opt.filter(|_| full && !defined);
+            let not_self_question = open_data.and_then(|d| d.not_self_question.clone());
+            let wisdom = open_data.and_then(|d| d.wisdom.clone());
+
+            let gates = if full {
+                Some(
+                    centers::gates_for_center(c)
+                        .into_iter()
+                        .map(|gate| GateActivation {
+                            gate,
+                            activation: gate_activation_side(gate, &pers_gates, &des_gates)
+                                .to_string(),
+                        })
+                        .collect(),
+                )
+            } else {
+                None
+            };
+
             CenterInfo {
+                key: *c,
                 name,
                 defined,
+                gates,
                 behavior_normal,
                 behavior_distorted,
+                not_self_question,
+                wisdom,
             }
         })
         .collect();
@@ -417,7 +665,7 @@ pub fn build_chart(
             if let Some(gate_data) = db.gates.get(&gate_id.to_string()) {
                 if let Some(b) = &gate_data.business {
                     // Find planets
-                    let mut planets = HashSet::new();
+                    let mut planets = BTreeSet::new();
                     for (planet, gate) in &pers_gates {
                         if gate.gate == *gate_id {
                             planets.insert(PlanetShortInfo {
@@ -467,51 +715,1127 @@ pub fn build_chart(
         None
     };
 
-    HdChart {
-        birth_date: format!("{:04}-{:02}-{:02}", year, month, day),
-        birth_time: format!("{:02}:{:02}", hour, min),
-        utc_offset,
+    let areas = definition_areas(&defined_centers, &active_channels);
+    let circuitry = build_circuitry(&channel_infos, db, full);
+    let definition_key = definition_key(areas.len());
+    let definition_label_key = format!("cli.definition.{}", definition_key);
+    let definition = rust_i18n::t!(&definition_label_key).to_string();
+
+    let bridges = (areas.len() > 1).then(|| {
+        let center_names = |area: &[Center]| {
+            area.iter()
+                .map(|c| {
+                    db.centers
+                        .get(c.key())
+                        .map(|d| d.name.clone())
+                        .unwrap_or_else(|| c.key().to_string())
+                })
+                .collect::<Vec<_>>()
+        };
+        find_bridges(&areas, &all_active_gates)
+            .into_iter()
+            .map(|(i, j, path)| {
+                let mut gates: Vec<u8> = path
+                    .iter()
+                    .flat_map(|ch| [ch.gate_a, ch.gate_b])
+                    .filter(|g| !all_active_gates.contains(g))
+                    .collect();
+                gates.dedup();
+                Bridge {
+                    areas: vec![center_names(&areas[i]), center_names(&areas[j])],
+                    gates,
+                    channels: path.iter().map(|ch| ch.key()).collect(),
+                }
+            })
+            .collect()
+    });
+
+    let birth_utc = astro_calc::jd_to_utc(personality_jd);
+
+    let activated_on = |side: &str| {
+        active_gates
+            .iter()
+            .filter(|g| g.activation == side || g.activation == "both")
+            .count()
+    };
+    let summary = ChartSummary {
+        defined_centers: defined_centers.len(),
+        channels: channel_infos.len(),
+        gates: active_gates.len(),
+        conscious_gates: activated_on("personality"),
+        unconscious_gates: activated_on("design"),
+    };
+
+    // Only reported when an expert option moved away from the standard calculation
+    let research = ResearchSettings {
+        design_arc: calc_opts.design_arc,
+        wheel_offset: calc_opts.wheel_offset,
+        true_node: calc_opts.node == astro_calc::NodeModel::True,
+        ayanamsa: calc_opts.sidereal_wheel.map(|a| a.key().to_string()),
+    };
+    let research = (research.design_arc != astro_calc::DEFAULT_DESIGN_ARC
+        || research.wheel_offset != 0.0
+        || research.true_node
+        || research.ayanamsa.is_some())
+        .then_some(research);
+
+    let activations = build_activations(&personality, &design);
+
+    Ok(HdChart {
+        birth_date: birth_utc.format("%Y-%m-%d").to_string(),
+        // Seconds only when there are any, as `build_chart` shows entered times
+        birth_time: birth_utc
+            .format(if birth_utc.second() == 0 { "%H:%M" } else { "%H:%M:%S" })
+            .to_string(),
+        utc_offset: 0.0,
+        personality_jd,
+        design_jd,
+        design_utc: astro_calc::jd_to_utc(design_jd)
+            .format("%Y-%m-%d %H:%M:%S")
+            .to_string(),
+        moon_phase: moon_phase(personality_jd, calc_opts),
+        summary,
+        life_cycle: None,
+        time_sensitivity: None,
         hd_type,
+        type_key: type_kind,
         type_description,
         profile,
+        profile_key: Profile::from_lines(pers_sun_gp.1.line, des_sun_gp.1.line),
         profile_description,
+        personality_line,
+        design_line,
         authority,
+        authority_key: authority_kind,
         authority_description,
         strategy,
         strategy_description,
+        signature,
+        signature_description,
+        not_self_theme,
+        not_self_description,
+        definition,
+        definition_key: definition_key.to_string(),
+        bridges,
         incarnation_cross,
         cross_description,
+        cross_gates,
         personality,
         design,
+        nodes,
+        active_gates,
+        activations,
+        emphasis,
+        hanging_gates,
         channels: channel_infos,
         centers: center_infos,
         business,
+        variable,
+        cognition: Some(cognition),
         motivation,
         environment,
         diet,
         fear,
+        awareness_streams,
         sexuality,
         love,
         vision,
+        circuitry,
         circuit_scores,
+        advanced_depth: None,
+        research,
+        sidereal: None,
+    })
+}
+
+/// Moon phase at `jd`, from the Sun–Moon angle (each phase spans 45° centered on its exact angle)
+pub fn moon_phase(jd: f64, calc_opts: &CalcOptions) -> MoonPhase {
+    const PHASES: [&str; 8] = [
+        "new_moon",
+        "waxing_crescent",
+        "first_quarter",
+        "waxing_gibbous",
+        "full_moon",
+        "waning_gibbous",
+        "last_quarter",
+        "waning_crescent",
+    ];
+    let positions = astro_calc::positions_for(jd, &[HdPlanet::Sun, HdPlanet::Moon], calc_opts);
+    let angle = (positions[1].ecliptic_lng - positions[0].ecliptic_lng).rem_euclid(360.0);
+    let key = PHASES[((angle + 22.5) / 45.0) as usize % 8];
+    let illumination = (1.0 - angle.to_radians().cos()) / 2.0 * 100.0;
+    let name_key = format!("cli.moon.{}", key);
+    MoonPhase {
+        name: rust_i18n::t!(&name_key).to_string(),
+        key: key.to_string(),
+        angle: (angle * 10.0).round() / 10.0,
+        illumination: (illumination * 10.0).round() / 10.0,
+    }
+}
+
+/// Age on `now_jd` of someone born at `birth_jd`, and the life phase around the
+/// Saturn return (~29), Uranus opposition (~40-42) and Kiron return (~50).
+/// None for a birth moment in the future.
+pub fn life_cycle(birth_jd: f64, now_jd: f64) -> Option<LifeCycle> {
+    if now_jd < birth_jd {
+        return None;
+    }
+    let age = ((now_jd - birth_jd) / 365.2425).floor() as u32;
+    let phase = match age {
+        0..=27 => "pre_saturn_return",
+        28..=30 => "saturn_return",
+        31..=37 => "post_saturn_return",
+        38..=44 => "uranus_opposition",
+        45..=49 => "pre_kiron_return",
+        _ => "kiron_return",
+    };
+    let name_key = format!("cli.cycle.{}.name", phase);
+    let note_key = format!("cli.cycle.{}.note", phase);
+    Some(LifeCycle {
+        age,
+        phase: phase.to_string(),
+        name: rust_i18n::t!(&name_key).to_string(),
+        note: rust_i18n::t!(&note_key).to_string(),
+    })
+}
+
+/// Dream Rave bodygraph from the same Personality and Design positions
+pub fn build_dreamrave(
+    personality_jd: f64,
+    bodies: &[HdPlanet],
+    calc_opts: &CalcOptions,
+) -> Result<DreamRaveChart, String> {
+    let (pers_gates, des_gates) = natal_activations(personality_jd, bodies, calc_opts)?;
+    // One shift for both sides: a sidereal ayanamsa moves by seconds of arc in 88 days
+    let wheel_shift = calc_opts.wheel_shift(personality_jd);
+    let activations: Vec<DreamActivation> = [("personality", &pers_gates), ("design", &des_gates)]
+        .iter()
+        .flat_map(|(side, gates)| {
+            gates.iter().map(move |(planet, gp)| {
+                let dream_gate = dreamrave::dream_gate(gp.degree, wheel_shift);
+                DreamActivation {
+                    planet: planet.name(),
+                    planet_symbol: planet.symbol(),
+                    side: side.to_string(),
+                    dream_gate,
+                    center: dreamrave::dream_center(dream_gate).to_string(),
+                }
+            })
+        })
+        .collect();
+
+    let active = |gate: u8| activations.iter().any(|a| a.dream_gate == gate);
+    let channels: Vec<(u8, u8)> = dreamrave::DREAM_CHANNELS
+        .iter()
+        .copied()
+        .filter(|&(a, b)| active(a) && active(b))
+        .collect();
+
+    let centers = dreamrave::DREAM_CENTERS
+        .iter()
+        .map(|key| {
+            let mut gates: Vec<u8> = activations
+                .iter()
+                .filter(|a| a.center == *key)
+                .map(|a| a.dream_gate)
+                .collect();
+            gates.sort_unstable();
+            gates.dedup();
+            let name_key = format!("cli.dreamrave.center.{}", key);
+            DreamCenter {
+                key: key.to_string(),
+                name: rust_i18n::t!(&name_key).to_string(),
+                defined: channels
+                    .iter()
+                    .any(|&(a, b)| dreamrave::dream_center(a) == *key || dreamrave::dream_center(b) == *key),
+                gates,
+            }
+        })
+        .collect();
+
+    Ok(DreamRaveChart {
+        moment: astro_calc::jd_to_utc(personality_jd)
+            .format("%Y-%m-%d %H:%M")
+            .to_string(),
+        activations,
+        centers,
+        channels: channels.iter().map(|(a, b)| format!("{}-{}", a, b)).collect(),
+    })
+}
+
+/// Gene Keys Activation Sequence from the Personality and Design Sun/Earth
+pub fn build_gene_keys(personality_jd: f64, lang: &str, calc_opts: &CalcOptions) -> Result<GeneKeysChart, String> {
+    let db = database::get_database(lang);
+    let (pers_gates, des_gates) = natal_activations(personality_jd, &[HdPlanet::Sun, HdPlanet::Earth], calc_opts)?;
+    let spheres = [
+        ("lifes_work", "personality", &pers_gates, HdPlanet::Sun),
+        ("evolution", "personality", &pers_gates, HdPlanet::Earth),
+        ("radiance", "design", &des_gates, HdPlanet::Sun),
+        ("purpose", "design", &des_gates, HdPlanet::Earth),
+    ]
+    .iter()
+    .filter_map(|(key, side, gates, planet)| {
+        let (_, gp) = gates.iter().find(|(p, _)| p == planet)?;
+        let data = db.gene_keys.get(&gp.gate.to_string());
+        let name_key = format!("cli.genekeys.sphere.{}", key);
+        Some(GeneKeySphere {
+            key: key.to_string(),
+            name: rust_i18n::t!(&name_key).to_string(),
+            planet: planet.name(),
+            side: side.to_string(),
+            gate: gp.gate,
+            line: gp.line,
+            shadow: data.map_or_else(|| gene_key_name(gp.gate, "shadow"), |d| d.shadow.clone()),
+            gift: data.map_or_else(|| gene_key_name(gp.gate, "gift"), |d| d.gift.clone()),
+            siddhi: data.map_or_else(|| gene_key_name(gp.gate, "siddhi"), |d| d.siddhi.clone()),
+        })
+    })
+    .collect();
+
+    Ok(GeneKeysChart {
+        moment: astro_calc::jd_to_utc(personality_jd)
+            .format("%Y-%m-%d %H:%M")
+            .to_string(),
+        spheres,
+    })
+}
+
+/// Built-in Shadow/Gift/Siddhi name of a Gene Key in the interface language
+fn gene_key_name(gate: u8, frequency: &str) -> String {
+    let key = format!("cli.genekeys.keys.{}.{}", gate, frequency);
+    rust_i18n::t!(&key).to_string()
+}
+
+/// Longitudes (and optionally gates) of exactly the given bodies at `jd`, straight
+/// from the selected engine; no Design moment and no chart are calculated
+pub fn build_ephemeris(jd: f64, bodies: &[HdPlanet], with_gates: bool, calc_opts: &CalcOptions) -> Ephemeris {
+    let speeds = astro_calc::daily_speeds(jd, bodies, calc_opts);
+    let bodies = astro_calc::positions_for(jd, bodies, calc_opts)
+        .into_iter()
+        .zip(speeds)
+        .map(|(p, speed)| {
+            let gp = with_gates.then(|| gates::degree_to_gate(p.ecliptic_lng, calc_opts.wheel_shift(jd)));
+            EphemerisBody {
+                planet: p.planet.name(),
+                planet_symbol: p.planet.symbol(),
+                longitude: p.ecliptic_lng,
+                speed_deg_per_day: speed,
+                gate: gp.as_ref().map(|gp| gp.gate),
+                line: gp.as_ref().map(|gp| gp.line),
+            }
+        })
+        .collect();
+
+    Ephemeris {
+        moment: astro_calc::jd_to_utc(jd)
+            .format("%Y-%m-%d %H:%M:%S")
+            .to_string(),
+        julian_day: jd,
+        engine: calc_opts.engine.key().to_string(),
+        delta_t_seconds: if calc_opts.delta_t {
+            astro_calc::delta_t(jd)
+        } else {
+            0.0
+        },
+        apparent: calc_opts.apparent,
+        bodies,
+    }
+}
+
+/// Penta analysis of a group given by (name, Personality JD): which Penta
+/// channels the group defines, the roles nobody carries and the resulting dynamics
+pub fn build_penta(members: &[(String, f64)], calc_opts: &CalcOptions) -> Result<PentaReport, String> {
+    let member_gates: Vec<(String, Vec<u8>)> = members
+        .iter()
+        .map(|(name, jd)| {
+            let (pers_gates, des_gates) = natal_activations(*jd, &HdPlanet::all(), calc_opts)?;
+            let gates: Vec<u8> = pers_gates
+                .iter()
+                .chain(des_gates.iter())
+                .map(|(_, gp)| gp.gate)
+                .collect();
+            Ok((name.clone(), gates))
+        })
+        .collect::<Result<_, String>>()?;
+
+    let penta_gate = |gate: u8, role: &str| {
+        let role_key = format!("cli.penta.role.{}", role);
+        PentaGate {
+            gate,
+            role: rust_i18n::t!(&role_key).to_string(),
+            members: member_gates
+                .iter()
+                .filter(|(_, g)| g.contains(&gate))
+                .map(|(n, _)| n.clone())
+                .collect(),
+        }
+    };
+
+    let mut channels = Vec::new();
+    let mut missing_roles = Vec::new();
+    let mut gaps = Vec::new();
+    for def in &penta::PENTA_CHANNELS {
+        let gates = vec![penta_gate(def.gate_a, def.role_a), penta_gate(def.gate_b, def.role_b)];
+        let present = gates.iter().filter(|g| !g.members.is_empty()).count();
+        let status = match present {
+            2 => "defined",
+            1 => "gap",
+            _ => "missing",
+        };
+        let role_key = format!("cli.penta.channel.{}", def.role);
+        let role = rust_i18n::t!(&role_key).to_string();
+        for g in gates.iter().filter(|g| g.members.is_empty()) {
+            missing_roles.push(format!("{} ({})", g.role, g.gate));
+            if present == 1 {
+                gaps.push(
+                    rust_i18n::t!("cli.penta.dynamics.gap", channel = role, gate = g.gate)
+                        .to_string(),
+                );
+            }
+        }
+        channels.push(PentaChannel {
+            key: def.key(),
+            part: def.part.to_string(),
+            role,
+            status: status.to_string(),
+            gates,
+        });
     }
+
+    let part_defined = |part: &str| {
+        channels
+            .iter()
+            .filter(|c| c.part == part)
+            .all(|c| c.status == "defined")
+    };
+    let (upper, lower) = (part_defined("upper"), part_defined("lower"));
+    let mut summary = Vec::new();
+    if upper && lower {
+        summary.push(rust_i18n::t!("cli.penta.dynamics.complete").to_string());
+    }
+    if !upper {
+        summary.push(rust_i18n::t!("cli.penta.dynamics.upper_incomplete").to_string());
+    }
+    if !lower {
+        summary.push(rust_i18n::t!("cli.penta.dynamics.lower_incomplete").to_string());
+    }
+    summary.append(&mut gaps);
+
+    Ok(PentaReport {
+        members: member_gates.into_iter().map(|(n, _)| n).collect(),
+        channels,
+        missing_roles,
+        dynamics: summary,
+    })
+}
+
+/// Build a composite chart for two or more people given by (name, Personality JD)
+pub fn build_composite(
+    members: &[(String, f64)],
+    lang: &str,
+    calc_opts: &CalcOptions,
+) -> Result<CompositeChart, String> {
+    let db = &*database::get_database(lang);
+
+    // Every planetary activation of each member as (gate, activator)
+    let member_activations: Vec<Vec<(u8, MemberActivator)>> = members
+        .iter()
+        .map(|(name, jd)| {
+            let (pers_gates, des_gates) = natal_activations(*jd, &HdPlanet::all(), calc_opts)?;
+            Ok([("personality", pers_gates), ("design", des_gates)]
+                .into_iter()
+                .flat_map(|(side, positions)| {
+                    positions.into_iter().map(move |(planet, gp)| {
+                        (
+                            gp.gate,
+                            MemberActivator {
+                                member: name.clone(),
+                                planet: planet.name(),
+                                planet_symbol: planet.symbol(),
+                                side: side.to_string(),
+                                line: gp.line,
+                            },
+                        )
+                    })
+                })
+                .collect())
+        })
+        .collect::<Result<_, String>>()?;
+
+    let member_gates: Vec<(String, Vec<u8>)> = members
+        .iter()
+        .zip(&member_activations)
+        .map(|((name, _), activations)| {
+            let mut gates: Vec<u8> = activations.iter().map(|(gate, _)| *gate).collect();
+            gates.sort();
+            gates.dedup();
+            (name.clone(), gates)
+        })
+        .collect();
+
+    let mut all_gates: Vec<u8> = member_gates
+        .iter()
+        .flat_map(|(_, g)| g.iter().copied())
+        .collect();
+    all_gates.sort();
+    all_gates.dedup();
+
+    let composite_channels = channels::unique_channels(channels::find_active_channels(&all_gates));
+    let defined_centers = find_defined_centers(&composite_channels);
+    let type_key = determine_type(&defined_centers, &composite_channels)
+        .key()
+        .to_string();
+    let hd_type = db
+        .types
+        .get(&type_key)
+        .map(|m| m.name.clone())
+        .unwrap_or(type_key);
+
+    let center_name = |c: &Center| {
+        db.centers
+            .get(c.key())
+            .map(|d| d.name.clone())
+            .unwrap_or_else(|| c.key().to_string())
+    };
+
+    let has_channel = |gates: &[u8], ch: &ChannelDef| {
+        gates.contains(&ch.gate_a) && gates.contains(&ch.gate_b)
+    };
+
+    let channel_infos: Vec<CompositeChannel> = composite_channels
+        .iter()
+        .map(|ch| {
+            let owners: Vec<String> = member_gates
+                .iter()
+                .filter(|(_, g)| has_channel(g, ch))
+                .map(|(n, _)| n.clone())
+                .collect();
+            let half_holders = member_gates
+                .iter()
+                .filter(|(_, g)| !has_channel(g, ch))
+                .any(|(_, g)| g.contains(&ch.gate_a) || g.contains(&ch.gate_b));
+
+            let kind = match owners.len() {
+                0 => "electromagnetic",
+                1 if half_holders => "compromise",
+                1 => "dominance",
+                _ => "companionship",
+            };
+
+            let gates = [ch.gate_a, ch.gate_b]
+                .iter()
+                .map(|gate| GateContribution {
+                    gate: *gate,
+                    members: member_gates
+                        .iter()
+                        .filter(|(_, g)| g.contains(gate))
+                        .map(|(n, _)| n.clone())
+                        .collect(),
+                    activators: member_activations
+                        .iter()
+                        .flatten()
+                        .filter(|(g, _)| g == gate)
+                        .map(|(_, a)| a.clone())
+                        .collect(),
+                })
+                .collect();
+
+            let supply = member_gates
+                .iter()
+                .filter_map(|(n, g)| {
+                    let mut gates: Vec<u8> = [ch.gate_a, ch.gate_b]
+                        .into_iter()
+                        .filter(|gate| g.contains(gate))
+                        .collect();
+                    gates.sort();
+                    (!gates.is_empty()).then(|| MemberSupply {
+                        member: n.clone(),
+                        gates,
+                    })
+                })
+                .collect();
+
+            CompositeChannel {
+                key: ch.key(),
+                name: channel_name(db, ch),
+                kind: kind.to_string(),
+                owners,
+                gates,
+                supply,
+            }
+        })
+        .collect();
+
+    let member_infos: Vec<CompositeMember> = member_gates
+        .iter()
+        .map(|(name, gates)| {
+            let own: Vec<ChannelDef> =
+                channels::unique_channels(channels::find_active_channels(gates));
+            let own_centers = find_defined_centers(&own);
+            CompositeMember {
+                name: name.clone(),
+                gates: gates.clone(),
+                defined_centers: Center::all()
+                    .iter()
+                    .filter(|c| own_centers.contains(c))
+                    .map(center_name)
+                    .collect(),
+                own_channels: own.iter().map(|ch| ch.key()).collect(),
+                bridged_channels: composite_channels
+                    .iter()
+                    .filter(|ch| !has_channel(gates, ch))
+                    .filter(|ch| gates.contains(&ch.gate_a) || gates.contains(&ch.gate_b))
+                    .map(|ch| ch.key())
+                    .collect(),
+            }
+        })
+        .collect();
+
+    Ok(CompositeChart {
+        members: member_infos,
+        hd_type,
+        defined_centers: Center::all()
+            .iter()
+            .filter(|c| defined_centers.contains(c))
+            .map(center_name)
+            .collect(),
+        open_centers: Center::all()
+            .iter()
+            .filter(|c| !defined_centers.contains(c))
+            .map(center_name)
+            .collect(),
+        channels: channel_infos,
+    })
+}
+
+/// Composite summary for every pair of members (N×N overview for families and teams)
+pub fn build_matrix(members: &[(String, f64)], lang: &str, calc_opts: &CalcOptions) -> Result<ProfileMatrix, String> {
+    let mut pairs = Vec::new();
+    for (i, a) in members.iter().enumerate() {
+        for b in &members[i + 1..] {
+            let composite = build_composite(&[a.clone(), b.clone()], lang, calc_opts)?;
+            let count = |kind: &str| composite.channels.iter().filter(|c| c.kind == kind).count();
+            pairs.push(MatrixPair {
+                a: a.0.clone(),
+                b: b.0.clone(),
+                electromagnetic: count("electromagnetic"),
+                dominance: count("dominance"),
+                compromise: count("compromise"),
+                companionship: count("companionship"),
+                hd_type: composite.hd_type.clone(),
+                defined_centers: composite.defined_centers.len(),
+            });
+        }
+    }
+    Ok(ProfileMatrix {
+        members: members.iter().map(|(name, _)| name.clone()).collect(),
+        pairs,
+    })
+}
+
+/// Keynote of a single profile line, labelled with its side (Personality / Design)
+fn profile_line_item(db: &HdDatabase, line: u8, side: &str) -> Option<InfoItem> {
+    db.profile_lines.get(&line.to_string()).map(|m| InfoItem {
+        label: format!(
+            "{} {} ({}): {}",
+            rust_i18n::t!("cli.label.line"),
+            line,
+            side,
+            m.name
+        ),
+        description: m.description.clone(),
+        planets: None,
+        gate_id: None,
+        gate_name: None,
+    })
+}
+
+/// Localized channel name from the DB (falls back to the key)
+pub fn channel_name(db: &HdDatabase, ch: &ChannelDef) -> String {
+    db.channels
+        .get(&ch.key())
+        .or_else(|| db.channels.get(&format!("{}-{}", ch.gate_b, ch.gate_a)))
+        .and_then(|c| c.name.clone())
+        .unwrap_or_else(|| ch.key())
+}
+
+/// Planet → gate position pairs for one side of the chart
+pub type Activations = Vec<(HdPlanet, gates::GatePosition)>;
+
+/// Gate positions of the given planets (plus Sun and Earth) at the given Julian Day
+pub fn gate_activations(jd: f64, bodies: &[HdPlanet], calc_opts: &CalcOptions) -> Activations {
+    let wheel_shift = calc_opts.wheel_shift(jd);
+    astro_calc::calc_planet_positions(jd, bodies, calc_opts)
+        .iter()
+        .map(|p| (p.planet, gates::degree_to_gate(p.ecliptic_lng, wheel_shift)))
+        .collect()
+}
+
+/// Design moment (UT Julian Day) of a birth, from its Personality activations
+pub fn natal_design_jd(
+    personality_jd: f64,
+    pers_gates: &Activations,
+    calc_opts: &CalcOptions,
+) -> Result<f64, String> {
+    let sun_gp = pers_gates
+        .iter()
+        .find(|(p, _)| *p == HdPlanet::Sun)
+        .unwrap();
+    astro_calc::find_design_jd(personality_jd, sun_gp.1.degree, calc_opts.design_arc, calc_opts)
+}
+
+/// Personality and Design gate positions for a birth moment
+pub fn natal_activations(
+    personality_jd: f64,
+    bodies: &[HdPlanet],
+    calc_opts: &CalcOptions,
+) -> Result<(Activations, Activations), String> {
+    let pers_gates = gate_activations(personality_jd, bodies, calc_opts);
+    let design_jd = natal_design_jd(personality_jd, &pers_gates, calc_opts)?;
+    let des_gates = gate_activations(design_jd, bodies, calc_opts);
+    Ok((pers_gates, des_gates))
+}
+
+/// Substructure levels that differ between two gate positions
+fn changed_levels(a: &gates::GatePosition, b: &gates::GatePosition) -> Vec<&'static str> {
+    let mut levels = Vec::new();
+    if a.gate != b.gate || a.line != b.line || a.color != b.color {
+        levels.push("color");
+    }
+    if !levels.is_empty() || a.tone != b.tone {
+        levels.push("tone");
+    }
+    if !levels.is_empty() || a.base != b.base {
+        levels.push("base");
+    }
+    levels
+}
+
+/// Mark color/tone/base values that could change within the minute after the
+/// given birth time (the seconds are unknown), for both Personality and Design
+pub fn flag_uncertain_substructure(
+    chart: &mut HdChart,
+    personality_jd: f64,
+    bodies: &[HdPlanet],
+    calc_opts: &CalcOptions,
+) -> Result<(), String> {
+    let samples = [0.0, 0.5, 1.0]
+        .iter()
+        .map(|k| natal_activations(personality_jd + k * BIRTH_TIME_UNCERTAINTY_DAYS, bodies, calc_opts))
+        .collect::<Result<Vec<_>, String>>()?;
+    let (pers_samples, des_samples): (Vec<Activations>, Vec<Activations>) =
+        samples.into_iter().unzip();
+
+    let sides = [
+        (&mut chart.personality, pers_samples),
+        (&mut chart.design, des_samples),
+    ];
+    for (positions, side_samples) in sides {
+        for (i, pos) in positions.iter_mut().enumerate() {
+            let first = &side_samples[0][i].1;
+            let mut levels: Vec<&'static str> = Vec::new();
+            for sample in &side_samples[1..] {
+                for level in changed_levels(first, &sample[i].1) {
+                    if !levels.contains(&level) {
+                        levels.push(level);
+                    }
+                }
+            }
+            pos.uncertain = Some(levels.iter().map(|l| l.to_string()).collect());
+        }
+    }
+    Ok(())
+}
+
+/// Sun and Moon gate.line on both sides for a birth at `personality_jd`
+fn sun_moon_lines(
+    personality_jd: f64,
+    calc_opts: &CalcOptions,
+) -> Result<Vec<(&'static str, HdPlanet, u8, u8)>, String> {
+    let (pers, des) = natal_activations(personality_jd, &[HdPlanet::Sun, HdPlanet::Moon], calc_opts)?;
+    Ok([("personality", pers), ("design", des)]
+        .into_iter()
+        .flat_map(|(side, activations)| {
+            activations
+                .into_iter()
+                .filter(|(p, _)| matches!(p, HdPlanet::Sun | HdPlanet::Moon))
+                .map(move |(p, gp)| (side, p, gp.gate, gp.line))
+        })
+        .collect())
+}
+
+/// Nearest birth-time shift in `direction` (1.0 later, -1.0 earlier) that moves
+/// a Sun or Moon activation to another gate or line
+fn nearest_time_shift(
+    personality_jd: f64,
+    direction: f64,
+    calc_opts: &CalcOptions,
+) -> Result<Option<TimeShift>, String> {
+    let base = sun_moon_lines(personality_jd, calc_opts)?;
+    let mut inside = personality_jd;
+    let mut outside = personality_jd;
+    loop {
+        outside += SENSITIVITY_STEP_DAYS * direction;
+        if (outside - personality_jd).abs() > SENSITIVITY_SCAN_DAYS {
+            return Ok(None);
+        }
+        if sun_moon_lines(outside, calc_opts)? != base {
+            break;
+        }
+        inside = outside;
+    }
+
+    while (outside - inside).abs() > BIRTH_TIME_UNCERTAINTY_DAYS {
+        let mid = (inside + outside) / 2.0;
+        if sun_moon_lines(mid, calc_opts)? == base {
+            inside = mid;
+        } else {
+            outside = mid;
+        }
+    }
+
+    let changed = sun_moon_lines(outside, calc_opts)?;
+    Ok(base
+        .iter()
+        .zip(&changed)
+        .find(|(b, a)| b != a)
+        .map(|(before, after)| TimeShift {
+            minutes: ((outside - personality_jd) * 1440.0).round() as i64,
+            planet: before.1.name(),
+            side: before.0.to_string(),
+            from: format!("{}.{}", before.2, before.3),
+            to: format!("{}.{}", after.2, after.3),
+        }))
+}
+
+/// How far the birth time can move either way before a Sun or Moon gate/line changes
+pub fn time_sensitivity(personality_jd: f64, calc_opts: &CalcOptions) -> Result<TimeSensitivity, String> {
+    Ok(TimeSensitivity {
+        earlier: nearest_time_shift(personality_jd, -1.0, calc_opts)?,
+        later: nearest_time_shift(personality_jd, 1.0, calc_opts)?,
+    })
+}
+
+/// Collect the general meaning of every color, tone and base present in the chart,
+/// with the planets carrying it (values the database doesn't describe are skipped)
+pub fn add_depth_keynotes(chart: &mut HdChart, lang: &str) {
+    let db = database::get_database(lang);
+    let levels: [(&str, &HashMap<String, MetaObject>); 3] = [
+        ("color", &db.substructure.colors),
+        ("tone", &db.substructure.tones),
+        ("base", &db.substructure.bases),
+    ];
+
+    let mut items = Vec::new();
+    for (level, meanings) in levels {
+        let value_of = |p: &PlanetPosition| match level {
+            "color" => p.color,
+            "tone" => p.tone,
+            _ => p.base,
+        };
+        let mut values: Vec<u8> = chart
+            .personality
+            .iter()
+            .chain(&chart.design)
+            .map(&value_of)
+            .collect();
+        values.sort_unstable();
+        values.dedup();
+        for value in values {
+            let Some(meaning) = meanings.get(&value.to_string()) else {
+                continue;
+            };
+            let planets = chart
+                .personality
+                .iter()
+                .chain(&chart.design)
+                .filter(|p| value_of(p) == value)
+                .map(|p| PlanetShortInfo {
+                    name: p.planet.clone(),
+                    symbol: p.planet_symbol.clone(),
+                })
+                .collect();
+            let level_key = format!("cli.label.{}", level);
+            items.push(InfoItem {
+                label: format!("{} {} ({}):", rust_i18n::t!(&level_key), value, meaning.name),
+                description: meaning.description.clone(),
+                planets: Some(planets),
+                gate_id: None,
+                gate_name: None,
+            });
+        }
+    }
+    chart.advanced_depth = if items.is_empty() { None } else { Some(items) };
+}
+
+/// Channel awareness from the activation sides of its gates:
+/// "conscious" (all Personality), "unconscious" (all Design) or "mixed"
+fn channel_awareness(gates: &[GateActivation]) -> &'static str {
+    let conscious = gates
+        .iter()
+        .all(|g| g.activation == "personality" || g.activation == "both");
+    let unconscious = gates
+        .iter()
+        .all(|g| g.activation == "design" || g.activation == "both");
+    match (conscious, unconscious) {
+        (true, false) => "conscious",
+        (false, true) => "unconscious",
+        _ => "mixed",
+    }
+}
+
+/// Which side activates a gate: "personality", "design", "both" or "inactive"
+pub fn gate_activation_side(
+    gate: u8,
+    pers_gates: &[(HdPlanet, gates::GatePosition)],
+    des_gates: &[(HdPlanet, gates::GatePosition)],
+) -> &'static str {
+    let in_pers = pers_gates.iter().any(|(_, gp)| gp.gate == gate);
+    let in_des = des_gates.iter().any(|(_, gp)| gp.gate == gate);
+    match (in_pers, in_des) {
+        (true, true) => "both",
+        (true, false) => "personality",
+        (false, true) => "design",
+        (false, false) => "inactive",
+    }
+}
+
+/// Group activations by gate: which planets (and on which side) turn each gate on
+fn build_active_gates(
+    pers_gates: &[(HdPlanet, gates::GatePosition)],
+    des_gates: &[(HdPlanet, gates::GatePosition)],
+    db: &HdDatabase,
+) -> Vec<ActiveGate> {
+    let mut by_gate: std::collections::BTreeMap<u8, Vec<GateActivator>> =
+        std::collections::BTreeMap::new();
+    for (side, activations) in [("personality", pers_gates), ("design", des_gates)] {
+        for (planet, gp) in activations {
+            by_gate.entry(gp.gate).or_default().push(GateActivator {
+                planet: planet.name(),
+                planet_symbol: planet.symbol(),
+                side: side.to_string(),
+                line: gp.line,
+            });
+        }
+    }
+
+    by_gate
+        .into_iter()
+        .map(|(gate, activators)| {
+            let gate_data = db.gates.get(&gate.to_string());
+            ActiveGate {
+                gate,
+                activation: gate_activation_side(gate, pers_gates, des_gates).to_string(),
+                gate_name: gate_data.map(|g| g.name.clone()).unwrap_or_default(),
+                keynote: gate_data.map(|g| g.keynote_or_summary()),
+                activators,
+            }
+        })
+        .collect()
+}
+
+/// Awareness streams with at least one activated gate, complete when all their channels are defined
+fn build_awareness_streams(
+    all_active_gates: &[u8],
+    db: &HdDatabase,
+    full: bool,
+) -> Option<Vec<AwarenessStream>> {
+    let streams: Vec<AwarenessStream> = awareness::AWARENESS_STREAMS
+        .iter()
+        .filter_map(|def| {
+            let gates = def.gates();
+            let active_gates: Vec<u8> = gates
+                .iter()
+                .copied()
+                .filter(|g| all_active_gates.contains(g))
+                .collect();
+            if active_gates.is_empty() {
+                return None;
+            }
+            let defined_channels: Vec<String> = def
+                .channels
+                .iter()
+                .filter(|(a, b)| all_active_gates.contains(a) && all_active_gates.contains(b))
+                .map(|&(a, b)| format!("{}-{}", a.min(b), a.max(b)))
+                .collect();
+            let meta = db.awareness_streams.get(def.key);
+            let name_key = format!("cli.awareness.stream.{}", def.key);
+            let center_key = def.center.key();
+            Some(AwarenessStream {
+                key: def.key.to_string(),
+                name: meta
+                    .map(|m| m.name.clone())
+                    .unwrap_or_else(|| rust_i18n::t!(&name_key).to_string()),
+                center: center_key.to_string(),
+                center_name: db
+                    .centers
+                    .get(center_key)
+                    .map(|c| c.name.clone())
+                    .unwrap_or_else(|| center_key.to_string()),
+                complete: defined_channels.len() == def.channels.len(),
+                gates,
+                active_gates,
+                defined_channels,
+                description: if full {
+                    meta.map(|m| m.description.clone())
+                } else {
+                    None
+                },
+            })
+        })
+        .collect();
+    if streams.is_empty() {
+        None
+    } else {
+        Some(streams)
+    }
+}
+
+/// Active gates outside every active channel, in center order, with the
+/// partner gates that would complete each of their channels
+fn build_hanging_gates(
+    active_gates: &[ActiveGate],
+    active_channels: &[ChannelDef],
+    db: &HdDatabase,
+) -> Vec<HangingGate> {
+    let all_channels = channels::unique_channels(channels::all_channels());
+    let mut hanging = Vec::new();
+    for center in Center::all() {
+        let center_gates = centers::gates_for_center(center);
+        for ag in active_gates.iter().filter(|ag| center_gates.contains(&ag.gate)) {
+            if active_channels
+                .iter()
+                .any(|ch| ch.gate_a == ag.gate || ch.gate_b == ag.gate)
+            {
+                continue;
+            }
+            let completions = all_channels
+                .iter()
+                .filter_map(|ch| {
+                    let partner = if ch.gate_a == ag.gate {
+                        ch.gate_b
+                    } else if ch.gate_b == ag.gate {
+                        ch.gate_a
+                    } else {
+                        return None;
+                    };
+                    Some(GateCompletion {
+                        gate: partner,
+                        channel: ch.key(),
+                    })
+                })
+                .collect();
+            hanging.push(HangingGate {
+                gate: ag.gate,
+                gate_name: ag.gate_name.clone(),
+                activation: ag.activation.clone(),
+                center: *center,
+                center_name: db
+                    .centers
+                    .get(center.key())
+                    .map(|d| d.name.clone())
+                    .unwrap_or_else(|| center.key().to_string()),
+                completions,
+            });
+        }
+    }
+    hanging
+}
+
+/// Gates activated several times, and gates activated on both sides
+fn build_gate_emphasis(active_gates: &[ActiveGate]) -> GateEmphasis {
+    let mut repeated: Vec<RepeatedGate> = active_gates
+        .iter()
+        .filter(|ag| ag.activators.len() > 1)
+        .map(|ag| RepeatedGate {
+            gate: ag.gate,
+            gate_name: ag.gate_name.clone(),
+            count: ag.activators.len(),
+        })
+        .collect();
+    // Strongest emphasis first, ties by gate number
+    repeated.sort_by(|a, b| b.count.cmp(&a.count).then(a.gate.cmp(&b.gate)));
+
+    let resonance = active_gates
+        .iter()
+        .filter(|ag| {
+            ag.activators.iter().any(|a| a.side == "personality")
+                && ag.activators.iter().any(|a| a.side == "design")
+        })
+        .map(|ag| ag.gate)
+        .collect();
+
+    GateEmphasis {
+        repeated,
+        resonance,
+    }
+}
+
+fn build_node_themes(
+    pers_gates: &[(HdPlanet, gates::GatePosition)],
+    des_gates: &[(HdPlanet, gates::GatePosition)],
+    db: &HdDatabase,
+    full: bool,
+) -> Vec<NodeTheme> {
+    let phases = [
+        (HdPlanet::SouthNode, "before_uranus_opposition"),
+        (HdPlanet::NorthNode, "after_uranus_opposition"),
+    ];
+
+    let mut themes = Vec::new();
+    for (node, phase) in phases {
+        for (side, positions) in [("personality", pers_gates), ("design", des_gates)] {
+            if let Some((planet, gp)) = positions.iter().find(|(p, _)| *p == node) {
+                let gate_data = db.gates.get(&gp.gate.to_string());
+                themes.push(NodeTheme {
+                    phase: phase.to_string(),
+                    side: side.to_string(),
+                    planet: planet.name(),
+                    planet_symbol: planet.symbol(),
+                    gate: gp.gate,
+                    line: gp.line,
+                    gate_name: gate_data.map(|g| g.name.clone()),
+                    gate_description: if full {
+                        gate_data.map(|g| g.description.clone())
+                    } else {
+                        None
+                    },
+                    line_description: if full {
+                        gate_data
+                            .and_then(|g| g.lines.get(&gp.line.to_string()))
+                            .map(|l| l.description.clone())
+                    } else {
+                        None
+                    },
+                });
+            }
+        }
+    }
+    themes
 }
 
 fn build_planet_positions(
     positions: &[(HdPlanet, gates::GatePosition)],
+    jd: f64,
+    side: &str,
     db: &HdDatabase,
     full: bool,
+    calc_opts: &CalcOptions,
 ) -> Vec<PlanetPosition> {
+    let planets: Vec<HdPlanet> = positions.iter().map(|(planet, _)| *planet).collect();
+    let speeds = astro_calc::daily_speeds(jd, &planets, calc_opts);
     positions
         .iter()
+        .zip(speeds)
         .enumerate()
-        .map(|(idx, (planet, gp))| {
-            let (zodiac_key, zodiac_degree) = gates::degree_to_zodiac(gp.degree);
-            let zodiac_symbol = zodiac_symbol_from_key(&zodiac_key);
-            let zodiac_key_str = format!("zodiac.{}", zodiac_key);
-            let zodiac_sign = rust_i18n::t!(&zodiac_key_str).to_string();
+        .map(|(idx, ((planet, gp), speed))| {
+            let (zodiac_sign, zodiac_symbol, zodiac_degree) = zodiac_fields(gp.degree);
 
             let gate_name = db.gates.get(&gp.gate.to_string()).map(|g| g.name.clone());
+            let gate_keynote = db
+                .gates
+                .get(&gp.gate.to_string())
+                .map(|g| g.keynote_or_summary());
 
             let (gate_description, line_description) = if full {
                 let g_desc = db
@@ -523,7 +1847,7 @@ fn build_planet_positions(
                     .gates
                     .get(&gp.gate.to_string())
                     .and_then(|g| g.lines.get(&l_key))
-                    .cloned();
+                    .map(|l| l.description.clone());
                 (g_desc, l_desc)
             } else {
                 (None, None)
@@ -531,26 +1855,75 @@ fn build_planet_positions(
 
             PlanetPosition {
                 planet: planet.name(),
+                activation: side.to_string(),
                 index: idx,
                 longitude: gp.degree,
                 degree: (gp.degree * 100.0).round() / 100.0,
                 zodiac_sign,
                 zodiac_symbol,
                 planet_symbol: planet.symbol(),
-                zodiac_degree: (zodiac_degree * 100.0).round() / 100.0,
+                zodiac_degree,
+                speed_deg_per_day: (speed * 10000.0).round() / 10000.0,
+                retrograde: speed < 0.0,
                 gate: gp.gate,
                 line: gp.line,
                 color: gp.color,
                 tone: gp.tone,
                 base: gp.base,
                 gate_name,
+                gate_keynote,
                 gate_description,
                 line_description,
+                fixing: line_fixing(db, *planet, gp),
+                detail: None,
+                uncertain: None,
             }
         })
         .collect()
 }
 
+/// Exaltation/detriment of the planet in its gate line, per the gates database
+fn line_fixing(db: &HdDatabase, planet: HdPlanet, gp: &gates::GatePosition) -> Option<Fixing> {
+    let line = db.gates.get(&gp.gate.to_string())?.lines.get(&gp.line.to_string())?;
+    let key = planet.key();
+    let exalted = line.exaltation.iter().any(|p| p == key);
+    let detriment = line.detriment.iter().any(|p| p == key);
+    match (exalted, detriment) {
+        (true, true) => Some(Fixing::Juxtaposed),
+        (true, false) => Some(Fixing::Exalted),
+        (false, true) => Some(Fixing::Detriment),
+        (false, false) => None,
+    }
+}
+
+/// Localized sign, sign symbol and degree within the sign (rounded) for a longitude
+fn zodiac_fields(longitude: f64) -> (String, String, f64) {
+    let (zodiac_key, zodiac_degree) = gates::degree_to_zodiac(longitude);
+    let zodiac_key_str = format!("zodiac.{}", zodiac_key);
+    (
+        rust_i18n::t!(&zodiac_key_str).to_string(),
+        zodiac_symbol_from_key(&zodiac_key),
+        // Enough precision for arc seconds (`--angle-format dms`)
+        (zodiac_degree * 1e6).round() / 1e6,
+    )
+}
+
+/// Show signs in the sidereal zodiac. Gates are unaffected; `wheel` only records that
+/// the gate wheel was shifted as well (via `CalcOptions::sidereal_wheel` when building).
+/// The ayanamsa at the birth moment is used for both sides.
+pub fn apply_sidereal(chart: &mut HdChart, ayanamsa: astro_calc::Ayanamsa, personality_jd: f64, wheel: bool) {
+    let ayanamsa_degrees = ayanamsa.degrees(personality_jd);
+    for p in chart.personality.iter_mut().chain(chart.design.iter_mut()) {
+        (p.zodiac_sign, p.zodiac_symbol, p.zodiac_degree) =
+            zodiac_fields(p.longitude - ayanamsa_degrees);
+    }
+    chart.sidereal = Some(SiderealSettings {
+        ayanamsa: ayanamsa.key().to_string(),
+        ayanamsa_degrees: (ayanamsa_degrees * 10000.0).round() / 10000.0,
+        wheel,
+    });
+}
+
 fn zodiac_symbol_from_key(key: &str) -> String {
     match key {
         "aries" => "♈",
@@ -570,7 +1943,7 @@ fn zodiac_symbol_from_key(key: &str) -> String {
     .to_string()
 }
 
-fn find_defined_centers(channels: &[ChannelDef]) -> HashSet<Center> {
+pub fn find_defined_centers(channels: &[ChannelDef]) -> HashSet<Center> {
     let mut defined = HashSet::new();
     for ch in channels {
         defined.insert(ch.center_a);
@@ -579,21 +1952,192 @@ fn find_defined_centers(channels: &[ChannelDef]) -> HashSet<Center> {
     defined
 }
 
-fn determine_type(defined: &HashSet<Center>, channels: &[ChannelDef]) -> String {
+/// Separate areas of definition: groups of defined centers connected by defined channels
+pub fn definition_areas(defined: &HashSet<Center>, channels: &[ChannelDef]) -> Vec<Vec<Center>> {
+    let mut areas: Vec<Vec<Center>> = Vec::new();
+    for start in Center::all().iter().filter(|c| defined.contains(c)) {
+        if areas.iter().any(|a| a.contains(start)) {
+            continue;
+        }
+        let mut area = Vec::new();
+        let mut stack = vec![*start];
+        while let Some(current) = stack.pop() {
+            if area.contains(&current) {
+                continue;
+            }
+            area.push(current);
+            for ch in channels {
+                if ch.center_a == current {
+                    stack.push(ch.center_b);
+                }
+                if ch.center_b == current {
+                    stack.push(ch.center_a);
+                }
+            }
+        }
+        area.sort_by_key(|c| Center::all().iter().position(|x| x == c));
+        areas.push(area);
+    }
+    areas
+}
+
+/// Cheapest ways to join each pair of definition areas, as paths of channels.
+/// A channel costs the number of its gates missing from `gates`; every direct
+/// channel of the minimal cost is its own alternative.
+pub fn find_bridges(areas: &[Vec<Center>], gates: &[u8]) -> Vec<(usize, usize, Vec<ChannelDef>)> {
+    let all = Center::all();
+    let index = |c: &Center| all.iter().position(|x| x == c).unwrap_or(0);
+    let missing = |ch: &ChannelDef| {
+        [ch.gate_a, ch.gate_b]
+            .iter()
+            .filter(|g| !gates.contains(g))
+            .count()
+    };
+    let channels = channels::unique_channels(channels::all_channels());
+
+    let mut bridges = Vec::new();
+    for i in 0..areas.len() {
+        for j in i + 1..areas.len() {
+            // Dijkstra over the nine centers, starting from the whole area i
+            let mut dist = vec![usize::MAX; all.len()];
+            let mut via: Vec<Option<usize>> = vec![None; all.len()];
+            let mut done = vec![false; all.len()];
+            for c in &areas[i] {
+                dist[index(c)] = 0;
+            }
+            while let Some(u) = (0..all.len())
+                .filter(|&k| !done[k] && dist[k] != usize::MAX)
+                .min_by_key(|&k| dist[k])
+            {
+                done[u] = true;
+                for (ci, ch) in channels.iter().enumerate() {
+                    let v = if index(&ch.center_a) == u {
+                        index(&ch.center_b)
+                    } else if index(&ch.center_b) == u {
+                        index(&ch.center_a)
+                    } else {
+                        continue;
+                    };
+                    let d = dist[u] + missing(ch);
+                    if d < dist[v] {
+                        dist[v] = d;
+                        via[v] = Some(ci);
+                    }
+                }
+            }
+            let Some(target) = areas[j].iter().map(index).min_by_key(|&k| dist[k]) else {
+                continue;
+            };
+            let cost = dist[target];
+
+            let direct: Vec<&ChannelDef> = channels
+                .iter()
+                .filter(|ch| {
+                    let (a, b) = (&ch.center_a, &ch.center_b);
+                    missing(ch) == cost
+                        && ((areas[i].contains(a) && areas[j].contains(b))
+                            || (areas[i].contains(b) && areas[j].contains(a)))
+                })
+                .collect();
+            if !direct.is_empty() {
+                bridges.extend(direct.into_iter().map(|ch| (i, j, vec![ch.clone()])));
+                continue;
+            }
+
+            // No single channel is as cheap: walk the path back to area i
+            let mut path = Vec::new();
+            let mut k = target;
+            while let Some(ci) = via[k] {
+                let ch = &channels[ci];
+                if missing(ch) > 0 {
+                    path.push(ch.clone());
+                }
+                k = if index(&ch.center_a) == k {
+                    index(&ch.center_b)
+                } else {
+                    index(&ch.center_a)
+                };
+            }
+            path.reverse();
+            bridges.push((i, j, path));
+        }
+    }
+    bridges
+}
+
+/// Defined channels per circuit group: Individual, Tribal and Collective first,
+/// then any other group in the database (e.g. Integration). None without channels.
+fn build_circuitry(
+    channels: &[ChannelInfo],
+    db: &HdDatabase,
+    full: bool,
+) -> Option<Vec<CircuitryShare>> {
+    let mut circuits: Vec<&str> = vec!["individual", "tribal", "collective"];
+    for ch in channels {
+        if let Some(ref c) = ch.circuit {
+            if !circuits.contains(&c.as_str()) {
+                circuits.push(c);
+            }
+        }
+    }
+    let total = channels.len();
+    let shares: Vec<CircuitryShare> = circuits
+        .into_iter()
+        .filter_map(|circuit| {
+            let count = channels
+                .iter()
+                .filter(|ch| ch.circuit.as_deref() == Some(circuit))
+                .count();
+            if count == 0 {
+                return None;
+            }
+            let meta = db.circuits.get(circuit);
+            Some(CircuitryShare {
+                circuit: circuit.to_string(),
+                name: meta
+                    .map(|m| m.name.clone())
+                    .unwrap_or_else(|| circuit.to_string()),
+                channels: count,
+                percent: (count as f64 / total as f64 * 1000.0).round() / 10.0,
+                keynote: meta
+                    .filter(|m| full && !m.description.is_empty())
+                    .map(|m| m.description.clone()),
+            })
+        })
+        .collect();
+    if shares.is_empty() {
+        None
+    } else {
+        Some(shares)
+    }
+}
+
+/// Definition classification key by the number of separate areas
+fn definition_key(areas: usize) -> &'static str {
+    match areas {
+        0 => "none",
+        1 => "single",
+        2 => "split",
+        3 => "triple_split",
+        _ => "quad_split",
+    }
+}
+
+fn determine_type(defined: &HashSet<Center>, channels: &[ChannelDef]) -> HdType {
     let has_sacral = defined.contains(&Center::Sacral);
     let _has_throat = defined.contains(&Center::Throat);
     let motor_to_throat = has_motor_to_throat_connection(defined, channels);
 
     if defined.is_empty() {
-        "reflector".to_string()
+        HdType::Reflector
     } else if has_sacral && motor_to_throat {
-        "manifesting_generator".to_string()
+        HdType::ManifestingGenerator
     } else if has_sacral {
-        "generator".to_string()
+        HdType::Generator
     } else if motor_to_throat {
-        "manifestor".to_string()
+        HdType::Manifestor
     } else {
-        "projector".to_string()
+        HdType::Projector
     }
 }
 
@@ -628,24 +2172,85 @@ fn has_motor_to_throat_connection(defined: &HashSet<Center>, channels: &[Channel
     false
 }
 
-fn determine_authority(defined: &HashSet<Center>) -> String {
+/// Every planetary activation, Personality then Design
+fn build_activations(personality: &[PlanetPosition], design: &[PlanetPosition]) -> Vec<Activation> {
+    personality
+        .iter()
+        .map(|p| (p, true))
+        .chain(design.iter().map(|p| (p, false)))
+        .map(|(p, conscious)| Activation {
+            planet: p.planet.clone(),
+            planet_symbol: p.planet_symbol.clone(),
+            index: p.index,
+            conscious,
+            gate: p.gate,
+            line: p.line,
+            color: p.color,
+            tone: p.tone,
+            base: p.base,
+            notation: format!("{}.{}.{}.{}.{}", p.gate, p.line, p.color, p.tone, p.base),
+            fixing: p.fixing,
+        })
+        .collect()
+}
+
+/// Inner authority by the center hierarchy; the Type tells the Heart of a Manifestor
+/// (wired to the Throat) from that of a Projector (wired to the G)
+fn determine_authority(defined: &HashSet<Center>, hd_type: HdType) -> Authority {
     if defined.contains(&Center::SolarPlexus) {
-        "emotional".to_string()
+        Authority::Emotional
     } else if defined.contains(&Center::Sacral) {
-        "sacral".to_string()
+        Authority::Sacral
     } else if defined.contains(&Center::Spleen) {
-        "splenic".to_string()
+        Authority::Splenic
     } else if defined.contains(&Center::Heart) {
-        "ego".to_string()
+        if hd_type == HdType::Manifestor {
+            Authority::EgoManifested
+        } else {
+            Authority::EgoProjected
+        }
     } else if defined.contains(&Center::G) {
-        "self_projected".to_string()
+        Authority::SelfProjected
     } else if defined.contains(&Center::Throat) {
-        "mental".to_string()
+        Authority::Environmental
     } else {
-        "lunar".to_string()
+        Authority::Lunar
     }
 }
 
+/// Variable from the Sun and North Node tones: tones 1–3 point the arrow left, 4–6 right
+fn determine_variable(pers_sun_tone: u8, pers_node_tone: u8, des_sun_tone: u8, des_node_tone: u8) -> Variable {
+    let arrows: Vec<VariableArrow> = [
+        ("awareness", pers_sun_tone),
+        ("perspective", pers_node_tone),
+        ("digestion", des_sun_tone),
+        ("environment", des_node_tone),
+    ]
+    .iter()
+    .map(|&(key, tone)| {
+        let side = if tone <= 3 { "left" } else { "right" };
+        let name_key = format!("cli.variable.arrow.{}", key);
+        let keynote_key = format!("cli.variable.keynote.{}_{}", key, side);
+        VariableArrow {
+            key: key.to_string(),
+            name: rust_i18n::t!(&name_key).to_string(),
+            side: side.to_string(),
+            tone,
+            keynote: rust_i18n::t!(&keynote_key).to_string(),
+        }
+    })
+    .collect();
+    let letter = |a: &VariableArrow| if a.side == "left" { 'L' } else { 'R' };
+    let notation = format!(
+        "P{}{} D{}{}",
+        letter(&arrows[0]),
+        letter(&arrows[1]),
+        letter(&arrows[2]),
+        letter(&arrows[3])
+    );
+    Variable { notation, arrows }
+}
+
 fn determine_strategy_localized(hd_type_key: &str) -> String {
     match hd_type_key {
         "generator" => rust_i18n::t!("strategy.generator").to_string(),
@@ -657,20 +2262,28 @@ fn determine_strategy_localized(hd_type_key: &str) -> String {
     }
 }
 
-fn find_cross_key_in_db(
-    db: &HdDatabase,
-    sun_gate_id: &str,
+/// Cross entry in the database: the catalog entry for the chart's four gates or for the
+/// canonical cross of its Personality Sun gate, then the Sun gate's cross of the same angle.
+/// The flag tells whether the entry came from a catalog key.
+fn find_cross_in_db<'a>(
+    db: &'a HdDatabase,
     angle_key_part: &str,
-) -> Option<String> {
-    if let Some(gate_data) = db.gates.get(sun_gate_id) {
-        for cross_key in &gate_data.crosses {
-            if cross_key.contains(angle_key_part) {
-                return Some(cross_key.clone());
-            }
-        }
-        if let Some(first) = gate_data.crosses.first() {
-            return Some(first.clone());
-        }
+    gates: [u8; 4],
+    catalog_cross: Option<crosses::CatalogCross>,
+) -> (Option<&'a MetaObject>, bool) {
+    let catalog_meta = std::iter::once(gates)
+        .chain(catalog_cross.map(|c| c.gates))
+        .find_map(|g| db.crosses.get(&crosses::catalog_key(angle_key_part, g)));
+    if catalog_meta.is_some() {
+        return (catalog_meta, true);
     }
-    None
+
+    let gate_meta = db.gates.get(&gates[0].to_string()).and_then(|gate_data| {
+        gate_data
+            .crosses
+            .iter()
+            .find(|key| key.contains(angle_key_part))
+            .and_then(|key| db.crosses.get(key))
+    });
+    (gate_meta, false)
 }
@@ -31,6 +31,8 @@ fn planet_weight(planet: HdPlanet) -> f64 {
         HdPlanet::Uranus => 6.0,
         HdPlanet::Neptune => 6.0,
         HdPlanet::Pluto => 6.0,
+        // Optional bodies activate no circuits
+        HdPlanet::Chiron | HdPlanet::Lilith => 0.0,
     }
 }
 
@@ -1,12 +1,14 @@
-use crate::models::HdChart;
+use crate::astro_calc::{Calendar, EngineKind, ExtraPlanet, HdPlanet, NodeModel};
+use crate::config::WrapSettings;
+use crate::models::{
+    CompositeChart, CycleEvent, DreamRaveChart, Ephemeris, GeneKeysChart, HdChart, LunarCycle, PentaReport, ProfileMatrix, ResolvedMoment, SolarReturn, SunIngress, Topic, TransitPlan,
+    TransitReport, Verbosity,
+};
+use crate::render::{self, ActivationOrder, AngleFormat, ChannelGrouping, RenderOptions, Section};
 /// CLI interface: arguments, output formatting
 use clap::{Parser, Subcommand, ValueEnum};
-use colored::*;
-use comfy_table::{
-    presets, Attribute, Cell, Color as TableColor, ColumnConstraint, ContentArrangement, Table,
-};
-use terminal_size::{terminal_size, Width};
-use textwrap::Options;
+use crate::render::style::*;
+use std::fmt::Write;
 
 /// Output format
 #[derive(Debug, Clone, ValueEnum)]
@@ -17,15 +19,424 @@ pub enum OutputFormat {
     Json,
     /// YAML format
     Yaml,
+    /// Single-file HTML report (chart only)
+    Html,
+    /// CSV rows for spreadsheets (chart only, see `--csv-rows`)
+    Csv,
+    /// One line: type · profile · authority · definition · cross (chart only)
+    Summary,
+}
+
+/// When to use colors and box drawing
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Default)]
+pub enum ColorChoice {
+    /// Only when stdout is a terminal and NO_COLOR is not set (or empty)
+    #[default]
+    Auto,
+    /// Always, even when piped or redirected
+    Always,
+    /// Never (plain text with ASCII borders)
+    Never,
+}
+
+impl ColorChoice {
+    /// Whether output should be styled
+    pub fn resolve(self) -> bool {
+        use std::io::IsTerminal;
+        match self {
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+            // NO_COLOR only counts when set to a non-empty value (no-color.org)
+            ColorChoice::Auto => {
+                std::io::stdout().is_terminal()
+                    && std::env::var_os("NO_COLOR").is_none_or(|v| v.is_empty())
+            }
+        }
+    }
+}
+
+/// Rows of CSV output
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Default)]
+pub enum CsvRows {
+    /// One row per planetary activation: side, planet, gate, line, color, tone, base, longitude
+    #[default]
+    Activations,
+    /// One summary row per chart, to collect batch runs into one file
+    Summary,
+}
+
+/// CSV output settings
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CsvOptions {
+    pub rows: CsvRows,
+    /// Leave out the header line (when appending the rows of several runs)
+    pub no_header: bool,
+}
+
+/// Key casing for JSON output
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Default)]
+pub enum JsonCase {
+    /// snake_case keys (as in the data model)
+    #[default]
+    Snake,
+    /// camelCase keys (for JavaScript consumers)
+    Camel,
+}
+
+/// JSON serialization settings
+#[derive(Clone, Copy, Debug, Default)]
+pub struct JsonOptions {
+    pub case: JsonCase,
+    /// Minified output instead of pretty-printed
+    pub compact: bool,
 }
 
 #[derive(Subcommand, Debug, Clone)]
 pub enum Commands {
     /// Manage configuration
     Config {
-        /// Set default language (en, ru, es)
+        /// Set default language (en, ru, es, zh)
         #[arg(long)]
         set_lang: Option<String>,
+
+        /// Set default interface language for labels and messages (en, ru, es, zh; empty string to follow --lang)
+        #[arg(long)]
+        set_ui_lang: Option<String>,
+
+        /// Set command executed by the daemon on transit events (empty string to clear)
+        #[arg(long)]
+        set_notify_command: Option<String>,
+
+        /// Show color/tone/base columns by default (true/false)
+        #[arg(long)]
+        set_depth: Option<bool>,
+
+        /// Cache computed charts on disk by default (true/false)
+        #[arg(long)]
+        set_cache: Option<bool>,
+
+        /// Default lunar node model (mean, true)
+        #[arg(long, value_enum)]
+        set_node: Option<NodeModel>,
+
+        /// Default maximum width of description paragraphs (0 = terminal width)
+        #[arg(long)]
+        set_wrap_width: Option<usize>,
+
+        /// Default indent of description paragraphs
+        #[arg(long)]
+        set_indent: Option<usize>,
+
+        /// Break words longer than a line by default (true/false)
+        #[arg(long)]
+        set_break_words: Option<bool>,
+
+        /// Allow breaks after hyphens by default (true/false)
+        #[arg(long)]
+        set_split_hyphens: Option<bool>,
+
+        /// URL template for gate/channel hyperlinks, e.g. 'https://example.org/{kind}/{id}' (empty string to disable)
+        #[arg(long)]
+        set_link_template: Option<String>,
+
+        /// Topics shown by default, comma-separated (phs, business, gate-descriptions, lines, themes, circuits)
+        #[arg(long, value_enum, value_delimiter = ',')]
+        set_show: Vec<Topic>,
+
+        /// Topics hidden by default, comma-separated
+        #[arg(long, value_enum, value_delimiter = ',')]
+        set_hide: Vec<Topic>,
+    },
+    /// Manage saved profiles (birth data stored under a name)
+    Profile {
+        #[command(subcommand)]
+        action: ProfileAction,
+    },
+    /// Compare a saved profile against transits (default: now)
+    Transit {
+        /// Saved profile name
+        #[arg(short = 'p', long)]
+        profile: String,
+
+        /// Transit date in YYYY-MM-DD format (default: now)
+        #[arg(long)]
+        date: Option<String>,
+
+        /// Transit time in HH:MM or HH:MM:SS format (default: 12:00 if date is given)
+        #[arg(long)]
+        time: Option<String>,
+
+        /// Transit time zone as UTC offset (default: 0)
+        #[arg(long)]
+        utc: Option<String>,
+    },
+    /// Rank the days of a date range by transits significant for a saved profile
+    Planner {
+        /// Saved profile name
+        #[arg(short = 'p', long)]
+        profile: String,
+
+        /// First day in YYYY-MM-DD format (default: today)
+        #[arg(long)]
+        from: Option<String>,
+
+        /// Number of days to scan
+        #[arg(long, default_value_t = 30)]
+        days: u32,
+
+        /// Show only the N highest-ranked days
+        #[arg(long)]
+        top: Option<usize>,
+    },
+    /// Moon gates over one lunar cycle (28.5 days) against a saved profile, for lunar authority
+    LunarCycle {
+        /// Saved profile name
+        #[arg(short = 'p', long)]
+        profile: String,
+
+        /// Start of the cycle in YYYY-MM-DD format (default: now)
+        #[arg(long)]
+        from: Option<String>,
+    },
+    /// List the moments the Sun enters a new gate (or line) in a date range
+    Transits {
+        /// First day in YYYY-MM-DD format
+        #[arg(long)]
+        from: String,
+
+        /// Day after the last one, in YYYY-MM-DD format
+        #[arg(long)]
+        to: String,
+
+        /// Also list line changes within each gate
+        #[arg(long)]
+        lines: bool,
+
+        /// Print an iCalendar (.ics) file instead of --format output
+        #[arg(long)]
+        ical: bool,
+    },
+    /// List upcoming solar returns (exact moments) of all saved profiles
+    Birthdays {
+        /// Look this many days ahead
+        #[arg(long, default_value_t = 30)]
+        days: u32,
+    },
+    /// Watch transits for a saved profile and notify about gate/line changes
+    Daemon {
+        /// Saved profile name
+        #[arg(short = 'p', long)]
+        profile: String,
+
+        /// Seconds between checks
+        #[arg(long, default_value_t = 60)]
+        interval: u64,
+
+        /// Command to run on each event (overrides config); data is passed in HD_* env vars
+        #[arg(long)]
+        exec: Option<String>,
+
+        /// Also report line changes within relevant gates
+        #[arg(long)]
+        lines: bool,
+
+        /// Report every transit gate change, not only ones touching the natal chart
+        #[arg(long)]
+        all: bool,
+
+        /// Do not show desktop notifications
+        #[arg(long)]
+        no_notify: bool,
+
+        /// Detach and keep running in the background
+        #[arg(long)]
+        detach: bool,
+    },
+    /// Aggregate bodygraph of two or more saved profiles
+    Group {
+        /// Saved profile names (e.g. mom dad sister)
+        #[arg(required = true, num_args = 2..)]
+        names: Vec<String>,
+    },
+    /// Relationship chart of two people: combined centers and connection kind of every channel
+    Compare {
+        /// First person: saved profile name or "YYYY-MM-DD HH:MM UTC" (e.g. "1990-05-15 14:30 +3")
+        first: String,
+
+        /// Second person, same forms as the first
+        second: String,
+    },
+    /// Pairwise connection matrix of several saved profiles
+    Matrix {
+        /// Saved profile names (e.g. mom dad sister)
+        #[arg(required = true, num_args = 2..)]
+        names: Vec<String>,
+
+        /// Print one CSV row per pair instead of the grid
+        #[arg(long)]
+        csv: bool,
+    },
+    /// Penta analysis of a 3–5 person group: Penta channels, missing roles, group dynamics
+    Penta {
+        /// CSV file with one person per line: name,date,time,utc (header row optional)
+        #[arg(long)]
+        input: std::path::PathBuf,
+    },
+    /// Generate a static site (index.html, SVG bodygraph, JSON) for one chart
+    Site {
+        /// Saved profile to use instead of --date/--time/--utc
+        #[arg(short = 'p', long)]
+        profile: Option<String>,
+
+        /// Output directory (created if missing)
+        #[arg(long, default_value = "hd_site")]
+        out: std::path::PathBuf,
+    },
+    /// Dream Rave (sleep) bodygraph: 15 dream gates in 5 dream centers
+    Dreamrave {
+        /// Saved profile to use instead of --date/--time/--utc
+        #[arg(short = 'p', long)]
+        profile: Option<String>,
+    },
+    /// Gene Keys Activation Sequence: Life's Work, Evolution, Radiance and Purpose
+    Genekeys {
+        /// Saved profile to use instead of --date/--time/--utc
+        #[arg(short = 'p', long)]
+        profile: Option<String>,
+    },
+    /// Raw planetary longitudes for a moment, without building a chart
+    Ephemeris {
+        /// Saved profile to use instead of --date/--time/--utc
+        #[arg(short = 'p', long)]
+        profile: Option<String>,
+
+        /// Date in YYYY-MM-DD format (same as the top-level --date)
+        #[arg(short = 'd', long)]
+        date: Option<String>,
+
+        /// Time in HH:MM or HH:MM:SS format (same as the top-level --time)
+        #[arg(short = 't', long)]
+        time: Option<String>,
+
+        /// Time zone as UTC offset or abbreviation (same as the top-level --utc)
+        #[arg(short = 'u', long)]
+        utc: Option<String>,
+
+        /// Moment as Julian Day, UT (same as the top-level --jd)
+        #[arg(long, conflicts_with_all = ["date", "time", "utc"])]
+        jd: Option<f64>,
+
+        /// Also map each longitude to its gate and line
+        #[arg(long)]
+        gates: bool,
+    },
+    /// Solar return chart: the moment the Sun comes back to its natal degree in a given year
+    SolarReturn {
+        /// Year of the return
+        #[arg(long)]
+        year: i32,
+
+        /// Saved profile to use instead of --date/--time/--utc
+        #[arg(short = 'p', long)]
+        profile: Option<String>,
+    },
+    /// Rave New Year chart: the global chart of the moment the Sun enters Gate 41
+    RaveNewYear {
+        /// Year of the Rave New Year
+        #[arg(long)]
+        year: i32,
+    },
+    /// Dates of the Saturn return, Uranus opposition, Kiron return and second Saturn return
+    Cycles {
+        /// Saved profile to use instead of --date/--time/--utc
+        #[arg(short = 'p', long)]
+        profile: Option<String>,
+    },
+    /// Serve the chart engine over HTTP (JSON API, optional web UI)
+    Serve {
+        /// Address to listen on
+        #[arg(long, default_value = "127.0.0.1:8080")]
+        addr: String,
+
+        /// Also serve a web page with a birth data form and bodygraph at /
+        #[arg(long)]
+        ui: bool,
+
+        /// Gates database JSON to use for --lang instead of the built-in one, reloaded when the file changes
+        #[arg(long, value_name = "FILE")]
+        db: Option<std::path::PathBuf>,
+    },
+    /// Work with the gates database
+    Db {
+        #[command(subcommand)]
+        action: DbAction,
+    },
+    /// Manage the chart cache
+    Cache {
+        #[command(subcommand)]
+        action: CacheAction,
+    },
+    /// Update hd-cli to the latest GitHub release
+    SelfUpdate {
+        /// Only report whether a newer release exists
+        #[arg(long)]
+        check: bool,
+    },
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum DbAction {
+    /// Export the database (in --lang) as Markdown files: one per type, center, channel and gate
+    Book {
+        /// Output directory (created if missing)
+        #[arg(long, default_value = "hd_reference")]
+        out: std::path::PathBuf,
+    },
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum CacheAction {
+    /// Remove all cached charts
+    Clear,
+    /// Show the number and size of cached charts
+    Stats,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum ProfileAction {
+    /// Save birth data under a name
+    Add {
+        /// Profile name (e.g. me, mom)
+        name: String,
+
+        /// Date of birth in YYYY-MM-DD format
+        #[arg(short = 'd', long)]
+        date: String,
+
+        /// Time of birth in HH:MM or HH:MM:SS format
+        #[arg(short = 't', long)]
+        time: String,
+
+        /// Time zone as UTC offset (e.g. +3, -5, +5.5) or abbreviation (e.g. MSK, EST, CET)
+        #[arg(short = 'u', long, required_unless_present_any = ["tz", "place"])]
+        utc: Option<String>,
+
+        /// Named time zone (e.g. Europe/Moscow), saved as is and resolved with its
+        /// historical DST whenever the profile is used
+        #[arg(long, conflicts_with = "utc")]
+        tz: Option<String>,
+
+        /// Birth place (e.g. "Berlin, Germany"), saved as is and resolved to its time zone
+        /// whenever the profile is used
+        #[arg(long, conflicts_with_all = ["utc", "tz"])]
+        place: Option<String>,
+    },
+    /// List saved profiles
+    List,
+    /// Remove a saved profile
+    Remove {
+        /// Profile name
+        name: String,
     },
 }
 
@@ -49,31 +460,274 @@ pub struct Cli {
     #[arg(short = 'd', long)]
     pub date: Option<String>,
 
-    /// Time of birth in HH:MM format (e.g. 14:30)
+    /// Time of birth in HH:MM or HH:MM:SS format (e.g. 14:30, 14:30:15)
     #[arg(short = 't', long)]
     pub time: Option<String>,
 
-    /// Time zone as UTC offset (e.g. +3, -5, +5.5)
+    /// Time zone as UTC offset (e.g. +3, -5, +5.5) or abbreviation (e.g. MSK, EST, CET)
     #[arg(short = 'u', long)]
     pub utc: Option<String>,
 
-    /// Output format: table (default), json, yaml
-    #[arg(short = 'f', long, default_value = "table")]
+    /// Time zone as with --utc, or a named zone (e.g. Europe/Moscow): the offset in
+    /// effect at the birth date and time, including historical DST
+    #[arg(long, conflicts_with = "utc")]
+    pub tz: Option<String>,
+
+    /// Birth place (e.g. "Berlin, Germany"), resolved to its time zone from the bundled
+    /// city list, instead of --utc/--tz
+    #[arg(long, conflicts_with_all = ["utc", "tz"])]
+    pub place: Option<String>,
+
+    /// Birth moment as Unix timestamp in seconds, UTC (replaces --date/--time/--utc)
+    #[arg(long, allow_hyphen_values = true, conflicts_with_all = ["date", "time", "utc", "tz", "place"])]
+    pub epoch: Option<i64>,
+
+    /// Birth moment as Julian Day, UT (expert mode, bypasses calendar and time zone)
+    #[arg(long, conflicts_with_all = ["date", "time", "utc", "tz", "place", "epoch"])]
+    pub jd: Option<f64>,
+
+    /// Solar arc in degrees between Design and Personality (expert/research option, default 88)
+    #[arg(long, global = true)]
+    pub design_arc: Option<f64>,
+
+    /// Shift of the gate wheel anchor in degrees (expert/research option, default 0:
+    /// Gate 41 starts at 302° ecliptic)
+    #[arg(long, global = true, allow_hyphen_values = true)]
+    pub wheel_offset: Option<f64>,
+
+    /// Lunar node model: mean (standard) or true (osculating); default from config
+    #[arg(long, value_enum, global = true)]
+    pub node: Option<NodeModel>,
+
+    /// Ephemeris engine; defaults to the most precise one compiled in
+    /// (`swisseph` with the build feature, `astro` otherwise)
+    #[arg(long, value_enum, global = true)]
+    pub engine: Option<EngineKind>,
+
+    /// Skip the ΔT (TT − UT) correction and evaluate the ephemeris at civil time
+    /// (reproduces charts from software without it)
+    #[arg(long, global = true)]
+    pub no_delta_t: bool,
+
+    /// Use true geometric positions, without nutation and aberration
+    /// (reproduces the positions of earlier versions)
+    #[arg(long, global = true)]
+    pub true_positions: bool,
+
+    /// Calendar of the entered dates: auto (Julian before 1582-10-15), julian or gregorian
+    #[arg(long, value_enum, global = true, default_value = "auto")]
+    pub calendar: Calendar,
+
+    /// Experimental: show zodiac signs in the sidereal zodiac with this ayanamsa
+    /// (gates stay on the tropical HD wheel unless --sidereal-wheel is given)
+    #[arg(long, value_enum, global = true)]
+    pub sidereal: Option<crate::astro_calc::Ayanamsa>,
+
+    /// Experimental: also shift the HD gate wheel to the sidereal zodiac, in every mode
+    /// (added to any --wheel-offset)
+    #[arg(long, global = true, requires = "sidereal")]
+    pub sidereal_wheel: bool,
+
+    /// Output format: table (default), json, yaml, html, csv, summary
+    #[arg(short = 'f', long, default_value = "table", global = true)]
     pub format: OutputFormat,
 
+    /// Key casing for JSON output: snake (default) or camel
+    #[arg(long, value_enum, default_value = "snake", global = true)]
+    pub json_case: JsonCase,
+
+    /// Minified JSON (one line) for piping and API use
+    #[arg(long, global = true)]
+    pub compact: bool,
+
+    /// Rows of `--format csv`: activations (default, one per planet and side) or summary (one per chart)
+    #[arg(long, value_enum, default_value = "activations")]
+    pub csv_rows: CsvRows,
+
+    /// Omit the CSV header line, e.g. when appending the rows of several runs to one file
+    #[arg(long)]
+    pub no_header: bool,
+
+    /// Colors and box drawing: auto (only on a terminal), always, never
+    #[arg(long, value_enum, default_value = "auto", global = true)]
+    pub color: ColorChoice,
+
+    /// Plain output without colors, same as --color never
+    #[arg(long, global = true, conflicts_with = "color")]
+    pub no_color: bool,
+
+    /// ASCII only: abbreviations for planet and zodiac glyphs, plain markers and table borders
+    /// in the terminal report (JSON, YAML and CSV keep the glyphs)
+    #[arg(long, global = true)]
+    pub ascii: bool,
+
     /// Short output (hide detailed descriptions of gates, lines, channels and centers)
     #[arg(long)]
     pub short: bool,
 
+    /// Show Primary Health System (motivation, vision, environment, diet)
+    #[arg(long, overrides_with = "no_phs")]
+    pub with_phs: bool,
+    #[arg(long, hide = true)]
+    pub no_phs: bool,
+
+    /// Show business section
+    #[arg(long, overrides_with = "no_business")]
+    pub with_business: bool,
+    #[arg(long, hide = true)]
+    pub no_business: bool,
+
+    /// Show gate descriptions for planets and nodes
+    #[arg(long, overrides_with = "no_gate_descriptions")]
+    pub with_gate_descriptions: bool,
+    /// Hide gate descriptions
+    #[arg(long)]
+    pub no_gate_descriptions: bool,
+
+    /// Show line descriptions
+    #[arg(long, overrides_with = "no_lines")]
+    pub with_lines: bool,
+    /// Hide line descriptions (planets, nodes, profile lines)
+    #[arg(long)]
+    pub no_lines: bool,
+
+    /// Show fear, sexuality and love sections
+    #[arg(long, overrides_with = "no_themes")]
+    pub with_themes: bool,
+    /// Hide fear, sexuality and love sections
+    #[arg(long)]
+    pub no_themes: bool,
+
+    /// Show circuit scores
+    #[arg(long, overrides_with = "no_circuits")]
+    pub with_circuits: bool,
+    #[arg(long, hide = true)]
+    pub no_circuits: bool,
+
+    /// Show color/tone/base of every activation (planet table, position detail and their meanings)
+    #[arg(long)]
+    pub depth: bool,
+
+    /// Show how far the birth time can move before a Sun or Moon gate/line changes
+    #[arg(long)]
+    pub sensitivity: bool,
+
+    /// Reuse charts cached on disk for identical input and options
+    #[arg(long)]
+    pub cache: bool,
+
+    /// Channels table layout: none (default), circuit or center
+    #[arg(long, value_enum, default_value = "none")]
+    pub group_channels: ChannelGrouping,
+
+    /// Activations table order: side (Personality then Design, default), planet or gate
+    #[arg(long, value_enum, default_value = "side")]
+    pub sort_activations: ActivationOrder,
+
+    /// Zodiac positions in decimal degrees (default) or degrees, minutes and seconds (dms)
+    #[arg(long, value_enum, default_value = "decimal")]
+    pub angle_format: AngleFormat,
+
+    /// Only these report sections, comma-separated (table, JSON and YAML output)
+    #[arg(long, value_enum, value_delimiter = ',')]
+    pub sections: Vec<Section>,
+
+    /// Leave out these report sections, comma-separated
+    #[arg(long, value_enum, value_delimiter = ',')]
+    pub exclude_sections: Vec<Section>,
+
+    /// Numbered section headers with a table of contents (line numbers) up front
+    #[arg(long)]
+    pub toc: bool,
+
+    /// Make gate and channel names clickable (OSC-8) using this URL template;
+    /// `{kind}` is "gate" or "channel", `{id}` the gate number or channel key
+    #[arg(long)]
+    pub link_template: Option<String>,
+
+    /// Maximum width of description paragraphs, independent of the terminal (0 = no limit)
+    #[arg(long)]
+    pub wrap_width: Option<usize>,
+
+    /// Indent of description paragraphs (nested ones get two more columns)
+    #[arg(long)]
+    pub indent: Option<usize>,
+
+    /// Break words longer than a line (true/false, default: true)
+    #[arg(long)]
+    pub break_words: Option<bool>,
+
+    /// Allow line breaks after hyphens inside words (true/false, default: true)
+    #[arg(long)]
+    pub split_hyphens: Option<bool>,
+
+    /// Only calculate these bodies, comma-separated (Sun and Earth are always included)
+    #[arg(long, value_enum, value_delimiter = ',')]
+    pub planets: Vec<HdPlanet>,
+
+    /// Also show these optional bodies, comma-separated (chiron, lilith); they define no gates
+    #[arg(long, value_enum, value_delimiter = ',')]
+    pub extra_planets: Vec<ExtraPlanet>,
+
     /// Description language (default: ru). Determines data file gates_database_{lang}.json
-    #[arg(short = 'l', long)]
+    #[arg(short = 'l', long, global = true)]
     pub lang: Option<String>,
 
+    /// Interface language for labels, headers and errors (default: same as --lang)
+    #[arg(long, global = true)]
+    pub ui_lang: Option<String>,
+
+    /// Only validate the input and print the resolved UTC moment and Julian Day (no chart)
+    #[arg(long, global = true)]
+    pub dry_run: bool,
+
+    /// Render the chart through a Tera template file instead of --format (chart fields by their JSON names)
+    #[arg(long, value_name = "FILE", conflicts_with = "format")]
+    pub template: Option<std::path::PathBuf>,
+
     /// Save output to file. If filename is not specified, it will be generated automatically.
     #[arg(long, num_args(0..=1), default_missing_value = "default")]
     pub save: Option<String>,
 }
 
+impl Cli {
+    /// Color choice from --color, overridden by --no-color
+    pub fn color_choice(&self) -> ColorChoice {
+        if self.no_color {
+            ColorChoice::Never
+        } else {
+            self.color
+        }
+    }
+
+    /// Wrapping settings given on the command line
+    pub fn wrap_settings(&self) -> WrapSettings {
+        WrapSettings {
+            max_width: self.wrap_width,
+            indent: self.indent,
+            break_words: self.break_words,
+            split_hyphens: self.split_hyphens,
+        }
+    }
+
+    /// Topic switches given on the command line (unset topics stay `None`)
+    pub fn verbosity(&self) -> Verbosity {
+        let flag = |on: bool, off: bool| match (on, off) {
+            (true, _) => Some(true),
+            (_, true) => Some(false),
+            _ => None,
+        };
+        Verbosity {
+            phs: flag(self.with_phs, self.no_phs),
+            business: flag(self.with_business, self.no_business),
+            gate_descriptions: flag(self.with_gate_descriptions, self.no_gate_descriptions),
+            lines: flag(self.with_lines, self.no_lines),
+            themes: flag(self.with_themes, self.no_themes),
+            circuits: flag(self.with_circuits, self.no_circuits),
+        }
+    }
+}
+
 /// Parse date from YYYY-MM-DD string
 pub fn parse_date(s: &str) -> Result<(i32, u8, u8), String> {
     let parts: Vec<&str> = s.split('-').collect();
@@ -123,13 +777,13 @@ pub fn parse_date(s: &str) -> Result<(i32, u8, u8), String> {
     Ok((year, month, day))
 }
 
-/// Parse time from HH:MM string
-pub fn parse_time(s: &str) -> Result<(u8, u8), String> {
+/// Parse time from HH:MM or HH:MM:SS string
+pub fn parse_time(s: &str) -> Result<(u8, u8, u8), String> {
     let parts: Vec<&str> = s.split(':').collect();
-    if parts.len() != 2 {
+    if parts.len() != 2 && parts.len() != 3 {
         return Err(rust_i18n::t!(
             "error.parse_time",
-            error = format!("'{}'. Expected HH:MM", s)
+            error = format!("'{}'. Expected HH:MM or HH:MM:SS", s)
         )
         .to_string());
     }
@@ -147,6 +801,16 @@ pub fn parse_time(s: &str) -> Result<(u8, u8), String> {
         )
         .to_string()
     })?;
+    let sec: u8 = match parts.get(2) {
+        Some(part) => part.parse().map_err(|_| {
+            rust_i18n::t!(
+                "error.parse_time",
+                error = format!("Invalid second: '{}'", part)
+            )
+            .to_string()
+        })?,
+        None => 0,
+    };
 
     if hour > 23 {
         return Err(rust_i18n::t!(
@@ -162,16 +826,38 @@ pub fn parse_time(s: &str) -> Result<(u8, u8), String> {
         )
         .to_string());
     }
-    Ok((hour, min))
+    if sec > 59 {
+        return Err(rust_i18n::t!(
+            "error.parse_time",
+            error = format!("Second must be 0-59, got: {}", sec)
+        )
+        .to_string());
+    }
+    Ok((hour, min, sec))
 }
 
-/// Parse UTC offset from string (+3, -5, +5.5)
-pub fn parse_utc_offset(s: &str) -> Result<f64, String> {
+/// Parse a UTC offset (`+3`, `-5`, `+5.5`) or a time zone abbreviation (`MSK`, `EST`)
+/// resolved for the given date. Ambiguous abbreviations print a warning.
+pub fn parse_utc_offset(s: &str, year: i32, month: u8, day: u8) -> Result<f64, String> {
     let s = s.trim();
+    if let Some(tz) = crate::data::timezones::abbreviation_offset(s, year, month, day) {
+        if !tz.alternatives.is_empty() {
+            eprintln!(
+                "Warning: {}",
+                rust_i18n::t!(
+                    "error.tz_ambiguous",
+                    abbr = s.to_ascii_uppercase(),
+                    offset = format!("{:+}", tz.offset),
+                    alternatives = tz.alternatives
+                )
+            );
+        }
+        return Ok(tz.offset);
+    }
     let offset: f64 = s.parse().map_err(|_| {
         rust_i18n::t!(
             "error.parse_utc",
-            error = format!("'{}'. Expected number, e.g. +3, -5", s)
+            error = format!("'{}'. Expected number, e.g. +3, -5, or abbreviation, e.g. MSK, EST", s)
         )
         .to_string()
     })?;
@@ -185,827 +871,590 @@ pub fn parse_utc_offset(s: &str) -> Result<f64, String> {
     Ok(offset)
 }
 
-/// Generate chart output string
-pub fn generate_output(chart: &HdChart, format: &OutputFormat, plain: bool) -> String {
-    match format {
-        OutputFormat::Json => serde_json::to_string_pretty(chart).unwrap(),
-        OutputFormat::Yaml => serde_yaml::to_string(chart).unwrap(),
-        OutputFormat::Table => build_table_string(chart, plain),
+/// UTC offset for `--tz`: a plain offset or abbreviation as for `--utc`, otherwise a
+/// named (IANA) time zone at the local birth date and time
+pub fn parse_tz_offset(s: &str, date: (i32, u8, u8), time: (u8, u8, u8)) -> Result<f64, String> {
+    let (year, month, day) = date;
+    let offset_like = s.trim().parse::<f64>().is_ok()
+        || crate::data::timezones::abbreviation_offset(s.trim(), year, month, day).is_some();
+    if offset_like {
+        parse_utc_offset(s, year, month, day)
+    } else {
+        parse_zone_offset(s, date, time)
     }
 }
 
-// Deprecated in favor of generate_output + println! in main
-pub fn output_chart(chart: &HdChart, format: &OutputFormat) {
-    println!("{}", generate_output(chart, format, false));
+/// UTC offset of a named (IANA) time zone at a local birth date and time. A time
+/// repeated when DST ends is read in the earlier (summer) offset, with a warning
+pub fn parse_zone_offset(name: &str, date: (i32, u8, u8), time: (u8, u8, u8)) -> Result<f64, String> {
+    use crate::data::timezones::{zone_offset, ZoneOffset};
+    let local = chrono::NaiveDate::from_ymd_opt(date.0, date.1 as u32, date.2 as u32)
+        .and_then(|d| d.and_hms_opt(time.0 as u32, time.1 as u32, time.2 as u32))
+        .ok_or_else(|| {
+            rust_i18n::t!(
+                "error.parse_date",
+                error = format!("'{:04}-{:02}-{:02}'", date.0, date.1, date.2)
+            )
+            .to_string()
+        })?;
+    match zone_offset(name, local) {
+        Some(ZoneOffset::Single(offset)) => Ok(offset),
+        Some(ZoneOffset::Ambiguous(earlier, later)) => {
+            eprintln!(
+                "Warning: {}",
+                rust_i18n::t!(
+                    "error.tz_repeated_time",
+                    zone = name,
+                    offset = format!("{:+}", earlier),
+                    other = format!("{:+}", later)
+                )
+            );
+            Ok(earlier)
+        }
+        Some(ZoneOffset::Nonexistent) => Err(rust_i18n::t!("error.tz_skipped_time", zone = name).to_string()),
+        None => Err(rust_i18n::t!("error.tz_unknown", zone = name).to_string()),
+    }
 }
 
-use std::fmt::Write;
+/// IANA time zone of a bundled place ("City" or "City, Country")
+pub fn parse_place_zone(place: &str) -> Result<&'static str, String> {
+    use crate::geo::{find_place, PlaceMatch};
+    match find_place(place) {
+        PlaceMatch::Found(found) => Ok(found.tz),
+        PlaceMatch::Ambiguous(candidates) => Err(rust_i18n::t!(
+            "error.place_ambiguous",
+            place = place,
+            candidates = candidates.iter().map(|p| p.label()).collect::<Vec<_>>().join("; ")
+        )
+        .to_string()),
+        PlaceMatch::NotFound => Err(rust_i18n::t!("error.place_unknown", place = place).to_string()),
+    }
+}
 
-fn build_table_string(chart: &HdChart, plain: bool) -> String {
-    let mut out = String::new();
+/// UTC offset at a local birth date and time from `--utc`, `--tz` or `--place`,
+/// whichever is given (they exclude each other)
+pub fn resolve_utc_offset(
+    utc: Option<&str>,
+    tz: Option<&str>,
+    place: Option<&str>,
+    date: (i32, u8, u8),
+    time: (u8, u8, u8),
+) -> Result<f64, String> {
+    match (utc, tz, place) {
+        (Some(utc), _, _) => parse_utc_offset(utc, date.0, date.1, date.2),
+        (None, Some(tz), _) => parse_tz_offset(tz, date, time),
+        (None, None, Some(place)) => parse_place_zone(place).and_then(|tz| parse_zone_offset(tz, date, time)),
+        (None, None, None) => Err(rust_i18n::t!(
+            "error.parse_utc",
+            error = "missing; give --utc, --tz or --place"
+        )
+        .to_string()),
+    }
+}
 
-    // Disable colors globally for colored if plain=true
-    if plain {
-        colored::control::set_override(false);
-    }
-
-    // Header
-    writeln!(
-        out,
-        "\n{}",
-        "═══════════════════════════════════════════════════════════════".truecolor(95, 158, 160)
-    )
-    .unwrap();
-    writeln!(
-        out,
-        "      {}",
-        rust_i18n::t!("cli.header").truecolor(255, 255, 255).bold()
-    )
-    .unwrap();
-    writeln!(
-        out,
-        "{}",
-        "═══════════════════════════════════════════════════════════════".truecolor(95, 158, 160)
-    )
-    .unwrap();
-
-    // Main information
-    // Main information
-    writeln!(
-        out,
-        "\n{}",
-        rust_i18n::t!("cli.section.main_info")
-            .truecolor(95, 158, 160)
-            .bold()
-    )
-    .unwrap();
-    writeln!(out).unwrap(); // Spacing
-
-    let label_color = |s: &str| s.truecolor(255, 160, 122); // Soft Coral
-    let value_color = |s: &str| s.truecolor(255, 215, 0); // Gold
-    let desc_color = colored::Color::TrueColor {
-        r: 230,
-        g: 228,
-        b: 208,
-    }; // Beige
-
-    // Helper for conditional table cell formatting
-    let add_style = |cell: Cell, color: TableColor, bold: bool| -> Cell {
-        if plain {
-            cell
-        } else {
-            let mut c = cell.fg(color);
-            if bold {
-                c = c.add_attribute(Attribute::Bold);
-            }
-            c
-        }
+/// Convert a Unix timestamp (UTC) to date and time
+pub fn parse_epoch(epoch: i64) -> Result<(i32, u8, u8, u8, u8, u8), String> {
+    use chrono::{Datelike, Timelike};
+    let dt = chrono::DateTime::from_timestamp(epoch, 0)
+        .ok_or_else(|| rust_i18n::t!("error.parse_epoch", value = epoch).to_string())?;
+    Ok((
+        dt.year(),
+        dt.month() as u8,
+        dt.day() as u8,
+        dt.hour() as u8,
+        dt.minute() as u8,
+        dt.second() as u8,
+    ))
+}
+
+/// Parse date, time and UTC offset strings into a Julian Day (the date read in `calendar`)
+pub fn parse_moment_jd(date: &str, time: &str, utc: &str, calendar: Calendar) -> Result<f64, String> {
+    let (year, month, day) = parse_date(date)?;
+    let (hour, min, sec) = parse_time(time)?;
+    let utc_offset = parse_utc_offset(utc, year, month, day)?;
+    Ok(crate::astro_calc::calc_julian_day(
+        (year, month, day),
+        (hour, min, sec),
+        utc_offset,
+        calendar,
+    ))
+}
+
+/// JSON with keys converted to the requested casing
+fn to_json<T: serde::Serialize>(value: &T, json: JsonOptions) -> String {
+    let value = match json.case {
+        JsonCase::Snake => serde_json::to_value(value).unwrap(),
+        JsonCase::Camel => camel_case_keys(serde_json::to_value(value).unwrap()),
     };
+    if json.compact {
+        serde_json::to_string(&value).unwrap()
+    } else {
+        serde_json::to_string_pretty(&value).unwrap()
+    }
+}
 
-    writeln!(
-        out,
-        "  {} {} {} UTC{}",
-        label_color(&rust_i18n::t!("cli.label.date")),
-        value_color(&chart.birth_date),
-        value_color(&chart.birth_time),
-        value_color(&format!("{:+}", chart.utc_offset))
-    )
-    .unwrap();
-    writeln!(out).unwrap(); // Empty line after Date for spacing
-
-    writeln!(
-        out,
-        "  {} {}",
-        label_color(&rust_i18n::t!("cli.label.type")),
-        value_color(&chart.hd_type).bold()
-    )
-    .unwrap();
-    if let Some(ref desc) = chart.type_description {
-        write_wrapped(&mut out, desc, 4, Some(desc_color), false);
-    }
-    writeln!(out).unwrap(); // Empty line after item
-
-    writeln!(
-        out,
-        "  {} {}",
-        label_color(&rust_i18n::t!("cli.label.profile")),
-        value_color(&chart.profile).bold()
-    )
-    .unwrap();
-    if let Some(ref desc) = chart.profile_description {
-        write_wrapped(&mut out, desc, 4, Some(desc_color), false);
-    }
-    writeln!(out).unwrap(); // Empty line after item
-
-    writeln!(
-        out,
-        "  {} {}",
-        label_color(&rust_i18n::t!("cli.label.authority")),
-        value_color(&chart.authority).bold()
-    )
-    .unwrap();
-    if let Some(ref desc) = chart.authority_description {
-        write_wrapped(&mut out, desc, 4, Some(desc_color), false);
-    }
-    writeln!(out).unwrap(); // Empty line after item
-
-    writeln!(
-        out,
-        "  {} {}",
-        label_color(&rust_i18n::t!("cli.label.strategy")),
-        value_color(&chart.strategy).bold()
-    )
-    .unwrap();
-    if let Some(ref desc) = chart.strategy_description {
-        write_wrapped(&mut out, desc, 4, Some(desc_color), false);
-    }
-    writeln!(out).unwrap(); // Empty line after item
-
-    writeln!(
-        out,
-        "  {} {}",
-        label_color(&rust_i18n::t!("cli.label.cross")),
-        value_color(&chart.incarnation_cross).bold()
-    )
-    .unwrap();
-    if let Some(ref desc) = chart.cross_description {
-        write_wrapped(&mut out, desc, 4, Some(desc_color), false);
-    }
-    writeln!(out).unwrap(); // Empty line after item
-
-    // Business
-    if let Some(ref biz) = chart.business {
-        write_gate_section_items(&mut out, &rust_i18n::t!("cli.section.business"), biz);
-    }
-
-    // 4. CHANNELS (Moved here, after Business)
-    if !chart.channels.is_empty() {
-        writeln!(
-            out,
-            "\n{}",
-            rust_i18n::t!("cli.section.channels")
-                .truecolor(95, 158, 160)
-                .bold()
-        )
-        .unwrap();
-        writeln!(out).unwrap(); // Отступ
-
-        let has_descriptions = chart.channels.iter().any(|ch| ch.description.is_some());
-
-        let mut table = Table::new();
-        table
-            .load_preset(presets::UTF8_FULL)
-            .set_content_arrangement(ContentArrangement::Dynamic);
-
-        let mut headers = vec![
-            add_style(
-                Cell::new(&rust_i18n::t!("cli.label.channel")),
-                TableColor::Rgb {
-                    r: 255,
-                    g: 160,
-                    b: 122,
-                },
-                true,
-            ), // Coral
-            add_style(
-                Cell::new(&rust_i18n::t!("cli.label.name")),
-                TableColor::Rgb {
-                    r: 255,
-                    g: 215,
-                    b: 0,
-                },
-                true,
-            ), // Gold
-        ];
-        if has_descriptions {
-            headers.push(add_style(
-                Cell::new(&rust_i18n::t!("cli.label.description")),
-                TableColor::Rgb {
-                    r: 255,
-                    g: 160,
-                    b: 122,
-                },
-                true,
-            )); // Coral
-        }
-        table.set_header(headers);
-
-        for ch in &chart.channels {
-            let mut row = vec![
-                add_style(
-                    Cell::new(&ch.key),
-                    TableColor::Rgb {
-                        r: 95,
-                        g: 158,
-                        b: 160,
-                    },
-                    false,
-                ),
-                add_style(
-                    Cell::new(&ch.name),
-                    TableColor::Rgb {
-                        r: 255,
-                        g: 215,
-                        b: 0,
-                    },
-                    true,
-                ), // Gold
-            ];
-            if has_descriptions {
-                let desc = ch.description.clone().unwrap_or_default();
-                row.push(add_style(
-                    Cell::new(&desc),
-                    TableColor::Rgb {
-                        r: 230,
-                        g: 228,
-                        b: 208,
-                    },
-                    false,
-                ));
-            }
-            table.add_row(row);
-        }
-        writeln!(out, "{}", table).unwrap();
-    }
-
-    // 5. Planets (General table) (Now here)
-    write_combined_planet_table(&mut out, &chart.design, &chart.personality, plain);
-
-    // Centers
-    writeln!(
-        out,
-        "\n{}",
-        rust_i18n::t!("cli.section.centers")
-            .truecolor(95, 158, 160)
-            .bold()
-    )
-    .unwrap();
-    writeln!(out).unwrap(); // Spacing
-
-    let mut table = Table::new();
-    table
-        .load_preset(presets::UTF8_FULL)
-        .set_content_arrangement(ContentArrangement::Dynamic);
-
-    table.set_header(vec![
-        add_style(
-            Cell::new(&rust_i18n::t!("cli.label.center")),
-            TableColor::Rgb {
-                r: 255,
-                g: 160,
-                b: 122,
-            },
-            true,
-        ),
-        add_style(
-            Cell::new(&rust_i18n::t!("cli.label.status")),
-            TableColor::Rgb {
-                r: 255,
-                g: 160,
-                b: 122,
-            },
-            true,
+/// Recursively rename object keys from snake_case to camelCase
+fn camel_case_keys(value: serde_json::Value) -> serde_json::Value {
+    use serde_json::Value;
+    match value {
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .map(|(k, v)| (snake_to_camel(&k), camel_case_keys(v)))
+                .collect(),
         ),
-    ]);
-    for center in &chart.centers {
-        let status = if center.defined {
-            format!("● {}", rust_i18n::t!("cli.label.defined"))
-        } else {
-            format!("○ {}", rust_i18n::t!("cli.label.open"))
-        };
-        let height_color = if center.defined {
-            TableColor::Rgb {
-                r: 255,
-                g: 215,
-                b: 0,
-            }
-        } else {
-            TableColor::DarkGrey
-        }; // Gold for defined
-        let color = height_color;
-
-        // Combine behavior descriptions if available
-        let content = if let (Some(ref norm), Some(ref dist)) =
-            (&center.behavior_normal, &center.behavior_distorted)
-        {
-            format!("{}\n\n{}", norm, dist)
-        } else if let Some(ref beh) = center
-            .behavior_normal
-            .as_ref()
-            .or(center.behavior_distorted.as_ref())
-        {
-            // Fallback if only one exists (unlikely given calc.rs logic)
-            beh.to_string()
+        Value::Array(items) => Value::Array(items.into_iter().map(camel_case_keys).collect()),
+        other => other,
+    }
+}
+
+fn snake_to_camel(key: &str) -> String {
+    let mut out = String::with_capacity(key.len());
+    let mut upper = false;
+    for ch in key.chars() {
+        if ch == '_' {
+            upper = !out.is_empty();
+        } else if upper {
+            out.extend(ch.to_uppercase());
+            upper = false;
         } else {
-            status.to_string()
-        };
+            out.push(ch);
+        }
+    }
+    out
+}
 
-        table.add_row(vec![
-            add_style(Cell::new(&center.name), color, true),
-            add_style(
-                Cell::new(&content),
-                TableColor::Rgb {
-                    r: 230,
-                    g: 228,
-                    b: 208,
-                },
-                false,
-            ),
-        ]);
+/// Generate chart output string
+pub fn generate_output(
+    chart: &HdChart,
+    format: &OutputFormat,
+    json: JsonOptions,
+    csv: CsvOptions,
+    render_opts: &RenderOptions,
+) -> String {
+    match format {
+        OutputFormat::Json => to_json(&selected_sections(chart, render_opts), json),
+        OutputFormat::Yaml => serde_yaml::to_string(&selected_sections(chart, render_opts)).unwrap(),
+        OutputFormat::Table => render::render_chart(chart, render_opts),
+        OutputFormat::Html => crate::site::report_html(chart),
+        OutputFormat::Csv => chart_csv(chart, csv),
+        OutputFormat::Summary => summary_line(chart),
     }
-    writeln!(out, "{}", table).unwrap();
+}
 
-    // Additional information
-    let has_extra = chart.motivation.is_some()
-        || chart.environment.is_some()
-        || chart.diet.is_some()
-        || chart.vision.is_some();
+/// Chart as a JSON value without the keys of the sections left out by
+/// `--sections` / `--exclude-sections`
+fn selected_sections(chart: &HdChart, render_opts: &RenderOptions) -> serde_json::Value {
+    let mut value = serde_json::to_value(chart).unwrap();
+    if let serde_json::Value::Object(map) = &mut value {
+        for section in Section::value_variants() {
+            if !render_opts.shows(*section) {
+                for key in section.json_keys() {
+                    map.shift_remove(*key);
+                }
+            }
+        }
+    }
+    value
+}
+
+/// Generate transit report output string
+pub fn generate_transit_output(
+    report: &TransitReport,
+    format: &OutputFormat,
+    json: JsonOptions,
+    render_opts: &RenderOptions,
+) -> String {
+    match format {
+        OutputFormat::Json => to_json(report, json),
+        OutputFormat::Yaml => serde_yaml::to_string(report).unwrap(),
+        OutputFormat::Table | OutputFormat::Html | OutputFormat::Csv | OutputFormat::Summary => {
+            render::render_transit(report, render_opts)
+        }
+    }
+}
 
-    let is_full_mode = chart.type_description.is_some();
+/// Generate composite (group) chart output string
+pub fn generate_composite_output(
+    chart: &CompositeChart,
+    format: &OutputFormat,
+    json: JsonOptions,
+    render_opts: &RenderOptions,
+) -> String {
+    match format {
+        OutputFormat::Json => to_json(chart, json),
+        OutputFormat::Yaml => serde_yaml::to_string(chart).unwrap(),
+        OutputFormat::Table | OutputFormat::Html | OutputFormat::Csv | OutputFormat::Summary => {
+            render::render_composite(chart, render_opts)
+        }
+    }
+}
 
-    // Fear Section
-    if let Some(ref items) = chart.fear {
-        write_gate_section_items(&mut out, &rust_i18n::t!("cli.section.fear"), items);
+/// Generate transit planner output string
+pub fn generate_planner_output(
+    plan: &TransitPlan,
+    format: &OutputFormat,
+    json: JsonOptions,
+    render_opts: &RenderOptions,
+) -> String {
+    match format {
+        OutputFormat::Json => to_json(plan, json),
+        OutputFormat::Yaml => serde_yaml::to_string(plan).unwrap(),
+        OutputFormat::Table | OutputFormat::Html | OutputFormat::Csv | OutputFormat::Summary => {
+            render::render_planner(plan, render_opts)
+        }
     }
+}
 
-    // Sexuality Section
-    if let Some(ref items) = chart.sexuality {
-        write_gate_section_items(&mut out, &rust_i18n::t!("cli.section.sexuality"), items);
+/// Generate lunar cycle output string
+pub fn generate_lunar_cycle_output(
+    cycle: &LunarCycle,
+    format: &OutputFormat,
+    json: JsonOptions,
+    render_opts: &RenderOptions,
+) -> String {
+    match format {
+        OutputFormat::Json => to_json(cycle, json),
+        OutputFormat::Yaml => serde_yaml::to_string(cycle).unwrap(),
+        OutputFormat::Table | OutputFormat::Html | OutputFormat::Csv | OutputFormat::Summary => {
+            render::render_lunar_cycle(cycle, render_opts)
+        }
     }
+}
 
-    // Love Section
-    if let Some(ref items) = chart.love {
-        write_gate_section_items(&mut out, &rust_i18n::t!("cli.section.love"), items);
+/// Generate Sun ingresses output string
+pub fn generate_sun_ingresses_output(
+    ingresses: &[SunIngress],
+    format: &OutputFormat,
+    json: JsonOptions,
+    render_opts: &RenderOptions,
+) -> String {
+    match format {
+        OutputFormat::Json => to_json(&ingresses, json),
+        OutputFormat::Yaml => serde_yaml::to_string(&ingresses).unwrap(),
+        OutputFormat::Table | OutputFormat::Html | OutputFormat::Csv | OutputFormat::Summary => {
+            render::render_sun_ingresses(ingresses, render_opts)
+        }
     }
+}
 
-    if has_extra && is_full_mode {
-        writeln!(
+/// Sun ingresses as an iCalendar file, one event per ingress
+pub fn generate_sun_ingresses_ical(ingresses: &[SunIngress]) -> String {
+    let mut out = String::new();
+    out.push_str("BEGIN:VCALENDAR\r\nVERSION:2.0\r\nPRODID:-//hd-cli//Sun ingresses//EN\r\n");
+    for i in ingresses {
+        let stamp = crate::astro_calc::jd_to_utc(i.julian_day).format("%Y%m%dT%H%M%SZ");
+        let summary = rust_i18n::t!(
+            "cli.ingresses.summary",
+            gate = i.gate,
+            line = i.line,
+            name = i.gate_name.clone().unwrap_or_default()
+        );
+        write!(
             out,
-            "\n{}",
-            rust_i18n::t!("cli.section.extra")
-                .truecolor(95, 158, 160)
-                .bold()
+            "BEGIN:VEVENT\r\nUID:sun-{}-{}@hd-cli\r\nDTSTAMP:{}\r\nDTSTART:{}\r\nSUMMARY:{}\r\nEND:VEVENT\r\n",
+            stamp, i.gate, stamp, stamp, summary.trim_end()
         )
         .unwrap();
-        writeln!(out).unwrap(); // Spacing
+    }
+    out.push_str("END:VCALENDAR\r\n");
+    out
+}
 
-        if let Some(ref m) = chart.motivation {
-            write_info_items(&mut out, &rust_i18n::t!("cli.label.motivation"), m);
-        }
-        if let Some(ref v) = chart.vision {
-            write_info_items(&mut out, &rust_i18n::t!("cli.label.vision"), v);
-        }
-        if let Some(ref e) = chart.environment {
-            write_info_items(&mut out, &rust_i18n::t!("cli.label.environment"), e);
-        }
-        if let Some(ref d) = chart.diet {
-            write_info_items(&mut out, &rust_i18n::t!("cli.label.diet"), d);
+/// Generate solar returns output string
+pub fn generate_solar_returns_output(
+    returns: &[SolarReturn],
+    days: u32,
+    format: &OutputFormat,
+    json: JsonOptions,
+    render_opts: &RenderOptions,
+) -> String {
+    match format {
+        OutputFormat::Json => to_json(&returns, json),
+        OutputFormat::Yaml => serde_yaml::to_string(&returns).unwrap(),
+        OutputFormat::Table | OutputFormat::Html | OutputFormat::Csv | OutputFormat::Summary => {
+            render::render_solar_returns(returns, days, render_opts)
         }
     }
+}
 
-    if let Some(ref scores) = chart.circuit_scores {
-        if !scores.is_empty() {
-            write_circuit_scores_table(&mut out, scores, plain);
+/// Generate Gene Keys output string
+pub fn generate_genekeys_output(
+    chart: &GeneKeysChart,
+    format: &OutputFormat,
+    json: JsonOptions,
+    render_opts: &RenderOptions,
+) -> String {
+    match format {
+        OutputFormat::Json => to_json(chart, json),
+        OutputFormat::Yaml => serde_yaml::to_string(chart).unwrap(),
+        OutputFormat::Table | OutputFormat::Html | OutputFormat::Csv | OutputFormat::Summary => {
+            render::render_genekeys(chart, render_opts)
         }
     }
-
-    out
 }
 
-fn write_info_items(out: &mut String, title: &str, items: &[crate::models::InfoItem]) {
-    writeln!(out, "  {}", title.truecolor(255, 215, 0)).unwrap(); // Gold Title
-
-    let label_color = colored::Color::TrueColor {
-        r: 255,
-        g: 160,
-        b: 122,
-    };
-    let desc_color = colored::Color::TrueColor {
-        r: 230,
-        g: 228,
-        b: 208,
-    };
-
-    for item in items {
-        writeln!(out, "    {}", item.label.color(label_color)).unwrap();
-        if !item.description.is_empty() {
-            write_wrapped(out, &item.description, 6, Some(desc_color), false);
+pub fn generate_ephemeris_output(
+    ephemeris: &Ephemeris,
+    format: &OutputFormat,
+    json: JsonOptions,
+    render_opts: &RenderOptions,
+) -> String {
+    match format {
+        OutputFormat::Json => to_json(ephemeris, json),
+        OutputFormat::Yaml => serde_yaml::to_string(ephemeris).unwrap(),
+        OutputFormat::Table | OutputFormat::Html | OutputFormat::Csv | OutputFormat::Summary => {
+            render::render_ephemeris(ephemeris, render_opts)
         }
     }
 }
 
-fn write_combined_planet_table(
-    out: &mut String,
-    design: &[crate::models::PlanetPosition],
-    personality: &[crate::models::PlanetPosition],
-    plain: bool,
-) {
-    writeln!(
-        out,
-        "\n{}",
-        rust_i18n::t!("cli.section.planets")
-            .truecolor(95, 158, 160)
-            .bold()
-    )
-    .unwrap();
-
-    let tc_label = TableColor::Rgb {
-        r: 255,
-        g: 160,
-        b: 122,
-    };
-    let tc_white = TableColor::White;
-
-    let add_style = |cell: Cell, color: TableColor, bold: bool| -> Cell {
-        if plain {
-            cell
-        } else {
-            let mut c = cell.fg(color);
-            if bold {
-                c = c.add_attribute(Attribute::Bold);
-            }
-            c
+/// Generate life-cycle dates output string
+pub fn generate_cycles_output(
+    events: &[CycleEvent],
+    format: &OutputFormat,
+    json: JsonOptions,
+    render_opts: &RenderOptions,
+) -> String {
+    match format {
+        OutputFormat::Json => to_json(&events, json),
+        OutputFormat::Yaml => serde_yaml::to_string(&events).unwrap(),
+        OutputFormat::Table | OutputFormat::Html | OutputFormat::Csv | OutputFormat::Summary => {
+            render::render_cycles(events, render_opts)
         }
-    };
+    }
+}
 
-    let mut table = Table::new();
-    table
-        .load_preset(presets::UTF8_FULL)
-        .set_content_arrangement(ContentArrangement::Dynamic)
-        .set_header(vec![
-            add_style(
-                Cell::new(&rust_i18n::t!("planet.name_header")),
-                tc_label,
-                true,
-            ), // "Planet"
-            add_style(
-                Cell::new(&format!(
-                    "{}.{}",
-                    rust_i18n::t!("cli.label.gate"),
-                    rust_i18n::t!("cli.label.line")
-                )),
-                tc_label,
-                true,
-            ),
-            add_style(Cell::new(&rust_i18n::t!("cli.label.sign")), tc_label, true),
-            add_style(Cell::new(&rust_i18n::t!("cli.label.sign")), tc_label, true),
-            add_style(
-                Cell::new(&format!(
-                    "{}.{}",
-                    rust_i18n::t!("cli.label.gate"),
-                    rust_i18n::t!("cli.label.line")
-                )),
-                tc_label,
-                true,
-            ),
-            add_style(
-                Cell::new(&rust_i18n::t!("planet.name_header")),
-                tc_label,
-                true,
-            ),
-        ]);
-
-    // Set minimum width for Sign columns (index 2 and 3) to prevent squashing
-    let min_sign_width = ColumnConstraint::LowerBoundary(comfy_table::Width::Fixed(15));
-    if let Some(col) = table.column_mut(2) {
-        col.set_constraint(min_sign_width);
-    }
-    if let Some(col) = table.column_mut(3) {
-        col.set_constraint(min_sign_width);
-    }
-
-    for (des, pers) in design.iter().zip(personality.iter()) {
-        let des_sign = format!("{} {:.2}°", des.zodiac_symbol, des.zodiac_degree);
-        let pers_sign = format!("{} {:.2}°", pers.zodiac_symbol, pers.zodiac_degree);
-
-        let des_gate_line = format!("{}.{}", des.gate, des.line);
-        let pers_gate_line = format!("{}.{}", pers.gate, pers.line);
-
-        table.add_row(vec![
-            add_style(
-                Cell::new(&format!("{} {}", des.planet_symbol, des.planet)),
-                tc_label,
-                false,
-            ),
-            add_style(Cell::new(&des_gate_line), tc_label, true),
-            add_style(Cell::new(&des_sign), tc_label, false),
-            add_style(Cell::new(&pers_sign), tc_white, false),
-            add_style(Cell::new(&pers_gate_line), tc_white, true),
-            add_style(
-                Cell::new(&format!("{} {}", pers.planet_symbol, pers.planet)),
-                tc_white,
-                false,
-            ),
-        ]);
-    }
-
-    writeln!(out, "{}", table).unwrap();
-
-    // Output descriptions (Design + Personality) - only if descriptions exist
-    let has_descriptions = personality.iter().any(|p| p.gate_description.is_some());
-
-    if has_descriptions {
-        let term_width = if let Some((Width(w), _)) = terminal_size() {
-            w as usize
-        } else {
-            80
-        };
+/// Generate profile matrix output string
+pub fn generate_matrix_output(
+    matrix: &ProfileMatrix,
+    format: &OutputFormat,
+    json: JsonOptions,
+    render_opts: &RenderOptions,
+) -> String {
+    match format {
+        OutputFormat::Json => to_json(matrix, json),
+        OutputFormat::Yaml => serde_yaml::to_string(matrix).unwrap(),
+        OutputFormat::Table | OutputFormat::Html | OutputFormat::Csv | OutputFormat::Summary => {
+            render::render_matrix(matrix, render_opts)
+        }
+    }
+}
 
-        // Standardized Headers
-        writeln!(
-            out,
-            "\n{}",
-            rust_i18n::t!("cli.section.personality")
-                .truecolor(95, 158, 160)
-                .bold()
-        )
-        .unwrap();
-        // Removed extra newline here
-        write_descriptions(out, personality, term_width);
+/// One-line chart summary for scripts, e.g.
+/// `Generator · 3/5 · Emotional · Split Definition · RAX of Eden (23/43 | 30/29)`
+pub fn summary_line(chart: &HdChart) -> String {
+    [
+        &chart.hd_type,
+        &chart.profile,
+        &chart.authority,
+        &chart.definition,
+        &chart.incarnation_cross,
+    ]
+    .map(|s| s.as_str())
+    .join(" · ")
+}
 
-        writeln!(
-            out,
-            "\n{}",
-            rust_i18n::t!("cli.section.design")
-                .truecolor(95, 158, 160)
-                .bold()
-        )
-        .unwrap();
-        // Removed extra newline here
-        write_descriptions(out, design, term_width);
-    }
-}
-
-fn write_descriptions(
-    out: &mut String,
-    data: &[crate::models::PlanetPosition],
-    _term_width: usize,
-) {
-    let desc_color = colored::Color::TrueColor {
-        r: 230,
-        g: 228,
-        b: 208,
-    }; // Beige
-    let label_color = colored::Color::TrueColor {
-        r: 255,
-        g: 160,
-        b: 122,
-    }; // Soft Coral
-    let value_color = colored::Color::TrueColor {
-        r: 255,
-        g: 215,
-        b: 0,
-    }; // Gold
-
-    for p in data {
-        if let (Some(g_desc), Some(l_desc)) = (&p.gate_description, &p.line_description) {
-            let gate_hdr_txt = if let Some(g_name) = &p.gate_name {
-                format!("{} {}: {}", rust_i18n::t!("cli.label.gate"), p.gate, g_name)
-            } else {
-                format!("{} {}", rust_i18n::t!("cli.label.gate"), p.gate)
-            };
-
-            // Header for Gate
-            writeln!(
-                out,
-                "\n  {} - {}",
-                format!("{} {}", p.planet_symbol, p.planet)
-                    .color(label_color)
-                    .bold(),
-                gate_hdr_txt.color(value_color).bold()
-            )
-            .unwrap();
-            write_wrapped(out, g_desc, 4, Some(desc_color), false);
+/// CSV field, quoted when it contains a comma, quote or line break
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
 
-            // Header for Line (Label/Gold/Bold)
+/// Chart as CSV: one row per planetary activation (Personality, then Design)
+/// or a single summary row
+pub fn chart_csv(chart: &HdChart, csv: CsvOptions) -> String {
+    let mut out = String::new();
+    match csv.rows {
+        CsvRows::Activations => {
+            if !csv.no_header {
+                out.push_str("side,planet,gate,line,color,tone,base,longitude\n");
+            }
+            for p in chart.personality.iter().chain(chart.design.iter()) {
+                writeln!(
+                    out,
+                    "{},{},{},{},{},{},{},{:.6}",
+                    p.activation,
+                    csv_field(&p.planet),
+                    p.gate,
+                    p.line,
+                    p.color,
+                    p.tone,
+                    p.base,
+                    p.longitude
+                )
+                .unwrap();
+            }
+        }
+        CsvRows::Summary => {
+            if !csv.no_header {
+                out.push_str(
+                    "birth_date,birth_time,utc_offset,type,profile,authority,strategy,definition,incarnation_cross,defined_centers,channels\n",
+                );
+            }
+            let channels: Vec<&str> = chart.channels.iter().map(|c| c.key.as_str()).collect();
             writeln!(
                 out,
-                "    {}",
-                format!("{} {}:", rust_i18n::t!("cli.label.line"), p.line)
-                    .color(label_color)
-                    .bold()
+                "{},{},{},{},{},{},{},{},{},{},{}",
+                chart.birth_date,
+                chart.birth_time,
+                chart.utc_offset,
+                csv_field(&chart.hd_type),
+                csv_field(&chart.profile),
+                csv_field(&chart.authority),
+                csv_field(&chart.strategy),
+                csv_field(&chart.definition),
+                csv_field(&chart.incarnation_cross),
+                chart.summary.defined_centers,
+                channels.join(" ")
             )
             .unwrap();
-            write_wrapped(out, l_desc, 6, Some(desc_color), false);
         }
     }
+    out.trim_end().to_string()
 }
 
-fn write_gate_section_items(out: &mut String, title: &str, items: &[crate::models::InfoItem]) {
-    writeln!(out, "\n{}", title.truecolor(95, 158, 160).bold()).unwrap();
-    writeln!(out).unwrap(); // Spacing
-
-    let desc_color = colored::Color::TrueColor {
-        r: 230,
-        g: 228,
-        b: 208,
-    }; // Beige
-    let label_color = colored::Color::TrueColor {
-        r: 255,
-        g: 160,
-        b: 122,
-    }; // Soft Coral
-    let value_color = colored::Color::TrueColor {
-        r: 255,
-        g: 215,
-        b: 0,
-    }; // Gold
-
-    for item in items {
-        if let (Some(planets), Some(gate_id), Some(gate_name)) =
-            (&item.planets, item.gate_id, &item.gate_name)
-        {
-            // New Format: Planet - Gate
-            // "☉ Sun, ⊕ Earth - Gate 5: Name"
-            let mut planets_vec: Vec<_> = planets.iter().collect();
-            planets_vec.sort();
-
-            let planets_str = planets_vec
-                .iter()
-                .map(|p| format!("{} {}", p.symbol, p.name))
-                .collect::<Vec<_>>()
-                .join(", ");
-
-            let gate_part = format!(
-                "{} {}: {}",
-                rust_i18n::t!("cli.label.gate"),
-                gate_id,
-                gate_name
-            );
+/// Profile matrix as CSV, one row per pair
+pub fn matrix_csv(matrix: &ProfileMatrix) -> String {
+    let mut out = String::from(
+        "a,b,electromagnetic,dominance,compromise,companionship,type,defined_centers\n",
+    );
+    for p in &matrix.pairs {
+        writeln!(
+            out,
+            "{},{},{},{},{},{},{},{}",
+            csv_field(&p.a),
+            csv_field(&p.b),
+            p.electromagnetic,
+            p.dominance,
+            p.compromise,
+            p.companionship,
+            csv_field(&p.hd_type),
+            p.defined_centers
+        )
+        .unwrap();
+    }
+    out.trim_end().to_string()
+}
 
-            writeln!(
-                out,
-                "  {} - {}",
-                planets_str.color(label_color).bold(),
-                gate_part.color(value_color).bold()
-            )
-            .unwrap();
-            write_wrapped(out, &item.description, 4, Some(desc_color), false);
-        } else {
-            // Fallback / Standard InfoItem
-            writeln!(out, "  {}", item.label.truecolor(255, 160, 122)).unwrap();
-            write_wrapped(out, &item.description, 4, Some(desc_color), false);
+pub fn generate_dreamrave_output(
+    chart: &DreamRaveChart,
+    format: &OutputFormat,
+    json: JsonOptions,
+    render_opts: &RenderOptions,
+) -> String {
+    match format {
+        OutputFormat::Json => to_json(chart, json),
+        OutputFormat::Yaml => serde_yaml::to_string(chart).unwrap(),
+        OutputFormat::Table | OutputFormat::Html | OutputFormat::Csv | OutputFormat::Summary => {
+            render::render_dreamrave(chart, render_opts)
         }
     }
 }
 
-fn write_wrapped(
-    out: &mut String,
-    text: &str,
-    indent: usize,
-    color: Option<colored::Color>,
-    dimmed: bool,
-) {
-    let width = if let Some((Width(w), _)) = terminal_size() {
-        w as usize
-    } else {
-        80
-    };
-
-    let indent_str = " ".repeat(indent);
-    let options = Options::new(width)
-        .initial_indent(&indent_str)
-        .subsequent_indent(&indent_str);
-
-    let wrapped = textwrap::fill(text, &options);
-
-    let mut style = if let Some(c) = color {
-        wrapped.color(c)
-    } else {
-        wrapped.normal()
-    };
-
-    if dimmed {
-        style = style.dimmed();
+pub fn generate_penta_output(
+    report: &PentaReport,
+    format: &OutputFormat,
+    json: JsonOptions,
+    render_opts: &RenderOptions,
+) -> String {
+    match format {
+        OutputFormat::Json => to_json(report, json),
+        OutputFormat::Yaml => serde_yaml::to_string(report).unwrap(),
+        OutputFormat::Table | OutputFormat::Html | OutputFormat::Csv | OutputFormat::Summary => {
+            render::render_penta(report, render_opts)
+        }
     }
-
-    writeln!(out, "{}", style).unwrap();
 }
 
-fn write_circuit_scores_table(
-    out: &mut String,
-    scores: &[crate::models::CircuitScoreItem],
-    plain: bool,
-) {
-    use crate::circuit_score::group_by_circuit;
+/// People from CSV rows `name,date,time,utc` as (name, Personality JD).
+/// Blank lines, `#` comments and a leading `name,...` header row are skipped.
+pub fn parse_people_csv(content: &str, calendar: Calendar) -> Result<Vec<(String, f64)>, String> {
+    let mut people = Vec::new();
+    for (i, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let fields: Vec<&str> = line.split(',').map(|f| f.trim().trim_matches('"')).collect();
+        if people.is_empty() && fields[0].eq_ignore_ascii_case("name") {
+            continue;
+        }
+        let [name, date, time, utc] = fields[..] else {
+            return Err(rust_i18n::t!("error.people_csv", line = i + 1).to_string());
+        };
+        let jd = parse_moment_jd(date, time, utc, calendar).map_err(|e| format!("{}: {}", name, e))?;
+        people.push((name.to_string(), jd));
+    }
+    Ok(people)
+}
 
-    writeln!(
-        out,
-        "\n{}",
-        rust_i18n::t!("cli.section.circuits")
-            .truecolor(95, 158, 160)
-            .bold()
-    )
-    .unwrap();
-    writeln!(out).unwrap();
+/// Resolved moment for `--dry-run` output
+pub fn resolve_moment(label: &str, jd: f64) -> ResolvedMoment {
+    ResolvedMoment {
+        label: label.to_string(),
+        utc: crate::astro_calc::jd_to_utc(jd)
+            .format("%Y-%m-%d %H:%M:%S")
+            .to_string(),
+        julian_day: jd,
+    }
+}
 
-    let add_style = |cell: Cell, color: TableColor, bold: bool| -> Cell {
-        if plain {
-            cell
-        } else {
-            let mut c = cell.fg(color);
-            if bold {
-                c = c.add_attribute(Attribute::Bold);
+/// Generate `--dry-run` output string
+pub fn generate_moments_output(
+    moments: &[ResolvedMoment],
+    format: &OutputFormat,
+    json: JsonOptions,
+) -> String {
+    match format {
+        OutputFormat::Json => to_json(&moments, json),
+        OutputFormat::Yaml => serde_yaml::to_string(&moments).unwrap(),
+        OutputFormat::Table | OutputFormat::Html | OutputFormat::Csv | OutputFormat::Summary => {
+            let mut out = String::new();
+            for m in moments {
+                writeln!(
+                    out,
+                    "{}: {} UTC, JD {:.6}",
+                    m.label.truecolor(255, 160, 122),
+                    m.utc.truecolor(255, 215, 0),
+                    m.julian_day
+                )
+                .unwrap();
             }
-            c
-        }
-    };
-
-    let tc_coral = TableColor::Rgb {
-        r: 255,
-        g: 160,
-        b: 122,
-    };
-    let tc_gold = TableColor::Rgb {
-        r: 255,
-        g: 215,
-        b: 0,
-    };
-    let tc_teal = TableColor::Rgb {
-        r: 95,
-        g: 158,
-        b: 160,
-    };
-    let tc_beige = TableColor::Rgb {
-        r: 230,
-        g: 228,
-        b: 208,
-    };
-    let tc_grey = TableColor::DarkGrey;
-
-    let mut table = Table::new();
-    table
-        .load_preset(presets::UTF8_FULL)
-        .set_content_arrangement(ContentArrangement::Dynamic)
-        .set_header(vec![
-            add_style(
-                Cell::new(rust_i18n::t!("cli.label.circuit").as_ref()),
-                tc_coral,
-                true,
-            ),
-            add_style(
-                Cell::new(rust_i18n::t!("cli.label.score").as_ref()),
-                tc_coral,
-                true,
-            ),
-            add_style(
-                Cell::new(rust_i18n::t!("cli.label.planets").as_ref()),
-                tc_coral,
-                true,
-            ),
-            add_style(
-                Cell::new(rust_i18n::t!("cli.label.channels").as_ref()),
-                tc_coral,
-                true,
-            ),
-            add_style(
-                Cell::new(rust_i18n::t!("cli.label.description").as_ref()),
-                tc_coral,
-                true,
-            ),
-        ]);
-
-    let grouped = group_by_circuit(scores);
-
-    for (_, circuit_name, circuit_desc, circuit_total, sub_items) in &grouped {
-        let desc = if circuit_desc.is_empty() {
-            "—".to_string()
-        } else {
-            circuit_desc.clone()
-        };
-        table.add_row(vec![
-            add_style(Cell::new(format!("▶ {}", circuit_name)), tc_gold, true),
-            add_style(Cell::new(format!("{:.1}", circuit_total)), tc_gold, true),
-            add_style(Cell::new(""), tc_grey, false),
-            add_style(Cell::new(""), tc_grey, false),
-            add_style(Cell::new(&desc), tc_beige, false),
-        ]);
-
-        // ── Sub-circuit rows ────────────────────────────────────────────
-        for item in sub_items {
-            let sub_label = format!("  └ {}", item.sub_circuit_name);
-            let desc = if item.description.is_empty() {
-                "—".to_string()
-            } else {
-                item.description.clone()
-            };
-            table.add_row(vec![
-                add_style(Cell::new(&sub_label), tc_teal, false),
-                add_style(Cell::new(format!("{:.1}", item.score)), tc_teal, true),
-                add_style(
-                    Cell::new(format!("{}p", item.planet_count)),
-                    tc_beige,
-                    false,
-                ),
-                add_style(
-                    Cell::new(format!("{}ch", item.channel_count)),
-                    tc_beige,
-                    false,
-                ),
-                add_style(Cell::new(&desc), tc_beige, false),
-            ]);
+            out.trim_end().to_string()
         }
     }
+}
 
-    writeln!(out, "{}", table).unwrap();
+// Deprecated in favor of generate_output + println! in main
+pub fn output_chart(chart: &HdChart, format: &OutputFormat) {
+    println!(
+        "{}",
+        generate_output(
+            chart,
+            format,
+            JsonOptions::default(),
+            CsvOptions::default(),
+            &RenderOptions::default()
+        )
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn utc_offsets_and_abbreviations() {
+        // IST is read as India Standard Time (Irish and Israel times are only warned about)
+        assert_eq!(parse_utc_offset("IST", 1990, 5, 15), Ok(5.5));
+        assert_eq!(parse_utc_offset("ist", 1990, 5, 15), Ok(5.5));
+        assert_eq!(parse_utc_offset("+5.5", 1990, 5, 15), Ok(5.5));
+        assert_eq!(parse_utc_offset("MSK", 2012, 6, 1), Ok(4.0));
+        assert_eq!(parse_utc_offset("MSK", 2015, 6, 1), Ok(3.0));
+        assert!(parse_utc_offset("+15", 1990, 5, 15).is_err());
+        assert!(parse_utc_offset("XYZ", 1990, 5, 15).is_err());
+    }
 }
@@ -2,16 +2,88 @@ use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::PathBuf;
 use directories::ProjectDirs;
+use crate::astro_calc::NodeModel;
+use crate::models::{Topic, Verbosity};
+use crate::render::WrapOptions;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Config {
     pub language: String,
+    /// Interface language; follows `language` when unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ui_language: Option<String>,
+    /// Command executed by the daemon on transit events
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notify_command: Option<String>,
+    /// Show color/tone/base by default
+    #[serde(default)]
+    pub depth: bool,
+    /// Cache computed charts on disk
+    #[serde(default)]
+    pub cache: bool,
+    /// Lunar node model (overridden by --node)
+    #[serde(default)]
+    pub node: NodeModel,
+    /// Default report topics (overridden by --with-*/--no-* flags)
+    #[serde(default)]
+    pub verbosity: Verbosity,
+    /// Default description wrapping (overridden by --wrap-* flags)
+    #[serde(default)]
+    pub wrap: WrapSettings,
+    /// URL template for gate/channel hyperlinks in terminal output
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub link_template: Option<String>,
+}
+
+/// Description wrapping settings; `None` keeps the built-in default
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default)]
+pub struct WrapSettings {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_width: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub indent: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub break_words: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub split_hyphens: Option<bool>,
+}
+
+impl WrapSettings {
+    /// Fill unset values from `fallback`
+    pub fn or(self, fallback: WrapSettings) -> WrapSettings {
+        WrapSettings {
+            max_width: self.max_width.or(fallback.max_width),
+            indent: self.indent.or(fallback.indent),
+            break_words: self.break_words.or(fallback.break_words),
+            split_hyphens: self.split_hyphens.or(fallback.split_hyphens),
+        }
+    }
+
+    /// Renderer options with built-in defaults for unset values
+    pub fn resolve(self) -> WrapOptions {
+        let defaults = WrapOptions::default();
+        WrapOptions {
+            // 0 means "no limit"
+            max_width: self.max_width.or(defaults.max_width).filter(|w| *w > 0),
+            indent: self.indent.unwrap_or(defaults.indent),
+            break_words: self.break_words.unwrap_or(defaults.break_words),
+            split_hyphens: self.split_hyphens.unwrap_or(defaults.split_hyphens),
+        }
+    }
 }
 
 impl Default for Config {
     fn default() -> Self {
         Self {
             language: "ru".to_string(), // Default language is Russian
+            ui_language: None,
+            notify_command: None,
+            depth: false,
+            cache: false,
+            node: NodeModel::Mean,
+            verbosity: Verbosity::default(),
+            wrap: WrapSettings::default(),
+            link_template: None,
         }
     }
 }
@@ -49,12 +121,79 @@ impl Config {
     /// Set default language
     pub fn set_language(&mut self, lang: &str) -> Result<(), String> {
         match lang {
-            "en" | "ru" | "es" => {
+            "en" | "ru" | "es" | "zh" => {
                 self.language = lang.to_string();
                 self.save()
             }
-            _ => Err(format!("Unsupported language: {}. Supported: en, ru, es", lang)),
+            _ => Err(format!("Unsupported language: {}. Supported: en, ru, es, zh", lang)),
+        }
+    }
+
+    /// Set interface language (empty string follows the description language)
+    pub fn set_ui_language(&mut self, lang: &str) -> Result<(), String> {
+        match lang {
+            "" => {
+                self.ui_language = None;
+                self.save()
+            }
+            "en" | "ru" | "es" | "zh" => {
+                self.ui_language = Some(lang.to_string());
+                self.save()
+            }
+            _ => Err(format!("Unsupported language: {}. Supported: en, ru, es, zh", lang)),
+        }
+    }
+
+    /// Set daemon notification command (empty string clears it)
+    pub fn set_notify_command(&mut self, command: &str) -> Result<(), String> {
+        self.notify_command = if command.trim().is_empty() {
+            None
+        } else {
+            Some(command.to_string())
+        };
+        self.save()
+    }
+
+    /// Set default for color/tone/base output
+    pub fn set_depth(&mut self, depth: bool) -> Result<(), String> {
+        self.depth = depth;
+        self.save()
+    }
+
+    /// Enable or disable the chart cache by default
+    pub fn set_cache(&mut self, cache: bool) -> Result<(), String> {
+        self.cache = cache;
+        self.save()
+    }
+
+    /// Set the default lunar node model
+    pub fn set_node(&mut self, node: NodeModel) -> Result<(), String> {
+        self.node = node;
+        self.save()
+    }
+
+    /// Show or hide report topics by default
+    pub fn set_topics(&mut self, topics: &[Topic], on: bool) -> Result<(), String> {
+        for topic in topics {
+            self.verbosity.set(*topic, on);
         }
+        self.save()
+    }
+
+    /// Store the given wrapping settings as defaults (unset values are kept)
+    pub fn set_wrap(&mut self, wrap: WrapSettings) -> Result<(), String> {
+        self.wrap = wrap.or(self.wrap);
+        self.save()
+    }
+
+    /// Set hyperlink URL template (empty string disables links)
+    pub fn set_link_template(&mut self, template: &str) -> Result<(), String> {
+        self.link_template = if template.trim().is_empty() {
+            None
+        } else {
+            Some(template.to_string())
+        };
+        self.save()
     }
 
     fn get_config_path() -> Option<PathBuf> {
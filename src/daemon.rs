@@ -0,0 +1,257 @@
+/// Background watcher: notifies about transit gate/line changes relevant to a natal chart
+use crate::astro_calc::{self, CalcOptions, HdPlanet};
+use crate::calc::{self, Activations};
+use crate::data::channels;
+use std::collections::HashSet;
+use std::process::Command;
+use std::thread;
+use std::time::Duration;
+
+/// Daemon settings (from CLI flags and config)
+#[derive(Debug, Clone)]
+pub struct DaemonOptions {
+    /// Seconds between checks
+    pub interval_secs: u64,
+    /// Shell command executed on each event (event data passed via HD_* env vars)
+    pub command: Option<String>,
+    /// Also report line changes within relevant gates
+    pub lines: bool,
+    /// Report every gate change, not only ones touching the natal chart
+    pub all: bool,
+    /// Show desktop notifications
+    pub notify: bool,
+    /// Engine, corrections and expert overrides for natal and transit positions
+    pub calc_opts: CalcOptions,
+}
+
+/// Transit change worth notifying about
+#[derive(Debug, Clone)]
+pub struct TransitEvent {
+    /// natal_gate / hanging_gate / gate / line
+    pub kind: &'static str,
+    pub planet: HdPlanet,
+    pub gate: u8,
+    pub line: u8,
+    pub message: String,
+}
+
+/// Gates relevant to a natal chart: its own gates and the partners of its hanging gates
+struct NatalGates {
+    active: HashSet<u8>,
+    /// partner gate → channel key it would complete
+    hanging_partners: Vec<(u8, String)>,
+}
+
+impl NatalGates {
+    fn from_jd(natal_jd: f64, calc_opts: &CalcOptions) -> Result<Self, String> {
+        let (pers_gates, des_gates) = calc::natal_activations(natal_jd, &HdPlanet::all(), calc_opts)?;
+        let active: HashSet<u8> = pers_gates
+            .iter()
+            .chain(des_gates.iter())
+            .map(|(_, gp)| gp.gate)
+            .collect();
+
+        let hanging_partners = channels::unique_channels(channels::all_channels())
+            .into_iter()
+            .filter_map(|ch| {
+                match (active.contains(&ch.gate_a), active.contains(&ch.gate_b)) {
+                    (true, false) => Some((ch.gate_b, ch.key())),
+                    (false, true) => Some((ch.gate_a, ch.key())),
+                    _ => None,
+                }
+            })
+            .collect();
+
+        Ok(Self {
+            active,
+            hanging_partners,
+        })
+    }
+
+    fn completes(&self, gate: u8) -> Vec<String> {
+        self.hanging_partners
+            .iter()
+            .filter(|(g, _)| *g == gate)
+            .map(|(_, key)| key.clone())
+            .collect()
+    }
+}
+
+/// Compare two transit snapshots and collect relevant changes
+fn detect_events(
+    prev: &Activations,
+    current: &Activations,
+    natal: &NatalGates,
+    opts: &DaemonOptions,
+) -> Vec<TransitEvent> {
+    let mut events = Vec::new();
+
+    for ((planet, before), (_, now)) in prev.iter().zip(current.iter()) {
+        let name = format!("{} {}", planet.symbol(), planet.name());
+        let completes = natal.completes(now.gate);
+        let relevant = natal.active.contains(&now.gate) || !completes.is_empty();
+
+        if before.gate != now.gate {
+            if !completes.is_empty() {
+                events.push(TransitEvent {
+                    kind: "hanging_gate",
+                    planet: *planet,
+                    gate: now.gate,
+                    line: now.line,
+                    message: rust_i18n::t!(
+                        "daemon.hanging_gate",
+                        planet = name,
+                        gate = now.gate,
+                        line = now.line,
+                        channels = completes.join(", ")
+                    )
+                    .to_string(),
+                });
+            } else if natal.active.contains(&now.gate) {
+                events.push(TransitEvent {
+                    kind: "natal_gate",
+                    planet: *planet,
+                    gate: now.gate,
+                    line: now.line,
+                    message: rust_i18n::t!(
+                        "daemon.natal_gate",
+                        planet = name,
+                        gate = now.gate,
+                        line = now.line
+                    )
+                    .to_string(),
+                });
+            } else if opts.all {
+                events.push(TransitEvent {
+                    kind: "gate",
+                    planet: *planet,
+                    gate: now.gate,
+                    line: now.line,
+                    message: rust_i18n::t!(
+                        "daemon.gate",
+                        planet = name,
+                        gate = now.gate,
+                        line = now.line
+                    )
+                    .to_string(),
+                });
+            }
+        } else if before.line != now.line && opts.lines && (relevant || opts.all) {
+            events.push(TransitEvent {
+                kind: "line",
+                planet: *planet,
+                gate: now.gate,
+                line: now.line,
+                message: rust_i18n::t!(
+                    "daemon.line",
+                    planet = name,
+                    gate = now.gate,
+                    line = now.line
+                )
+                .to_string(),
+            });
+        }
+    }
+
+    events
+}
+
+/// Show a desktop notification using the platform's standard tool
+fn desktop_notify(title: &str, body: &str) {
+    #[cfg(target_os = "linux")]
+    let result = Command::new("notify-send").args([title, body]).status();
+
+    #[cfg(target_os = "macos")]
+    let result = Command::new("osascript")
+        .args([
+            "-e",
+            &format!(
+                "display notification \"{}\" with title \"{}\"",
+                body.replace('"', "\\\""),
+                title.replace('"', "\\\"")
+            ),
+        ])
+        .status();
+
+    #[cfg(target_os = "windows")]
+    let result = Command::new("msg").args(["*", &format!("{}: {}", title, body)]).status();
+
+    #[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+    let result: std::io::Result<std::process::ExitStatus> =
+        Err(std::io::Error::from(std::io::ErrorKind::Unsupported));
+
+    if let Err(e) = result {
+        eprintln!(
+            "{}",
+            rust_i18n::t!("daemon.notify_error", error = e.to_string())
+        );
+    }
+}
+
+/// Run the user-configured command with event data in the environment
+fn run_command(command: &str, profile: &str, event: &TransitEvent) {
+    #[cfg(target_os = "windows")]
+    let mut cmd = {
+        let mut c = Command::new("cmd");
+        c.args(["/C", command]);
+        c
+    };
+    #[cfg(not(target_os = "windows"))]
+    let mut cmd = {
+        let mut c = Command::new("sh");
+        c.args(["-c", command]);
+        c
+    };
+
+    let result = cmd
+        .env("HD_PROFILE", profile)
+        .env("HD_EVENT", event.kind)
+        .env("HD_PLANET", event.planet.name())
+        .env("HD_GATE", event.gate.to_string())
+        .env("HD_LINE", event.line.to_string())
+        .env("HD_MESSAGE", &event.message)
+        .status();
+
+    if let Err(e) = result {
+        eprintln!(
+            "{}",
+            rust_i18n::t!("daemon.command_error", error = e.to_string())
+        );
+    }
+}
+
+/// Watch transits for a natal chart until the process is stopped
+pub fn run(profile: &str, natal_jd: f64, opts: &DaemonOptions) -> Result<(), String> {
+    let natal = NatalGates::from_jd(natal_jd, &opts.calc_opts)?;
+    let title = rust_i18n::t!("daemon.title", profile = profile).to_string();
+
+    println!(
+        "{}",
+        rust_i18n::t!(
+            "daemon.started",
+            profile = profile,
+            interval = opts.interval_secs
+        )
+    );
+
+    let mut prev = calc::gate_activations(astro_calc::now_julian_day(), &HdPlanet::all(), &opts.calc_opts);
+    loop {
+        thread::sleep(Duration::from_secs(opts.interval_secs.max(1)));
+
+        let now_jd = astro_calc::now_julian_day();
+        let current = calc::gate_activations(now_jd, &HdPlanet::all(), &opts.calc_opts);
+        let moment = astro_calc::jd_to_utc(now_jd).format("%Y-%m-%d %H:%M");
+
+        for event in detect_events(&prev, &current, &natal, opts) {
+            println!("[{} UTC] {}", moment, event.message);
+            if opts.notify {
+                desktop_notify(&title, &event.message);
+            }
+            if let Some(ref command) = opts.command {
+                run_command(command, profile, &event);
+            }
+        }
+
+        prev = current;
+    }
+}
@@ -0,0 +1,34 @@
+//! Awareness streams: the flows of the three awareness centers (Spleen, Ajna,
+//! Solar Plexus), one per circuit group. Each stream is a chain of channels
+//! written from the pressure/source gate to the expressing gate; stream keys
+//! map to `cli.awareness.stream.*` and to the `awareness_streams` DB block.
+
+use super::centers::Center;
+
+/// One awareness stream and the channels it flows through
+#[derive(Debug, Clone, Copy)]
+pub struct AwarenessStreamDef {
+    pub center: Center,
+    pub key: &'static str,
+    pub channels: &'static [(u8, u8)],
+}
+
+impl AwarenessStreamDef {
+    /// Every gate of the stream in flow order
+    pub fn gates(&self) -> Vec<u8> {
+        self.channels.iter().flat_map(|&(a, b)| [a, b]).collect()
+    }
+}
+
+/// All nine streams: Spleen, then Ajna, then Solar Plexus
+pub const AWARENESS_STREAMS: [AwarenessStreamDef; 9] = [
+    AwarenessStreamDef { center: Center::Spleen, key: "taste", channels: &[(58, 18), (48, 16)] },
+    AwarenessStreamDef { center: Center::Spleen, key: "intuition", channels: &[(38, 28), (57, 20)] },
+    AwarenessStreamDef { center: Center::Spleen, key: "instinct", channels: &[(54, 32), (50, 27), (44, 26)] },
+    AwarenessStreamDef { center: Center::Ajna, key: "understanding", channels: &[(63, 4), (17, 62)] },
+    AwarenessStreamDef { center: Center::Ajna, key: "sensing", channels: &[(64, 47), (11, 56)] },
+    AwarenessStreamDef { center: Center::Ajna, key: "knowing", channels: &[(61, 24), (43, 23)] },
+    AwarenessStreamDef { center: Center::SolarPlexus, key: "sensitivity", channels: &[(19, 49), (6, 59), (37, 40)] },
+    AwarenessStreamDef { center: Center::SolarPlexus, key: "emotion", channels: &[(39, 55), (22, 12)] },
+    AwarenessStreamDef { center: Center::SolarPlexus, key: "feeling", channels: &[(41, 30), (36, 35)] },
+];
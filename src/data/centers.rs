@@ -1,5 +1,6 @@
-/// 9 Human Design Centers
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// 9 Human Design Centers (serialized with the same keys as `key()`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Center {
     Head,
     Ajna,
@@ -8,6 +9,7 @@ pub enum Center {
     Heart,
     Sacral,
     SolarPlexus,
+    #[serde(rename = "splenic")]
     Spleen,
     Root,
 }
@@ -50,7 +52,6 @@ impl Center {
 }
 
 /// Gates belonging to each center
-#[allow(dead_code)]
 pub fn gates_for_center(center: &Center) -> Vec<u8> {
     match center {
         Center::Head => vec![64, 61, 63],
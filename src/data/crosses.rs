@@ -0,0 +1,173 @@
+//! Incarnation cross catalog: a cross is identified by its angle and its four gates
+//! (Personality Sun / Earth | Design Sun / Earth). The canonical cross of a Sun gate
+//! puts the Earth opposite it and the Design Sun a quarter of the wheel back: 16 gates
+//! for the Right Angle (early Sun lines), 15 for the Left Angle and Juxtaposition
+//! (late Sun lines). The actual Design gate of a chart may be the neighbouring one,
+//! since the Design arc is 88° rather than 90°. Theme names map to
+//! `cross.<angle>.<theme>` and the full name to `cross.<angle>.fmt`.
+
+use super::gates::GATE_ORDER;
+
+/// Cross themes by Personality Sun gate: (gate, Right Angle, Juxtaposition, Left Angle).
+/// A Right Angle theme is shared by the four gates of its cross, a Left Angle theme
+/// by the Sun and Earth gates.
+const THEMES: [(u8, &str, &str, &str); 64] = [
+    (1, "sphinx", "self_expression", "defiance"),
+    (2, "sphinx", "driver", "defiance"),
+    (3, "laws", "mutation", "wishes"),
+    (4, "explanation", "formulization", "revolution"),
+    (5, "consciousness", "habits", "separation"),
+    (6, "eden", "conflict", "plane"),
+    (7, "sphinx", "interaction", "masks"),
+    (8, "contagion", "contribution", "uncertainty"),
+    (9, "planning", "focus", "identification"),
+    (10, "vessel_of_love", "behavior", "prevention"),
+    (11, "eden", "ideas", "education"),
+    (12, "eden", "articulation", "education"),
+    (13, "sphinx", "listening", "masks"),
+    (14, "contagion", "empowering", "uncertainty"),
+    (15, "vessel_of_love", "extremes", "prevention"),
+    (16, "planning", "experimentation", "identification"),
+    (17, "service", "opinions", "upheaval"),
+    (18, "service", "correction", "upheaval"),
+    (19, "four_ways", "need", "refinement"),
+    (20, "sleeping_phoenix", "now", "duality"),
+    (21, "tension", "control", "endeavour"),
+    (22, "rulership", "grace", "informing"),
+    (23, "explanation", "assimilation", "dedication"),
+    (24, "four_ways", "rationalization", "incarnation"),
+    (25, "vessel_of_love", "innocence", "healing"),
+    (26, "rulership", "trickster", "confrontation"),
+    (27, "unexpected", "caring", "alignment"),
+    (28, "unexpected", "risks", "alignment"),
+    (29, "contagion", "commitment", "industry"),
+    (30, "contagion", "fates", "industry"),
+    (31, "unexpected", "influence", "alpha"),
+    (32, "maya", "conservation", "limitation"),
+    (33, "four_ways", "retreat", "refinement"),
+    (34, "sleeping_phoenix", "power", "duality"),
+    (35, "consciousness", "experience", "separation"),
+    (36, "eden", "crisis", "plane"),
+    (37, "planning", "bargains", "migration"),
+    (38, "tension", "opposition", "individualism"),
+    (39, "tension", "provocation", "individualism"),
+    (40, "planning", "denial", "migration"),
+    (41, "unexpected", "fantasy", "alpha"),
+    (42, "maya", "completion", "limitation"),
+    (43, "explanation", "insight", "dedication"),
+    (44, "four_ways", "alertness", "incarnation"),
+    (45, "rulership", "possession", "confrontation"),
+    (46, "vessel_of_love", "serendipity", "healing"),
+    (47, "rulership", "oppression", "informing"),
+    (48, "tension", "depth", "endeavour"),
+    (49, "explanation", "principles", "revolution"),
+    (50, "laws", "values", "wishes"),
+    (51, "penetration", "shock", "clarion"),
+    (52, "service", "stillness", "demands"),
+    (53, "penetration", "beginnings", "cycles"),
+    (54, "penetration", "ambition", "cycles"),
+    (55, "sleeping_phoenix", "moods", "spirit"),
+    (56, "laws", "stimulation", "distraction"),
+    (57, "penetration", "intuition", "clarion"),
+    (58, "service", "vitality", "demands"),
+    (59, "sleeping_phoenix", "strategy", "spirit"),
+    (60, "laws", "limitation", "distraction"),
+    (61, "maya", "thinking", "obscuration"),
+    (62, "maya", "detail", "obscuration"),
+    (63, "consciousness", "doubts", "dominion"),
+    (64, "consciousness", "confusion", "dominion"),
+];
+
+/// One cross of the catalog
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CatalogCross {
+    pub angle: &'static str,
+    pub theme: &'static str,
+    /// Personality Sun / Earth, Design Sun / Earth
+    pub gates: [u8; 4],
+}
+
+impl CatalogCross {
+    pub fn key(&self) -> String {
+        catalog_key(self.angle, self.gates)
+    }
+
+    /// Localized name, e.g. "Right Angle Cross of the Sphinx"
+    pub fn name(&self) -> String {
+        let theme_key = format!("cross.{}.{}", self.angle, self.theme);
+        let fmt_key = format!("cross.{}.fmt", self.angle);
+        rust_i18n::t!(&fmt_key, theme = rust_i18n::t!(&theme_key)).to_string()
+    }
+}
+
+/// The cross of a Personality Sun gate at `angle`
+pub fn lookup(angle: &str, sun_gate: u8) -> Option<CatalogCross> {
+    let &(_, right, juxta, left) = THEMES.iter().find(|t| t.0 == sun_gate)?;
+    let (angle, theme) = match angle {
+        "right_angle" => ("right_angle", right),
+        "juxtaposition" => ("juxtaposition", juxta),
+        "left_angle" => ("left_angle", left),
+        _ => return None,
+    };
+    Some(CatalogCross {
+        angle,
+        theme,
+        gates: canonical_gates(angle, sun_gate)?,
+    })
+}
+
+/// Canonical four gates of the cross of a Personality Sun gate at `angle`
+/// ("right_angle", "left_angle" or "juxtaposition")
+pub fn canonical_gates(angle: &str, sun_gate: u8) -> Option<[u8; 4]> {
+    let index = GATE_ORDER.iter().position(|&g| g == sun_gate)?;
+    let at = |offset: usize| GATE_ORDER[(index + offset) % GATE_ORDER.len()];
+    let design = if angle == "right_angle" { 48 } else { 49 };
+    Some([sun_gate, at(32), at(design), at(design + 32)])
+}
+
+/// Catalog key of a cross, e.g. "right_angle_13_7_1_2"
+pub fn catalog_key(angle: &str, gates: [u8; 4]) -> String {
+    format!(
+        "{}_{}_{}_{}_{}",
+        angle, gates[0], gates[1], gates[2], gates[3]
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crosses_sharing_a_gate_resolve_apart() {
+        // Gate 13 is the Personality Sun of three crosses
+        let sphinx = lookup("right_angle", 13).unwrap();
+        let listening = lookup("juxtaposition", 13).unwrap();
+        let masks = lookup("left_angle", 13).unwrap();
+        assert_eq!(sphinx.gates, [13, 7, 1, 2]);
+        assert_eq!(sphinx.theme, "sphinx");
+        assert_eq!(listening.gates, [13, 7, 43, 23]);
+        assert_eq!(listening.theme, "listening");
+        assert_eq!(masks.gates, [13, 7, 43, 23]);
+        assert_eq!(masks.theme, "masks");
+        assert_eq!(sphinx.key(), "right_angle_13_7_1_2");
+        assert_ne!(listening.key(), masks.key());
+
+        // The Sphinx quarter with Sun 7 shares all four gates but is another cross
+        let sphinx_7 = lookup("right_angle", 7).unwrap();
+        assert_eq!(sphinx_7.gates, [7, 13, 2, 1]);
+        assert_eq!(sphinx_7.theme, sphinx.theme);
+        assert_ne!(sphinx_7.key(), sphinx.key());
+    }
+
+    #[test]
+    fn themes_follow_the_wheel() {
+        for gate in 1..=64 {
+            let right = lookup("right_angle", gate).unwrap();
+            for g in right.gates {
+                assert_eq!(lookup("right_angle", g).unwrap().theme, right.theme, "gate {}", g);
+            }
+            let left = lookup("left_angle", gate).unwrap();
+            assert_eq!(lookup("left_angle", left.gates[1]).unwrap().theme, left.theme, "gate {}", gate);
+        }
+    }
+}
@@ -1,12 +1,20 @@
 use serde::Deserialize;
 use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+use std::time::SystemTime;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 /// Gate structure
 #[derive(Debug, Deserialize, Clone)]
 pub struct GateData {
     pub name: String,
     pub description: String,
-    pub lines: HashMap<String, String>,
+    /// Short keynote for compact output
+    #[serde(default)]
+    pub keynote: Option<String>,
+    pub lines: HashMap<String, LineData>,
     #[serde(default)]
     pub crosses: Vec<String>,
     #[serde(default)]
@@ -28,6 +36,88 @@ pub struct GateData {
     pub sub_circuit: Option<String>,
 }
 
+/// Gate line: its text and the planets fixing it (by planet key, e.g. `"Sun"`, `"NorthNode"`).
+/// Databases may still give a line as a plain string
+#[derive(Debug, Deserialize, Clone)]
+#[serde(from = "LineEntry")]
+pub struct LineData {
+    pub description: String,
+    pub exaltation: Vec<String>,
+    pub detriment: Vec<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum LineEntry {
+    Text(String),
+    Full {
+        description: String,
+        #[serde(default)]
+        exaltation: Vec<String>,
+        #[serde(default)]
+        detriment: Vec<String>,
+    },
+}
+
+impl From<LineEntry> for LineData {
+    fn from(entry: LineEntry) -> Self {
+        match entry {
+            LineEntry::Text(description) => LineData {
+                description,
+                exaltation: Vec::new(),
+                detriment: Vec::new(),
+            },
+            LineEntry::Full {
+                description,
+                exaltation,
+                detriment,
+            } => LineData {
+                description,
+                exaltation,
+                detriment,
+            },
+        }
+    }
+}
+
+/// Longest keynote fallback taken from the description, in terminal columns
+const KEYNOTE_FALLBACK_WIDTH: usize = 60;
+
+impl GateData {
+    /// Keynote from the DB, or the first sentence of the description (shortened)
+    pub fn keynote_or_summary(&self) -> String {
+        if let Some(ref k) = self.keynote {
+            return k.clone();
+        }
+        // CJK punctuation ends a sentence without a following space
+        let end = self
+            .description
+            .char_indices()
+            .find_map(|(i, c)| match c {
+                '.' | '!' | '?' if self.description[i + 1..].starts_with(' ') => Some(i + 1),
+                '。' | '！' | '？' => Some(i + c.len_utf8()),
+                _ => None,
+            })
+            .unwrap_or(self.description.len());
+        let sentence = self.description[..end].trim();
+        if sentence.width() > KEYNOTE_FALLBACK_WIDTH {
+            let mut cut = String::new();
+            let mut cut_width = 0;
+            for c in sentence.chars() {
+                let w = c.width().unwrap_or(0);
+                if cut_width + w > KEYNOTE_FALLBACK_WIDTH - 1 {
+                    break;
+                }
+                cut.push(c);
+                cut_width += w;
+            }
+            format!("{}…", cut.trim_end())
+        } else {
+            sentence.to_string()
+        }
+    }
+}
+
 /// Channel structure
 #[derive(Debug, Deserialize, Clone)]
 pub struct ChannelData {
@@ -72,6 +162,12 @@ pub struct CenterData {
     pub description: String,
     pub normal: String,
     pub distorted: String,
+    /// Question the open center keeps asking when conditioned (e.g. "Am I still trying to prove my worth?")
+    #[serde(default)]
+    pub not_self_question: Option<String>,
+    /// What an open center can learn to see clearly in others
+    #[serde(default)]
+    pub wisdom: Option<String>,
 }
 
 /// PHS Block (Colors/Tones)
@@ -83,6 +179,25 @@ pub struct PhsBlock {
     pub tones: HashMap<String, String>,
 }
 
+/// General meanings of the substructure levels, keyed by value ("1".."6", bases "1".."5")
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct SubstructureBlock {
+    #[serde(default)]
+    pub colors: HashMap<String, MetaObject>,
+    #[serde(default)]
+    pub tones: HashMap<String, MetaObject>,
+    #[serde(default)]
+    pub bases: HashMap<String, MetaObject>,
+}
+
+/// Gene Keys frequency names of a gate
+#[derive(Debug, Deserialize, Clone)]
+pub struct GeneKeyData {
+    pub shadow: String,
+    pub gift: String,
+    pub siddhi: String,
+}
+
 /// Main database structure
 #[derive(Debug, Deserialize)]
 pub struct HdDatabase {
@@ -91,8 +206,16 @@ pub struct HdDatabase {
     pub centers: HashMap<String, CenterData>,
     pub types: HashMap<String, MetaObject>,
     pub profiles: HashMap<String, MetaObject>,
+    /// Keynotes of individual profile lines ("1".."6")
+    #[serde(default)]
+    pub profile_lines: HashMap<String, MetaObject>,
     #[serde(default)]
     pub strategies: HashMap<String, String>,
+    /// Signature and Not-Self theme descriptions by type key
+    #[serde(default)]
+    pub signatures: HashMap<String, String>,
+    #[serde(default)]
+    pub not_self_themes: HashMap<String, String>,
     pub authorities: HashMap<String, MetaObject>,
     #[serde(default)]
     pub fears: HashMap<String, String>,
@@ -104,34 +227,123 @@ pub struct HdDatabase {
     pub diet: Option<PhsBlock>,
     #[serde(default)]
     pub vision: Option<PhsBlock>,
+    /// Cognition (sense) by Design Sun tone ("1".."6")
+    #[serde(default)]
+    pub cognition: HashMap<String, MetaObject>,
     #[serde(default)]
     pub crosses: HashMap<String, MetaObject>,
     #[serde(default)]
     pub circuits: HashMap<String, CircuitMeta>,
+    /// Gene Keys Shadow/Gift/Siddhi by gate ("1".."64")
+    #[serde(default)]
+    pub gene_keys: HashMap<String, GeneKeyData>,
+    /// Awareness stream names and descriptions by stream key ("taste", "emotion", ...)
+    #[serde(default)]
+    pub awareness_streams: HashMap<String, MetaObject>,
+    /// Color/tone/base meanings for the advanced depth section
+    #[serde(default)]
+    pub substructure: SubstructureBlock,
 }
 
 use once_cell::sync::Lazy;
 
-// Embed all three databases
+// Embed all databases; optional ones are empty when not available at build time
 const DB_JSON_RU: &str = include_str!("../../data/gates_database_ru.json");
 const DB_JSON_EN: &str = include_str!("../../data/gates_database_en.json");
 const DB_JSON_ES: &str = include_str!("../../data/gates_database_es.json");
+const DB_JSON_ZH: &str = include_str!(concat!(env!("OUT_DIR"), "/gates_database_zh.json"));
 
-static DB_RU: Lazy<HdDatabase> = Lazy::new(|| {
-    serde_json::from_str(DB_JSON_RU).expect("Failed to parse embedded gates_database_ru.json")
-});
-static DB_EN: Lazy<HdDatabase> = Lazy::new(|| {
-    serde_json::from_str(DB_JSON_EN).expect("Failed to parse embedded gates_database_en.json")
-});
-static DB_ES: Lazy<HdDatabase> = Lazy::new(|| {
-    serde_json::from_str(DB_JSON_ES).expect("Failed to parse embedded gates_database_es.json")
-});
+/// Loaded databases by language. A reload swaps in a whole new `Arc`, so readers
+/// keep a consistent snapshot for as long as they hold theirs.
+static REGISTRY: Lazy<RwLock<HashMap<String, Arc<HdDatabase>>>> = Lazy::new(Default::default);
 
-/// Get database by language code
-pub fn get_database(lang: &str) -> &'static HdDatabase {
+/// Registry key for a language code (unknown codes use Russian, the default)
+fn registry_key(lang: &str) -> &'static str {
     match lang {
-        "en" => &DB_EN,
-        "es" => &DB_ES,
-        _ => &DB_RU,
+        "en" => "en",
+        "es" => "es",
+        "zh" => "zh",
+        _ => "ru",
+    }
+}
+
+fn embedded_json(key: &str) -> Option<&'static str> {
+    match key {
+        "en" => Some(DB_JSON_EN),
+        "es" => Some(DB_JSON_ES),
+        "zh" if !DB_JSON_ZH.trim().is_empty() => Some(DB_JSON_ZH),
+        "ru" => Some(DB_JSON_RU),
+        _ => None,
+    }
+}
+
+/// Get database by language code
+pub fn get_database(lang: &str) -> Arc<HdDatabase> {
+    let key = registry_key(lang);
+    if let Some(db) = REGISTRY.read().unwrap().get(key) {
+        return db.clone();
     }
+    let Some(json) = embedded_json(key) else {
+        // Chinese descriptions fall back to English until the database is published
+        return get_database("en");
+    };
+    let db: HdDatabase = serde_json::from_str(json)
+        .unwrap_or_else(|e| panic!("Failed to parse embedded gates_database_{}.json: {}", key, e));
+    REGISTRY
+        .write()
+        .unwrap()
+        .entry(key.to_string())
+        .or_insert_with(|| Arc::new(db))
+        .clone()
+}
+
+/// Replace the database for `lang` with the contents of `path`.
+/// The current database stays in place if the file can't be read or parsed.
+pub fn reload_from_file(lang: &str, path: &Path) -> Result<(), String> {
+    let content = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let db: HdDatabase =
+        serde_json::from_str(&content).map_err(|e| format!("{}: {}", path.display(), e))?;
+    REGISTRY
+        .write()
+        .unwrap()
+        .insert(registry_key(lang).to_string(), Arc::new(db));
+    Ok(())
+}
+
+/// Polls external database files and reloads the ones that changed on disk,
+/// for long-running processes that shouldn't need a restart on content updates
+#[derive(Debug, Default)]
+pub struct DatabaseWatcher {
+    files: Vec<(String, PathBuf, Option<SystemTime>)>,
+}
+
+impl DatabaseWatcher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load `path` as the database for `lang` now and reload it whenever it changes
+    pub fn watch(&mut self, lang: &str, path: PathBuf) -> Result<(), String> {
+        reload_from_file(lang, &path)?;
+        let modified = modified_time(&path);
+        self.files.push((lang.to_string(), path, modified));
+        Ok(())
+    }
+
+    /// Reload changed files; returns the languages that were checked with their result
+    pub fn poll(&mut self) -> Vec<(String, Result<(), String>)> {
+        let mut results = Vec::new();
+        for (lang, path, last) in &mut self.files {
+            let modified = modified_time(path);
+            if modified.is_some() && modified != *last {
+                *last = modified;
+                results.push((lang.clone(), reload_from_file(lang, path)));
+            }
+        }
+        results
+    }
+}
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
 }
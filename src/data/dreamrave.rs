@@ -0,0 +1,27 @@
+//! Dream Rave: the sleep bodygraph. The wheel is divided into 15 dream gates of
+//! 24° each, counted from the HD wheel start (Gate 41 at 302°); every three
+//! consecutive dream gates belong to one of the 5 dream centers. Center keys
+//! map to `cli.dreamrave.center.*`.
+
+use super::gates::WHEEL_START_DEGREE;
+
+/// Size of one dream gate in degrees
+pub const DREAM_GATE_SIZE_DEG: f64 = 24.0;
+
+/// Dream centers in wheel order, three dream gates each (1–3, 4–6, ...)
+pub const DREAM_CENTERS: [&str; 5] = ["mind", "vision", "voice", "self", "life"];
+
+/// Dream channels: the neighboring gates of two adjacent centers
+pub const DREAM_CHANNELS: [(u8, u8); 5] = [(3, 4), (6, 7), (9, 10), (12, 13), (15, 1)];
+
+/// Dream gate (1..15) of an ecliptic degree, with the wheel start shifted by
+/// `wheel_shift` degrees as for `gates::degree_to_gate`
+pub fn dream_gate(ecliptic_deg: f64, wheel_shift: f64) -> u8 {
+    let offset = (ecliptic_deg - WHEEL_START_DEGREE - wheel_shift).rem_euclid(360.0);
+    ((offset / DREAM_GATE_SIZE_DEG).floor() as u8).min(14) + 1
+}
+
+/// Dream center key of a dream gate
+pub fn dream_center(gate: u8) -> &'static str {
+    DREAM_CENTERS[((gate.clamp(1, 15) - 1) / 3) as usize]
+}
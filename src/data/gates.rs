@@ -40,8 +40,10 @@ pub struct GatePosition {
     pub degree: f64,
 }
 
-/// Convert ecliptic degree to gate/line/color/tone/base
-pub fn degree_to_gate(ecliptic_deg: f64) -> GatePosition {
+/// Convert ecliptic degree to gate/line/color/tone/base, with the wheel start shifted
+/// by `wheel_offset` degrees (0 for the standard wheel; research use, to compare
+/// against sources that anchor the mandala slightly differently)
+pub fn degree_to_gate(ecliptic_deg: f64, wheel_offset: f64) -> GatePosition {
     // Normalize degree to 0..360
     let mut deg = ecliptic_deg % 360.0;
     if deg < 0.0 {
@@ -49,10 +51,7 @@ pub fn degree_to_gate(ecliptic_deg: f64) -> GatePosition {
     }
 
     // Offset from wheel start
-    let mut offset = deg - WHEEL_START_DEGREE;
-    if offset < 0.0 {
-        offset += 360.0;
-    }
+    let offset = (deg - WHEEL_START_DEGREE - wheel_offset).rem_euclid(360.0);
 
     // Gate index (0..63)
     let gate_index = (offset / GATE_SIZE_DEG).floor() as usize;
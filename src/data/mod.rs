@@ -2,3 +2,8 @@ pub mod database;
 pub mod gates;
 pub mod channels;
 pub mod centers;
+pub mod timezones;
+pub mod penta;
+pub mod dreamrave;
+pub mod crosses;
+pub mod awareness;
@@ -0,0 +1,35 @@
+//! Penta: the six channels between the G center and the Throat (upper Penta)
+//! and between the Sacral and the G center (lower Penta) that a group of
+//! 3–5 people forms into a shared aura. Role keys map to `cli.penta.role.*`.
+
+/// One Penta channel with the functional role of each gate
+#[derive(Debug, Clone, Copy)]
+pub struct PentaChannelDef {
+    /// "upper" (G–Throat, vision and direction) or "lower" (Sacral–G, productivity)
+    pub part: &'static str,
+    pub role: &'static str,
+    pub gate_a: u8,
+    pub role_a: &'static str,
+    pub gate_b: u8,
+    pub role_b: &'static str,
+}
+
+impl PentaChannelDef {
+    pub fn key(&self) -> String {
+        format!("{}-{}", self.gate_a, self.gate_b)
+    }
+}
+
+/// All six Penta channels, upper Penta first
+pub const PENTA_CHANNELS: [PentaChannelDef; 6] = [
+    PentaChannelDef { part: "upper", role: "leadership", gate_a: 31, role_a: "influence", gate_b: 7, role_b: "direction" },
+    PentaChannelDef { part: "upper", role: "contribution", gate_a: 8, role_a: "marketing", gate_b: 1, role_b: "style" },
+    PentaChannelDef { part: "upper", role: "memory", gate_a: 33, role_a: "retelling", gate_b: 13, role_b: "listening" },
+    PentaChannelDef { part: "lower", role: "rhythm", gate_a: 15, role_a: "flow", gate_b: 5, role_b: "fixed_rhythms" },
+    PentaChannelDef { part: "lower", role: "resources", gate_a: 2, role_a: "resource_direction", gate_b: 14, role_b: "power_skills" },
+    PentaChannelDef { part: "lower", role: "commitment", gate_a: 46, role_a: "embodiment", gate_b: 29, role_b: "perseverance" },
+];
+
+/// Smallest and largest group a Penta forms in
+pub const MIN_MEMBERS: usize = 3;
+pub const MAX_MEMBERS: usize = 5;
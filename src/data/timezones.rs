@@ -0,0 +1,117 @@
+/// Common time zone abbreviations as people write them down for a birth time
+/// (abbreviation, UTC offset in hours, other zones sharing the abbreviation)
+const ABBREVIATIONS: &[(&str, f64, &str)] = &[
+    ("UTC", 0.0, ""),
+    ("GMT", 0.0, ""),
+    ("WET", 0.0, ""),
+    ("WEST", 1.0, ""),
+    ("BST", 1.0, "Bangladesh +6"),
+    ("IST", 5.5, "Irish +1, Israel +2"),
+    ("CET", 1.0, ""),
+    ("CEST", 2.0, ""),
+    ("EET", 2.0, ""),
+    ("EEST", 3.0, ""),
+    ("MSK", 3.0, ""),
+    ("MSD", 4.0, ""),
+    ("SAMT", 4.0, ""),
+    ("YEKT", 5.0, ""),
+    ("OMST", 6.0, ""),
+    ("KRAT", 7.0, ""),
+    ("IRKT", 8.0, ""),
+    ("YAKT", 9.0, ""),
+    ("VLAT", 10.0, ""),
+    ("MAGT", 11.0, ""),
+    ("PETT", 12.0, ""),
+    ("TRT", 3.0, ""),
+    ("IRST", 3.5, ""),
+    ("GST", 4.0, "South Georgia -2"),
+    ("PKT", 5.0, ""),
+    ("NPT", 5.75, ""),
+    ("ICT", 7.0, ""),
+    ("WIB", 7.0, ""),
+    ("HKT", 8.0, ""),
+    ("SGT", 8.0, ""),
+    ("PHT", 8.0, ""),
+    ("AWST", 8.0, ""),
+    ("JST", 9.0, ""),
+    ("KST", 9.0, ""),
+    ("ACST", 9.5, ""),
+    ("ACDT", 10.5, ""),
+    ("AEST", 10.0, ""),
+    ("AEDT", 11.0, ""),
+    ("NZST", 12.0, ""),
+    ("NZDT", 13.0, ""),
+    ("WAT", 1.0, ""),
+    ("CAT", 2.0, ""),
+    ("SAST", 2.0, ""),
+    ("EAT", 3.0, ""),
+    ("BRT", -3.0, ""),
+    ("ART", -3.0, ""),
+    ("NST", -3.5, ""),
+    ("NDT", -2.5, ""),
+    ("AST", -4.0, "Arabia +3"),
+    ("ADT", -3.0, ""),
+    ("EST", -5.0, ""),
+    ("EDT", -4.0, ""),
+    ("CST", -6.0, "China +8, Cuba -5"),
+    ("CDT", -5.0, ""),
+    ("MST", -7.0, ""),
+    ("MDT", -6.0, ""),
+    ("PST", -8.0, ""),
+    ("PDT", -7.0, ""),
+    ("AKST", -9.0, ""),
+    ("AKDT", -8.0, ""),
+    ("HST", -10.0, ""),
+];
+
+/// Offset of a known abbreviation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TzAbbreviation {
+    pub offset: f64,
+    /// Other zones written the same way, empty if unambiguous
+    pub alternatives: &'static str,
+}
+
+/// UTC offset of a time zone abbreviation (case-insensitive) on the given date;
+/// `None` for unknown abbreviations
+pub fn abbreviation_offset(abbr: &str, year: i32, month: u8, day: u8) -> Option<TzAbbreviation> {
+    let abbr = abbr.to_ascii_uppercase();
+    let &(_, offset, alternatives) = ABBREVIATIONS.iter().find(|(a, _, _)| *a == abbr)?;
+
+    // Moscow time was UTC+4 all year between the 2011 and 2014 reforms
+    let date = (year, month, day);
+    let offset = if abbr == "MSK" && ((2011, 3, 27)..(2014, 10, 26)).contains(&date) {
+        4.0
+    } else {
+        offset
+    };
+
+    Some(TzAbbreviation {
+        offset,
+        alternatives,
+    })
+}
+
+/// UTC offset of an IANA zone at a local time
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ZoneOffset {
+    Single(f64),
+    /// The local time occurs twice (DST ends); earlier and later offsets
+    Ambiguous(f64, f64),
+    /// The local time is skipped (DST starts)
+    Nonexistent,
+}
+
+/// UTC offset in hours of an IANA zone (e.g. `Europe/Moscow`) at a
+/// local date and time, from the zone's full history including DST;
+/// `None` for unknown zones
+pub fn zone_offset(name: &str, local: chrono::NaiveDateTime) -> Option<ZoneOffset> {
+    use chrono::{LocalResult, Offset, TimeZone};
+    let zone: chrono_tz::Tz = name.parse().ok()?;
+    let hours = |offset: &<chrono_tz::Tz as TimeZone>::Offset| offset.fix().local_minus_utc() as f64 / 3600.0;
+    Some(match zone.offset_from_local_datetime(&local) {
+        LocalResult::Single(offset) => ZoneOffset::Single(hours(&offset)),
+        LocalResult::Ambiguous(earlier, later) => ZoneOffset::Ambiguous(hours(&earlier), hours(&later)),
+        LocalResult::None => ZoneOffset::Nonexistent,
+    })
+}
@@ -0,0 +1,184 @@
+//! Offline place lookup for `--place`: a bundled list of major cities with their
+//! IANA time zone and coordinates (`cities.txt`), matched by name or alias and
+//! optionally narrowed by country ("Berlin, Germany", "Valencia, ES").
+
+use once_cell::sync::Lazy;
+
+/// A bundled city
+#[derive(Debug, Clone, PartialEq)]
+pub struct Place {
+    pub name: &'static str,
+    /// Other spellings and local-language names
+    pub aliases: Vec<&'static str>,
+    /// ISO 3166-1 alpha-2 country code
+    pub country: &'static str,
+    /// IANA time zone
+    pub tz: &'static str,
+    pub lat: f64,
+    pub lon: f64,
+}
+
+impl Place {
+    /// "Berlin, DE"
+    pub fn label(&self) -> String {
+        format!("{}, {}", self.name, self.country)
+    }
+
+    fn is_named(&self, name: &str) -> bool {
+        self.name.to_lowercase() == name || self.aliases.iter().any(|a| a.to_lowercase() == name)
+    }
+}
+
+/// Country names accepted after the comma, besides the ISO code itself
+const COUNTRIES: &[(&str, &[&str])] = &[
+    ("RU", &["Russia", "Russian Federation", "Россия", "Rusia"]),
+    ("UA", &["Ukraine", "Украина", "Україна", "Ucrania"]),
+    ("BY", &["Belarus", "Беларусь", "Bielorrusia"]),
+    ("KZ", &["Kazakhstan", "Казахстан", "Kazajistán"]),
+    ("UZ", &["Uzbekistan", "Узбекистан"]),
+    ("KG", &["Kyrgyzstan", "Кыргызстан", "Киргизия"]),
+    ("GE", &["Georgia", "Грузия"]),
+    ("AM", &["Armenia", "Армения"]),
+    ("AZ", &["Azerbaijan", "Азербайджан"]),
+    ("MD", &["Moldova", "Молдова", "Молдавия"]),
+    ("LV", &["Latvia", "Латвия", "Letonia"]),
+    ("LT", &["Lithuania", "Литва", "Lituania"]),
+    ("EE", &["Estonia", "Эстония"]),
+    ("GB", &["United Kingdom", "UK", "Great Britain", "Britain", "England", "Scotland", "Великобритания", "Англия", "Reino Unido"]),
+    ("IE", &["Ireland", "Ирландия", "Irlanda"]),
+    ("FR", &["France", "Франция", "Francia"]),
+    ("DE", &["Germany", "Deutschland", "Германия", "Alemania"]),
+    ("AT", &["Austria", "Österreich", "Австрия"]),
+    ("CH", &["Switzerland", "Schweiz", "Suisse", "Швейцария", "Suiza"]),
+    ("NL", &["Netherlands", "Holland", "Нидерланды", "Голландия", "Países Bajos"]),
+    ("BE", &["Belgium", "Бельгия", "Bélgica"]),
+    ("ES", &["Spain", "España", "Испания"]),
+    ("PT", &["Portugal", "Португалия"]),
+    ("IT", &["Italy", "Italia", "Италия"]),
+    ("GR", &["Greece", "Греция", "Grecia"]),
+    ("TR", &["Turkey", "Türkiye", "Турция", "Turquía"]),
+    ("PL", &["Poland", "Polska", "Польша", "Polonia"]),
+    ("CZ", &["Czech Republic", "Czechia", "Чехия", "Chequia"]),
+    ("HU", &["Hungary", "Венгрия", "Hungría"]),
+    ("RO", &["Romania", "Румыния", "Rumania"]),
+    ("BG", &["Bulgaria", "Болгария"]),
+    ("RS", &["Serbia", "Сербия"]),
+    ("HR", &["Croatia", "Хорватия", "Croacia"]),
+    ("SI", &["Slovenia", "Словения", "Eslovenia"]),
+    ("SK", &["Slovakia", "Словакия", "Eslovaquia"]),
+    ("DK", &["Denmark", "Дания", "Dinamarca"]),
+    ("SE", &["Sweden", "Швеция", "Suecia"]),
+    ("NO", &["Norway", "Норвегия", "Noruega"]),
+    ("FI", &["Finland", "Финляндия", "Finlandia"]),
+    ("IS", &["Iceland", "Исландия", "Islandia"]),
+    ("US", &["United States", "USA", "America", "США", "Estados Unidos", "美国"]),
+    ("CA", &["Canada", "Канада", "Canadá"]),
+    ("MX", &["Mexico", "México", "Мексика"]),
+    ("CU", &["Cuba", "Куба"]),
+    ("CO", &["Colombia", "Колумбия"]),
+    ("PE", &["Peru", "Perú", "Перу"]),
+    ("CL", &["Chile", "Чили"]),
+    ("AR", &["Argentina", "Аргентина"]),
+    ("BR", &["Brazil", "Brasil", "Бразилия"]),
+    ("VE", &["Venezuela", "Венесуэла"]),
+    ("UY", &["Uruguay", "Уругвай"]),
+    ("JP", &["Japan", "Япония", "Japón", "日本"]),
+    ("KR", &["South Korea", "Korea", "Южная Корея", "Корея", "Corea del Sur", "韩国"]),
+    ("CN", &["China", "Китай", "中国"]),
+    ("HK", &["Hong Kong", "Гонконг", "香港"]),
+    ("TW", &["Taiwan", "Тайвань", "台湾"]),
+    ("SG", &["Singapore", "Сингапур", "Singapur", "新加坡"]),
+    ("TH", &["Thailand", "Таиланд", "Tailandia"]),
+    ("VN", &["Vietnam", "Вьетнам"]),
+    ("ID", &["Indonesia", "Индонезия"]),
+    ("PH", &["Philippines", "Филиппины", "Filipinas"]),
+    ("MY", &["Malaysia", "Малайзия", "Malasia"]),
+    ("IN", &["India", "Индия"]),
+    ("PK", &["Pakistan", "Пакистан", "Pakistán"]),
+    ("BD", &["Bangladesh", "Бангладеш"]),
+    ("NP", &["Nepal", "Непал"]),
+    ("LK", &["Sri Lanka", "Шри-Ланка"]),
+    ("IR", &["Iran", "Иран", "Irán"]),
+    ("AE", &["United Arab Emirates", "UAE", "ОАЭ", "Emiratos Árabes Unidos"]),
+    ("SA", &["Saudi Arabia", "Саудовская Аравия", "Arabia Saudita"]),
+    ("IL", &["Israel", "Израиль"]),
+    ("IQ", &["Iraq", "Ирак", "Irak"]),
+    ("AF", &["Afghanistan", "Афганистан", "Afganistán"]),
+    ("MN", &["Mongolia", "Монголия"]),
+    ("EG", &["Egypt", "Египет", "Egipto"]),
+    ("NG", &["Nigeria", "Нигерия"]),
+    ("KE", &["Kenya", "Кения"]),
+    ("ZA", &["South Africa", "ЮАР", "Sudáfrica"]),
+    ("MA", &["Morocco", "Марокко", "Marruecos"]),
+    ("ET", &["Ethiopia", "Эфиопия", "Etiopía"]),
+    ("GH", &["Ghana", "Гана"]),
+    ("DZ", &["Algeria", "Алжир", "Argelia"]),
+    ("TN", &["Tunisia", "Тунис", "Túnez"]),
+    ("AU", &["Australia", "Австралия"]),
+    ("NZ", &["New Zealand", "Новая Зеландия", "Nueva Zelanda"]),
+];
+
+static PLACES: Lazy<Vec<Place>> = Lazy::new(|| {
+    include_str!("cities.txt")
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let fields: Vec<&'static str> = line.split('|').collect();
+            let [name, aliases, country, tz, lat, lon] = fields[..] else {
+                return None;
+            };
+            Some(Place {
+                name,
+                aliases: aliases.split(';').filter(|a| !a.is_empty()).collect(),
+                country,
+                tz,
+                lat: lat.parse().ok()?,
+                lon: lon.parse().ok()?,
+            })
+        })
+        .collect()
+});
+
+/// Every bundled place
+pub fn places() -> &'static [Place] {
+    &PLACES
+}
+
+/// Outcome of a place lookup
+#[derive(Debug, Clone, PartialEq)]
+pub enum PlaceMatch {
+    Found(&'static Place),
+    /// Several cities share the name; narrow it down with the country
+    Ambiguous(Vec<&'static Place>),
+    NotFound,
+}
+
+/// Find a place by "City" or "City, Country" (country name or ISO code), case-insensitive
+pub fn find_place(query: &str) -> PlaceMatch {
+    let (city, country) = match query.rsplit_once(',') {
+        Some((city, country)) => (city, Some(country.trim().to_lowercase())),
+        None => (query, None),
+    };
+    let city = city.trim().to_lowercase();
+    let in_country = |place: &Place| match &country {
+        None => true,
+        Some(country) => {
+            place.country.to_lowercase() == *country
+                || COUNTRIES
+                    .iter()
+                    .filter(|(code, _)| *code == place.country)
+                    .flat_map(|(_, names)| names.iter())
+                    .any(|name| name.to_lowercase() == *country)
+        }
+    };
+
+    let matches: Vec<&'static Place> = places()
+        .iter()
+        .filter(|place| place.is_named(&city) && in_country(place))
+        .collect();
+    match matches.len() {
+        0 => PlaceMatch::NotFound,
+        1 => PlaceMatch::Found(matches[0]),
+        _ => PlaceMatch::Ambiguous(matches),
+    }
+}
@@ -1,9 +1,23 @@
 pub mod astro_calc;
+pub mod bodygraph;
+pub mod book;
+pub mod cache;
 pub mod calc;
 pub mod circuit_score;
 pub mod cli;
 pub mod config;
+pub mod daemon;
 pub mod data;
+pub mod geo;
 pub mod models;
+pub mod profiles;
+pub mod render;
+pub mod server;
+pub mod site;
+#[cfg(feature = "swisseph")]
+pub mod swisseph;
+pub mod template;
+pub mod transit;
+pub mod update;
 
 rust_i18n::i18n!("locales");
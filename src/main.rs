@@ -1,21 +1,285 @@
-use clap::Parser;
-use hd_cli::cli::{self, Cli, Commands};
+use clap::{Parser, ValueEnum};
+use hd_cli::cli::{self, CacheAction, Cli, Commands, DbAction, OutputFormat, ProfileAction};
 use hd_cli::calc;
-use hd_cli::config::Config;
+use hd_cli::config::{Config, WrapSettings};
+use hd_cli::render::{self, RenderOptions};
+use hd_cli::profiles::{ProfileStore, SavedProfile};
+use hd_cli::astro_calc::{Calendar, HdPlanet};
+use hd_cli::data::{database, penta};
+use hd_cli::{astro_calc, book, cache, daemon, server, site, template, transit, update};
 
 // Init translations
 rust_i18n::i18n!("locales");
 
+/// Terminal color depth detected at startup
+static COLOR_DEPTH: std::sync::OnceLock<render::ColorDepth> = std::sync::OnceLock::new();
+
+/// Print rendered output with colors adapted to the terminal
+fn print_output(text: &str) {
+    let depth = COLOR_DEPTH.get().copied().unwrap_or(render::ColorDepth::TrueColor);
+    println!("{}", depth.adapt(text));
+}
+
+/// Unwrap a calculation result, printing the error and exiting on failure
+fn or_exit<T>(result: Result<T, String>) -> T {
+    result.unwrap_or_else(|e| {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    })
+}
+
 fn main() {
-    let args = Cli::parse();
-    
+    let mut args = Cli::parse();
+
+    // Colors and box drawing only on a terminal unless --color says otherwise; legacy
+    // Windows consoles without VT processing or UTF-8 fall back to plain/ASCII output
+    let console = render::ConsoleSupport::init();
+    let color = args.color_choice();
+    let styled = color.resolve() && (console.ansi || color == cli::ColorChoice::Always);
+    let ascii = args.ascii || !styled || !console.unicode;
+    colored::control::set_override(styled);
+
+    // Rendering always writes RGB; terminals without truecolor get it mapped to 256 colors
+    if styled {
+        COLOR_DEPTH.set(render::ColorDepth::detect()).ok();
+    }
+
     // 1. Load configuration
     let mut config = Config::load();
 
-    // 2. Handle subcommands
-    if let Some(command) = args.command {
+    // 2. Determine language
+    // Priority: CLI arg > Config > Default (built into Config)
+    let lang = args.lang.clone().unwrap_or(config.language.clone());
+    // Interface language (labels, errors) may differ from the data language
+    let ui_lang = args
+        .ui_lang
+        .clone()
+        .or(config.ui_language.clone())
+        .unwrap_or(lang.clone());
+    rust_i18n::set_locale(&ui_lang);
+
+    // Calculation settings shared by every chart and report of this run
+    let mut calc_opts = astro_calc::CalcOptions {
+        engine: args.engine.unwrap_or_default(),
+        node: args.node.unwrap_or(config.node),
+        delta_t: !args.no_delta_t,
+        apparent: !args.true_positions,
+        calendar: args.calendar,
+        ..Default::default()
+    };
+    if let Some(arc) = args.design_arc {
+        if !(arc > 0.0 && arc < 360.0) {
+            eprintln!("Error: {}", rust_i18n::t!("error.design_arc", value = arc));
+            std::process::exit(1);
+        }
+        calc_opts.design_arc = arc;
+    }
+    if let Some(offset) = args.wheel_offset {
+        if !(-10.0..=10.0).contains(&offset) {
+            eprintln!("Error: {}", rust_i18n::t!("error.wheel_offset", value = offset));
+            std::process::exit(1);
+        }
+        calc_opts.wheel_offset = offset;
+    }
+    // Applies to every mode, on top of any `--wheel-offset`, with the ayanamsa of the
+    // sign display
+    calc_opts.sidereal_wheel = args.sidereal.filter(|_| args.sidereal_wheel);
+    let calendar = calc_opts.calendar;
+
+    let json_opts = cli::JsonOptions {
+        case: args.json_case,
+        compact: args.compact,
+    };
+    let csv_opts = cli::CsvOptions {
+        rows: args.csv_rows,
+        no_header: args.no_header,
+    };
+
+    let render_opts = RenderOptions {
+        color: styled,
+        ascii,
+        // Legacy consoles without UTF-8 can't show the glyphs either
+        ascii_symbols: args.ascii || !console.unicode,
+        wrap: args.wrap_settings().or(config.wrap).resolve(),
+        channel_grouping: args.group_channels,
+        activation_order: args.sort_activations,
+        angle_format: args.angle_format,
+        sections: args.sections.clone(),
+        excluded_sections: args.exclude_sections.clone(),
+        toc: args.toc,
+        link_template: args.link_template.clone().or(config.link_template.clone()),
+        ..Default::default()
+    };
+
+    // Output directory of the `site` command, which renders the regular chart
+    let mut site_out = None;
+    let mut dreamrave = false;
+    let mut solar_return = None;
+    let mut cycles = false;
+    let mut gene_keys = false;
+    let mut rave_new_year = None;
+    let mut ephemeris_gates = None;
+
+    // 3. Handle subcommands
+    if let Some(command) = args.command.take() {
+        // HTML, CSV, the summary line and templates are built from a full
+        // chart; the other reports only come as tables, JSON or YAML
+        let chart_only = match (&args.template, &args.format) {
+            (Some(_), _) => Some("--template".to_string()),
+            (None, OutputFormat::Html | OutputFormat::Csv | OutputFormat::Summary) => args
+                .format
+                .to_possible_value()
+                .map(|v| format!("--format {}", v.get_name())),
+            _ => None,
+        };
+        if let (Some(option), true) = (
+            chart_only,
+            matches!(
+                command,
+                Commands::Transit { .. }
+                    | Commands::Planner { .. }
+                    | Commands::LunarCycle { .. }
+                    | Commands::Transits { .. }
+                    | Commands::Birthdays { .. }
+                    | Commands::Group { .. }
+                    | Commands::Compare { .. }
+                    | Commands::Penta { .. }
+                    | Commands::Matrix { .. }
+                    | Commands::Dreamrave { .. }
+                    | Commands::Genekeys { .. }
+                    | Commands::Ephemeris { .. }
+                    | Commands::Cycles { .. }
+            ),
+        ) {
+            eprintln!("Error: {}", rust_i18n::t!("error.chart_only_option", option = option));
+            std::process::exit(1);
+        }
         match command {
-            Commands::Config { set_lang } => {
+            Commands::Site { profile, out } => {
+                if let Some(name) = profile {
+                    use_profile(&mut args, &name);
+                }
+                site_out = Some(out);
+            }
+            Commands::Dreamrave { profile } => {
+                if let Some(name) = profile {
+                    use_profile(&mut args, &name);
+                }
+                dreamrave = true;
+            }
+            Commands::SolarReturn { year, profile } => {
+                if let Some(name) = profile {
+                    use_profile(&mut args, &name);
+                }
+                solar_return = Some(year);
+            }
+            Commands::RaveNewYear { year } => {
+                // A global chart: no birth data, just the exact moment
+                args.jd = Some(transit::rave_new_year_jd(year, &calc_opts));
+                rave_new_year = Some(year);
+            }
+            Commands::Ephemeris { profile, date, time, utc, jd, gates } => {
+                if let Some(name) = profile {
+                    use_profile(&mut args, &name);
+                }
+                // The moment may also be given after the subcommand name
+                args.date = date.or(args.date.take());
+                args.time = time.or(args.time.take());
+                args.utc = utc.or(args.utc.take());
+                args.jd = jd.or(args.jd);
+                ephemeris_gates = Some(gates);
+            }
+            Commands::Genekeys { profile } => {
+                if let Some(name) = profile {
+                    use_profile(&mut args, &name);
+                }
+                gene_keys = true;
+            }
+            Commands::Cycles { profile } => {
+                if let Some(name) = profile {
+                    use_profile(&mut args, &name);
+                }
+                cycles = true;
+            }
+            Commands::Config {
+                set_lang,
+                set_ui_lang,
+                set_notify_command,
+                set_depth,
+                set_cache,
+                set_node,
+                set_wrap_width,
+                set_indent,
+                set_break_words,
+                set_split_hyphens,
+                set_link_template,
+                set_show,
+                set_hide,
+            } => {
+                let wrap = WrapSettings {
+                    max_width: set_wrap_width,
+                    indent: set_indent,
+                    break_words: set_break_words,
+                    split_hyphens: set_split_hyphens,
+                };
+                if wrap.max_width.is_some()
+                    || wrap.indent.is_some()
+                    || wrap.break_words.is_some()
+                    || wrap.split_hyphens.is_some()
+                {
+                    match config.set_wrap(wrap) {
+                        Ok(_) => println!("Wrapping defaults updated"),
+                        Err(e) => eprintln!("Error: {}", e),
+                    }
+                }
+                if let Some(template) = set_link_template {
+                    match config.set_link_template(&template) {
+                        Ok(_) => println!("Link template set to '{}'", template),
+                        Err(e) => eprintln!("Error: {}", e),
+                    }
+                }
+                if !set_show.is_empty() {
+                    match config.set_topics(&set_show, true) {
+                        Ok(_) => println!("Topics shown by default: {:?}", set_show),
+                        Err(e) => eprintln!("Error: {}", e),
+                    }
+                }
+                if !set_hide.is_empty() {
+                    match config.set_topics(&set_hide, false) {
+                        Ok(_) => println!("Topics hidden by default: {:?}", set_hide),
+                        Err(e) => eprintln!("Error: {}", e),
+                    }
+                }
+                if let Some(depth) = set_depth {
+                    match config.set_depth(depth) {
+                        Ok(_) => println!("Default depth set to '{}'", depth),
+                        Err(e) => eprintln!("Error: {}", e),
+                    }
+                }
+                if let Some(cache) = set_cache {
+                    match config.set_cache(cache) {
+                        Ok(_) => println!("Chart cache set to '{}'", cache),
+                        Err(e) => eprintln!("Error: {}", e),
+                    }
+                }
+                if let Some(node) = set_node {
+                    match config.set_node(node) {
+                        Ok(_) => println!("Default lunar node set to '{}'", node.key()),
+                        Err(e) => eprintln!("Error: {}", e),
+                    }
+                }
+                if let Some(ui_lang) = set_ui_lang {
+                    match config.set_ui_language(&ui_lang) {
+                        Ok(_) => println!("Interface language set to '{}'", ui_lang),
+                        Err(e) => eprintln!("Error: {}", e),
+                    }
+                }
+                if let Some(command) = set_notify_command {
+                    match config.set_notify_command(&command) {
+                        Ok(_) => println!("Notify command set to '{}'", command),
+                        Err(e) => eprintln!("Error: {}", e),
+                    }
+                }
                 if let Some(lang) = set_lang {
                     match config.set_language(&lang) {
                         Ok(_) => println!("Default language set to '{}'", lang),
@@ -23,76 +287,602 @@ fn main() {
                     }
                 } else {
                     println!("Current default language: {}", config.language);
+                    if let Some(ref ui_lang) = config.ui_language {
+                        println!("Interface language: {}", ui_lang);
+                    }
+                    if let Some(ref command) = config.notify_command {
+                        println!("Notify command: {}", command);
+                    }
                 }
                 return; // Exit after handling config
             }
+            Commands::Profile { action } => {
+                run_profile(action, calendar);
+                return;
+            }
+            Commands::Transit {
+                profile,
+                date,
+                time,
+                utc,
+            } => {
+                let (natal_jd, transit_jd) = transit_moments(&profile, date, time, utc, calendar);
+                if args.dry_run {
+                    print_output(&cli::generate_moments_output(
+                        &[
+                            cli::resolve_moment(&profile, natal_jd),
+                            cli::resolve_moment("transit", transit_jd),
+                        ],
+                        &args.format,
+                        json_opts,
+                    ));
+                    return;
+                }
+                let report = or_exit(transit::build_transit_report(
+                    &profile,
+                    natal_jd,
+                    transit_jd,
+                    &lang,
+                    &calc_opts,
+                ));
+                print_output(&cli::generate_transit_output(
+                    &report,
+                    &args.format,
+                    json_opts,
+                    &render_opts,
+                ));
+                return;
+            }
+            Commands::Planner {
+                profile,
+                from,
+                days,
+                top,
+            } => {
+                let (natal_jd, from_jd) = transit_moments(&profile, from, None, None, calendar);
+                // Days are sampled at noon UTC, which JD integers fall on
+                let from_jd = from_jd.round();
+                if args.dry_run {
+                    print_output(&cli::generate_moments_output(
+                        &[
+                            cli::resolve_moment(&profile, natal_jd),
+                            cli::resolve_moment("from", from_jd),
+                        ],
+                        &args.format,
+                        json_opts,
+                    ));
+                    return;
+                }
+                let mut plan = or_exit(transit::build_transit_plan(
+                    &profile, natal_jd, from_jd, days, &lang, &calc_opts,
+                ));
+                if let Some(top) = top {
+                    plan.days.truncate(top);
+                }
+                print_output(&cli::generate_planner_output(
+                    &plan,
+                    &args.format,
+                    json_opts,
+                    &render_opts,
+                ));
+                return;
+            }
+            Commands::LunarCycle { profile, from } => {
+                let (natal_jd, from_jd) = transit_moments(&profile, from, None, None, calendar);
+                if args.dry_run {
+                    print_output(&cli::generate_moments_output(
+                        &[
+                            cli::resolve_moment(&profile, natal_jd),
+                            cli::resolve_moment("from", from_jd),
+                        ],
+                        &args.format,
+                        json_opts,
+                    ));
+                    return;
+                }
+                let cycle = or_exit(transit::build_lunar_cycle(&profile, natal_jd, from_jd, &lang, &calc_opts));
+                print_output(&cli::generate_lunar_cycle_output(
+                    &cycle,
+                    &args.format,
+                    json_opts,
+                    &render_opts,
+                ));
+                return;
+            }
+            Commands::Transits {
+                from,
+                to,
+                lines,
+                ical,
+            } => {
+                let (from_jd, to_jd) = match (
+                    cli::parse_moment_jd(&from, "00:00", "0", calendar),
+                    cli::parse_moment_jd(&to, "00:00", "0", calendar),
+                ) {
+                    (Ok(f), Ok(t)) if t > f => (f, t),
+                    (Err(e), _) | (_, Err(e)) => {
+                        eprintln!("Error: {}", e);
+                        std::process::exit(1);
+                    }
+                    _ => {
+                        eprintln!("Error: {}", rust_i18n::t!("error.date_range", from = from, to = to));
+                        std::process::exit(1);
+                    }
+                };
+                if args.dry_run {
+                    print_output(&cli::generate_moments_output(
+                        &[
+                            cli::resolve_moment("from", from_jd),
+                            cli::resolve_moment("to", to_jd),
+                        ],
+                        &args.format,
+                        json_opts,
+                    ));
+                    return;
+                }
+                let ingresses = transit::sun_ingresses(from_jd, to_jd, lines, &lang, &calc_opts);
+                if ical {
+                    print_output(&cli::generate_sun_ingresses_ical(&ingresses));
+                } else {
+                    print_output(&cli::generate_sun_ingresses_output(
+                        &ingresses,
+                        &args.format,
+                        json_opts,
+                        &render_opts,
+                    ));
+                }
+                return;
+            }
+            Commands::Birthdays { days } => {
+                let store = ProfileStore::load();
+                let members: Vec<(String, f64)> = store
+                    .profiles
+                    .keys()
+                    .map(|name| (name.clone(), load_profile_jd(&store, name, calendar)))
+                    .collect();
+                let returns = transit::upcoming_solar_returns(
+                    &members,
+                    astro_calc::now_julian_day(),
+                    days,
+                    &calc_opts,
+                );
+                print_output(&cli::generate_solar_returns_output(
+                    &returns,
+                    days,
+                    &args.format,
+                    json_opts,
+                    &render_opts,
+                ));
+                return;
+            }
+            Commands::Daemon {
+                profile,
+                interval,
+                exec,
+                lines,
+                all,
+                no_notify,
+                detach,
+            } => {
+                if detach {
+                    detach_daemon();
+                    return;
+                }
+                let store = ProfileStore::load();
+                let natal_jd = load_profile_jd(&store, &profile, calendar);
+                let opts = daemon::DaemonOptions {
+                    interval_secs: interval,
+                    command: exec.or(config.notify_command.clone()),
+                    lines,
+                    all,
+                    notify: !no_notify,
+                    calc_opts,
+                };
+                or_exit(daemon::run(&profile, natal_jd, &opts));
+                return;
+            }
+            Commands::Group { names } => {
+                let members = load_members(&names, calendar);
+                if args.dry_run {
+                    let moments: Vec<_> = members
+                        .iter()
+                        .map(|(name, jd)| cli::resolve_moment(name, *jd))
+                        .collect();
+                    print_output(&cli::generate_moments_output(&moments, &args.format, json_opts));
+                    return;
+                }
+                let chart = or_exit(calc::build_composite(&members, &lang, &calc_opts));
+                print_output(&cli::generate_composite_output(
+                    &chart,
+                    &args.format,
+                    json_opts,
+                    &render_opts,
+                ));
+                return;
+            }
+            Commands::Compare { first, second } => {
+                let store = ProfileStore::load();
+                let members: Vec<(String, f64)> = [first, second]
+                    .into_iter()
+                    .map(|spec| {
+                        let jd = birth_spec_jd(&store, &spec, calendar);
+                        (spec, jd)
+                    })
+                    .collect();
+                if args.dry_run {
+                    let moments: Vec<_> = members
+                        .iter()
+                        .map(|(name, jd)| cli::resolve_moment(name, *jd))
+                        .collect();
+                    print_output(&cli::generate_moments_output(&moments, &args.format, json_opts));
+                    return;
+                }
+                let chart = or_exit(calc::build_composite(&members, &lang, &calc_opts));
+                print_output(&cli::generate_composite_output(
+                    &chart,
+                    &args.format,
+                    json_opts,
+                    &render_opts,
+                ));
+                return;
+            }
+            Commands::Penta { input } => {
+                let members = match std::fs::read_to_string(&input)
+                    .map_err(|e| format!("{}: {}", input.display(), e))
+                    .and_then(|content| cli::parse_people_csv(&content, calendar))
+                {
+                    Ok(members) => members,
+                    Err(e) => {
+                        eprintln!("Error: {}", e);
+                        std::process::exit(1);
+                    }
+                };
+                if !(penta::MIN_MEMBERS..=penta::MAX_MEMBERS).contains(&members.len()) {
+                    eprintln!(
+                        "Error: {}",
+                        rust_i18n::t!("error.penta_size", count = members.len())
+                    );
+                    std::process::exit(1);
+                }
+                if args.dry_run {
+                    let moments: Vec<_> = members
+                        .iter()
+                        .map(|(name, jd)| cli::resolve_moment(name, *jd))
+                        .collect();
+                    print_output(&cli::generate_moments_output(&moments, &args.format, json_opts));
+                    return;
+                }
+                let report = or_exit(calc::build_penta(&members, &calc_opts));
+                print_output(&cli::generate_penta_output(
+                    &report,
+                    &args.format,
+                    json_opts,
+                    &render_opts,
+                ));
+                return;
+            }
+            Commands::Matrix { names, csv } => {
+                let members = load_members(&names, calendar);
+                if args.dry_run {
+                    let moments: Vec<_> = members
+                        .iter()
+                        .map(|(name, jd)| cli::resolve_moment(name, *jd))
+                        .collect();
+                    print_output(&cli::generate_moments_output(&moments, &args.format, json_opts));
+                    return;
+                }
+                let matrix = or_exit(calc::build_matrix(&members, &lang, &calc_opts));
+                if csv {
+                    println!("{}", cli::matrix_csv(&matrix));
+                } else {
+                    print_output(&cli::generate_matrix_output(
+                        &matrix,
+                        &args.format,
+                        json_opts,
+                        &render_opts,
+                    ));
+                }
+                return;
+            }
+            Commands::Db {
+                action: DbAction::Book { out },
+            } => {
+                let db = database::get_database(&lang);
+                match book::write_book(&db, &lang, &out) {
+                    Ok(count) => println!(
+                        "{}",
+                        rust_i18n::t!("book.written", count = count, path = out.display().to_string())
+                    ),
+                    Err(e) => {
+                        eprintln!("Error: {}", e);
+                        std::process::exit(1);
+                    }
+                }
+                return;
+            }
+            Commands::Serve { addr, ui, db } => {
+                let opts = server::ServeOptions {
+                    addr,
+                    ui,
+                    db,
+                    lang: lang.clone(),
+                    calc_opts,
+                };
+                if let Err(e) = server::run(&opts) {
+                    eprintln!("Error: {}", e);
+                    std::process::exit(1);
+                }
+                return;
+            }
+            Commands::Cache { action } => {
+                run_cache(action);
+                return;
+            }
+            Commands::SelfUpdate { check } => {
+                run_self_update(check);
+                return;
+            }
         }
     }
 
-    // 3. Determine language
-    // Priority: CLI arg > Config > Default (built into Config)
-    let lang = args.lang.unwrap_or(config.language);
-    rust_i18n::set_locale(&lang);
-
-    // 4. Validate required arguments for calculation
-    // Since we made them Option to support subcommands, we must check them here.
-    if args.date.is_none() || args.time.is_none() || args.utc.is_none() {
-        // If not running a subcommand and missing args, print help
-        use clap::CommandFactory;
-        let mut cmd = Cli::command();
-        cmd.print_help().unwrap();
-        std::process::exit(1);
-    }
+    // Topic switches: CLI flags > config defaults > --short
+    let verbosity = args.verbosity().or(config.verbosity);
+    // Topics switched on in short mode still need the full data
+    let full = !args.short || verbosity.any_enabled();
 
-    let date_str = args.date.unwrap();
-    let time_str = args.time.unwrap();
-    let utc_str = args.utc.unwrap();
+    let bodies = if args.planets.is_empty() {
+        HdPlanet::all()
+    } else {
+        args.planets.clone()
+    };
+    // The chart lists the optional bodies after the HD planets
+    let chart_bodies: Vec<HdPlanet> = bodies
+        .iter()
+        .copied()
+        .chain(args.extra_planets.iter().map(|p| p.planet()))
+        .collect();
 
-    // Parse input data
-    let (year, month, day) = match cli::parse_date(&date_str) {
-        Ok(v) => v,
-        Err(e) => {
-            eprintln!("Error: {}", e);
-            std::process::exit(1);
-        }
+    // Calendar input (year, month, day, hour, minute, second, UTC offset); `None` in JD mode
+    let (personality_jd, moment) = if let Some(jd) = args.jd {
+        // Expert mode: no calendar/timezone handling at all
+        (jd, None)
+    } else {
+        let (year, month, day, hour, min, sec, utc_offset) = if let Some(epoch) = args.epoch {
+            match cli::parse_epoch(epoch) {
+                Ok((year, month, day, hour, min, sec)) => (year, month, day, hour, min, sec, 0.0),
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        } else {
+            // Validate required arguments for calculation
+            // Since we made them Option to support subcommands, we must check them here.
+            if args.date.is_none() || args.time.is_none() || (args.utc.is_none() && args.tz.is_none() && args.place.is_none()) {
+                // If not running a subcommand and missing args, print help
+                use clap::CommandFactory;
+                let mut cmd = Cli::command();
+                cmd.print_help().unwrap();
+                std::process::exit(1);
+            }
+
+            let date_str = args.date.clone().unwrap();
+            let time_str = args.time.clone().unwrap();
+
+            // Parse input data
+            let (year, month, day) = match cli::parse_date(&date_str) {
+                Ok(v) => v,
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    std::process::exit(1);
+                }
+            };
+
+            let (hour, min, sec) = match cli::parse_time(&time_str) {
+                Ok(v) => v,
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    std::process::exit(1);
+                }
+            };
+
+            let utc_offset = match cli::resolve_utc_offset(
+                args.utc.as_deref(),
+                args.tz.as_deref(),
+                args.place.as_deref(),
+                (year, month, day),
+                (hour, min, sec),
+            ) {
+                Ok(v) => v,
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    std::process::exit(1);
+                }
+            };
+
+            (year, month, day, hour, min, sec, utc_offset)
+        };
+
+        let personality_jd =
+            astro_calc::calc_julian_day((year, month, day), (hour, min, sec), utc_offset, calendar);
+        (personality_jd, Some((year, month, day, hour, min, sec, utc_offset)))
     };
 
-    let (hour, min) = match cli::parse_time(&time_str) {
-        Ok(v) => v,
-        Err(e) => {
-            eprintln!("Error: {}", e);
-            std::process::exit(1);
-        }
+    // Solar return: the chart is cast for the Sun's return instead of the birth moment
+    let natal_jd = personality_jd;
+    let (personality_jd, chart_moment) = match solar_return {
+        Some(year) => (astro_calc::find_solar_return(natal_jd, year, &calc_opts), None),
+        None => (natal_jd, moment),
     };
 
-    let utc_offset = match cli::parse_utc_offset(&utc_str) {
-        Ok(v) => v,
-        Err(e) => {
-            eprintln!("Error: {}", e);
-            std::process::exit(1);
+    if args.dry_run {
+        let mut moments = vec![cli::resolve_moment("birth", natal_jd)];
+        if solar_return.is_some() {
+            moments.push(cli::resolve_moment("solar_return", personality_jd));
         }
-    };
+        print_output(&cli::generate_moments_output(
+            &moments,
+            &args.format,
+            json_opts,
+        ));
+        return;
+    }
+
+    if let Some(with_gates) = ephemeris_gates {
+        let ephemeris = calc::build_ephemeris(personality_jd, &chart_bodies, with_gates, &calc_opts);
+        print_output(&cli::generate_ephemeris_output(&ephemeris, &args.format, json_opts, &render_opts));
+        return;
+    }
 
-    // Calculate chart
+    if dreamrave {
+        let chart = or_exit(calc::build_dreamrave(personality_jd, &bodies, &calc_opts));
+        print_output(&cli::generate_dreamrave_output(&chart, &args.format, json_opts, &render_opts));
+        return;
+    }
+
+    if gene_keys {
+        let chart = or_exit(calc::build_gene_keys(natal_jd, &lang, &calc_opts));
+        print_output(&cli::generate_genekeys_output(&chart, &args.format, json_opts, &render_opts));
+        return;
+    }
+
+    if cycles {
+        let events = transit::life_cycle_dates(natal_jd, &calc_opts);
+        print_output(&cli::generate_cycles_output(&events, &args.format, json_opts, &render_opts));
+        return;
+    }
+
+    let depth = args.depth || config.depth;
+    // A JD, a Unix timestamp or a time with seconds is exact; other calendar input
+    // is only known to the minute
+    let exact = args.jd.is_some()
+        || args.epoch.is_some()
+        || args.time.as_deref().is_some_and(|t| t.split(':').count() == 3);
+    // Everything that shapes the chart, so a cached one is only reused for identical
+    // requests; the interface language is in there for the texts taken from the locales
+    let cache_key = cache::fingerprint(&[
+        &format!("{:?}", moment),
+        &format!("{:?}", args.jd.map(f64::to_bits)),
+        &format!("{:?}", solar_return),
+        &format!("{:?}", chart_bodies),
+        &lang,
+        &ui_lang,
+        &format!("{} {} {} {}", full, args.short, depth, exact),
+        &format!("{:?}", calc_opts),
+        &format!("{:?} {}", args.sidereal, args.sidereal_wheel),
+        &serde_json::to_string(&verbosity).unwrap_or_default(),
+    ]);
+    let chart_cache = (args.cache || config.cache)
+        .then(cache::ChartCache::open)
+        .flatten();
+
+    // 4. Calculate chart
     // We pass the resolved `lang` to calc::build_chart so it can pick the right DB
-    // Note: rust_i18n::set_locale affects translations (t! macro),
+    // Note: rust_i18n::set_locale (with `ui_lang`) affects translations (t! macro),
     // but the database content is retrieved via getting the right DB instance.
-    let chart = calc::build_chart(
-        year, month, day, hour, min, utc_offset,
-        !args.short, &lang,
-    );
+    let mut chart = match chart_cache.as_ref().and_then(|c| c.get(&cache_key)) {
+        Some(chart) => chart,
+        None => {
+            let mut chart = or_exit(match chart_moment {
+                Some((year, month, day, hour, min, sec, utc_offset)) => calc::build_chart(
+                    year, month, day, hour, min, sec, utc_offset,
+                    &chart_bodies, full, &lang, &calc_opts,
+                ),
+                None => calc::build_chart_from_jd(personality_jd, &chart_bodies, full, &lang, &calc_opts),
+            });
+            chart.apply_verbosity(&verbosity, !args.short);
+            if let Some(ayanamsa) = args.sidereal {
+                calc::apply_sidereal(&mut chart, ayanamsa, personality_jd, calc_opts.sidereal_wheel.is_some());
+            }
+            if depth {
+                chart.apply_depth();
+                calc::add_depth_keynotes(&mut chart, &lang);
+                if !exact {
+                    or_exit(calc::flag_uncertain_substructure(&mut chart, personality_jd, &chart_bodies, &calc_opts));
+                }
+            }
+            if let Some(ref c) = chart_cache {
+                if let Err(e) = c.put(&cache_key, &chart) {
+                    eprintln!("Error: {}", e);
+                }
+            }
+            chart
+        }
+    };
+    if rave_new_year.is_none() {
+        chart.life_cycle = calc::life_cycle(natal_jd, astro_calc::now_julian_day());
+    }
+    if args.sensitivity {
+        chart.time_sensitivity = Some(or_exit(calc::time_sensitivity(personality_jd, &calc_opts)));
+    }
+
+    if let Some(out) = site_out {
+        match site::write_site(&chart, &out, &ui_lang) {
+            Ok(_) => println!(
+                "{}",
+                rust_i18n::t!("site.written", path = out.display().to_string())
+            ),
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
 
     // 1. Console output (with colors)
-    let output = cli::generate_output(&chart, &args.format, false);
-    println!("{}", output);
+    let table = matches!(args.format, OutputFormat::Table) && args.template.is_none();
+    if let (Some(year), true) = (solar_return, table) {
+        let moment = astro_calc::jd_to_utc(personality_jd).format("%Y-%m-%d %H:%M");
+        println!(
+            "{}",
+            rust_i18n::t!("cli.solar_return.header", year = year, moment = moment)
+        );
+    }
+    if let (Some(year), true) = (rave_new_year, table) {
+        let moment = astro_calc::jd_to_utc(personality_jd).format("%Y-%m-%d %H:%M");
+        println!(
+            "{}",
+            rust_i18n::t!(
+                "cli.rave_new_year.header",
+                year = year,
+                moment = moment,
+                cross = chart.incarnation_cross
+            )
+        );
+    }
+    let output = match &args.template {
+        Some(path) => or_exit(template::render_template(&chart, path)),
+        None => cli::generate_output(&chart, &args.format, json_opts, csv_opts, &render_opts),
+    };
+    print_output(&output);
 
     // 2. Save to file (if flag is specified)
     if let Some(ref save_val) = args.save {
         // Generate again without colors (plain=true)
-        let file_output = cli::generate_output(&chart, &args.format, true);
+        let file_opts = RenderOptions {
+            color: false,
+            ascii: false,
+            ascii_symbols: false,
+            ..render_opts.clone()
+        };
+        let file_output = match args.template {
+            Some(_) => output.clone(),
+            None => cli::generate_output(&chart, &args.format, json_opts, csv_opts, &file_opts),
+        };
 
         let filename = if save_val == "default" {
-            format!("hd_chart_{}_{}.txt", date_str, time_str.replace(':', "-"))
+            let extension = match args.format {
+                OutputFormat::Html => "html",
+                OutputFormat::Csv => "csv",
+                _ => "txt",
+            };
+            format!(
+                "hd_chart_{}_{}.{}",
+                chart.birth_date,
+                chart.birth_time.replace(':', "-"),
+                extension
+            )
         } else {
             save_val.clone()
         };
@@ -103,3 +893,215 @@ fn main() {
         }
     }
 }
+
+fn run_profile(action: ProfileAction, calendar: Calendar) {
+    let mut store = ProfileStore::load();
+    match action {
+        ProfileAction::Add {
+            name,
+            date,
+            time,
+            utc,
+            tz,
+            place,
+        } => {
+            let profile = SavedProfile {
+                date,
+                time,
+                utc,
+                tz,
+                place,
+            };
+            // Validate before saving
+            if let Err(e) = profile.julian_day(calendar) {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+            match store.add(&name, profile) {
+                Ok(_) => println!("{}", rust_i18n::t!("profile.saved", name = name)),
+                Err(e) => eprintln!("Error: {}", e),
+            }
+        }
+        ProfileAction::List => {
+            if store.profiles.is_empty() {
+                println!("{}", rust_i18n::t!("profile.empty"));
+            }
+            for (name, p) in &store.profiles {
+                println!(
+                    "{} {} {} {}",
+                    render::pad_to_width(name, 16),
+                    p.date,
+                    p.time,
+                    p.zone_label()
+                );
+            }
+        }
+        ProfileAction::Remove { name } => match store.remove(&name) {
+            Ok(_) => println!("{}", rust_i18n::t!("profile.removed", name = name)),
+            Err(e) => eprintln!("Error: {}", e),
+        },
+    }
+}
+
+fn run_cache(action: CacheAction) {
+    let Some(chart_cache) = cache::ChartCache::open() else {
+        eprintln!("Error: Could not determine cache path");
+        std::process::exit(1);
+    };
+    match action {
+        CacheAction::Clear => match chart_cache.clear() {
+            Ok(count) => println!("{}", rust_i18n::t!("cache.cleared", count = count)),
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+        },
+        CacheAction::Stats => {
+            let stats = chart_cache.stats();
+            println!(
+                "{}",
+                rust_i18n::t!(
+                    "cache.stats",
+                    count = stats.entries,
+                    size = format!("{:.1}", stats.bytes as f64 / 1024.0),
+                    path = chart_cache.dir().display().to_string()
+                )
+            );
+        }
+    }
+}
+
+/// Natal and transit Julian Days for a saved profile (transit: now by default; exits on error)
+fn transit_moments(
+    profile_name: &str,
+    date: Option<String>,
+    time: Option<String>,
+    utc: Option<String>,
+    calendar: Calendar,
+) -> (f64, f64) {
+    let store = ProfileStore::load();
+    let natal_jd = load_profile_jd(&store, profile_name, calendar);
+
+    let transit_jd = match date {
+        Some(d) => {
+            let t = time.unwrap_or_else(|| "12:00".to_string());
+            let u = utc.unwrap_or_else(|| "0".to_string());
+            match cli::parse_moment_jd(&d, &t, &u, calendar) {
+                Ok(v) => v,
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+        None => astro_calc::now_julian_day(),
+    };
+
+    (natal_jd, transit_jd)
+}
+
+/// Saved profiles with their birth Julian Days (exits on error)
+fn load_members(names: &[String], calendar: Calendar) -> Vec<(String, f64)> {
+    let store = ProfileStore::load();
+    names
+        .iter()
+        .map(|name| (name.clone(), load_profile_jd(&store, name, calendar)))
+        .collect()
+}
+
+/// Take the birth data of a saved profile as if it was given on the command line (exits on error)
+fn use_profile(args: &mut Cli, name: &str) {
+    let store = ProfileStore::load();
+    match store.get(name) {
+        Ok(p) => {
+            args.date = Some(p.date.clone());
+            args.time = Some(p.time.clone());
+            // The profile's zone replaces any given on the command line
+            args.utc = p.utc.clone();
+            args.tz = p.tz.clone();
+            args.place = p.place.clone();
+        }
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Personality JD for a "YYYY-MM-DD HH:MM UTC" birth spec or a saved profile name (exits on error)
+fn birth_spec_jd(store: &ProfileStore, spec: &str, calendar: Calendar) -> f64 {
+    let parts: Vec<&str> = spec.split_whitespace().collect();
+    let [date, time, utc] = parts[..] else {
+        return load_profile_jd(store, spec, calendar);
+    };
+    match cli::parse_moment_jd(date, time, utc, calendar) {
+        Ok(jd) => jd,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+fn run_self_update(check: bool) {
+    println!("{}", rust_i18n::t!("update.checking"));
+    match update::run(check) {
+        Ok(update::UpdateStatus::UpToDate { version }) => {
+            println!("{}", rust_i18n::t!("update.up_to_date", version = version))
+        }
+        Ok(update::UpdateStatus::Available { current, latest }) => println!(
+            "{}",
+            rust_i18n::t!("update.available", current = current, latest = latest)
+        ),
+        Ok(update::UpdateStatus::Updated { from, to }) => {
+            println!("{}", rust_i18n::t!("update.updated", from = from, to = to))
+        }
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Resolve a saved profile into its birth Julian Day (exits on error)
+fn load_profile_jd(store: &ProfileStore, name: &str, calendar: Calendar) -> f64 {
+    match store
+        .get(name)
+        .and_then(|p| p.julian_day(calendar))
+    {
+        Ok(v) => v,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Re-launch the current command without `--detach`, detached from the terminal
+fn detach_daemon() {
+    let exe = match std::env::current_exe() {
+        Ok(v) => v,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+    };
+    let args: Vec<String> = std::env::args()
+        .skip(1)
+        .filter(|a| a != "--detach")
+        .collect();
+
+    match std::process::Command::new(exe)
+        .args(&args)
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()
+    {
+        Ok(child) => println!("{}", rust_i18n::t!("daemon.detached", pid = child.id())),
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
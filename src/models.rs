@@ -1,6 +1,125 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize)]
+/// Center identifier, serialized as its stable DB key ("head", "solar_plexus", ...)
+pub use crate::data::centers::Center as CenterKey;
+
+/// Energy type, serialized as a stable lowercase key (independent of the output language)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HdType {
+    Manifestor,
+    Generator,
+    ManifestingGenerator,
+    Projector,
+    Reflector,
+}
+
+impl HdType {
+    /// Key for DB and locale lookup
+    pub fn key(&self) -> &'static str {
+        match self {
+            HdType::Manifestor => "manifestor",
+            HdType::Generator => "generator",
+            HdType::ManifestingGenerator => "manifesting_generator",
+            HdType::Projector => "projector",
+            HdType::Reflector => "reflector",
+        }
+    }
+}
+
+/// Inner authority, serialized as a stable lowercase key
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Authority {
+    Emotional,
+    Sacral,
+    Splenic,
+    /// Heart to Throat (Manifestors)
+    EgoManifested,
+    /// Heart to G (Projectors)
+    EgoProjected,
+    SelfProjected,
+    /// Mental Projectors: clarity through the environment and sounding boards
+    Environmental,
+    Lunar,
+}
+
+impl Authority {
+    /// Key for DB lookup
+    pub fn key(&self) -> &'static str {
+        match self {
+            Authority::Emotional => "emotional",
+            Authority::Sacral => "sacral",
+            Authority::Splenic => "splenic",
+            Authority::EgoManifested => "ego_manifested",
+            Authority::EgoProjected => "ego_projected",
+            Authority::SelfProjected => "self_projected",
+            Authority::Environmental => "environmental",
+            Authority::Lunar => "lunar",
+        }
+    }
+
+    /// Key of databases that only know the flat "ego" and "mental" authorities
+    pub fn legacy_key(&self) -> &'static str {
+        match self {
+            Authority::EgoManifested | Authority::EgoProjected => "ego",
+            Authority::Environmental => "mental",
+            _ => self.key(),
+        }
+    }
+}
+
+/// The 12 profiles (Personality Sun line / Design Sun line), serialized as "1/3", "4/6", ...
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Profile {
+    #[serde(rename = "1/3")]
+    OneThree,
+    #[serde(rename = "1/4")]
+    OneFour,
+    #[serde(rename = "2/4")]
+    TwoFour,
+    #[serde(rename = "2/5")]
+    TwoFive,
+    #[serde(rename = "3/5")]
+    ThreeFive,
+    #[serde(rename = "3/6")]
+    ThreeSix,
+    #[serde(rename = "4/6")]
+    FourSix,
+    #[serde(rename = "4/1")]
+    FourOne,
+    #[serde(rename = "5/1")]
+    FiveOne,
+    #[serde(rename = "5/2")]
+    FiveTwo,
+    #[serde(rename = "6/2")]
+    SixTwo,
+    #[serde(rename = "6/3")]
+    SixThree,
+}
+
+impl Profile {
+    /// Profile from the Personality and Design Sun lines; `None` for impossible combinations
+    pub fn from_lines(personality: u8, design: u8) -> Option<Self> {
+        match (personality, design) {
+            (1, 3) => Some(Profile::OneThree),
+            (1, 4) => Some(Profile::OneFour),
+            (2, 4) => Some(Profile::TwoFour),
+            (2, 5) => Some(Profile::TwoFive),
+            (3, 5) => Some(Profile::ThreeFive),
+            (3, 6) => Some(Profile::ThreeSix),
+            (4, 6) => Some(Profile::FourSix),
+            (4, 1) => Some(Profile::FourOne),
+            (5, 1) => Some(Profile::FiveOne),
+            (5, 2) => Some(Profile::FiveTwo),
+            (6, 2) => Some(Profile::SixTwo),
+            (6, 3) => Some(Profile::SixThree),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CircuitScoreItem {
     pub circuit: String,
     pub circuit_name: String,
@@ -13,9 +132,52 @@ pub struct CircuitScoreItem {
     pub description: String,
 }
 
-#[derive(Debug, Clone, Serialize)]
+/// Share of the defined channels belonging to one circuit group
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CircuitryShare {
+    /// individual, tribal, collective, integration, ...
+    pub circuit: String,
+    pub name: String,
+    pub channels: usize,
+    /// Percent of all defined channels
+    pub percent: f64,
+    /// Circuit keynote from the database (full mode)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keynote: Option<String>,
+}
+
+/// How the activating planet fixes its line: exalted, in detriment, or both (juxtaposed)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Fixing {
+    Exalted,
+    Detriment,
+    Juxtaposed,
+}
+
+impl Fixing {
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Fixing::Exalted => "▲",
+            Fixing::Detriment => "▼",
+            Fixing::Juxtaposed => "★",
+        }
+    }
+
+    pub fn key(&self) -> &'static str {
+        match self {
+            Fixing::Exalted => "exalted",
+            Fixing::Detriment => "detriment",
+            Fixing::Juxtaposed => "juxtaposed",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlanetPosition {
     pub planet: String,
+    /// "personality" (conscious, black) or "design" (unconscious, red)
+    pub activation: String,
     pub index: usize,
     pub longitude: f64,
     pub degree: f64, // 0..360
@@ -23,63 +185,419 @@ pub struct PlanetPosition {
     pub zodiac_symbol: String, // e.g. "♉"
     pub planet_symbol: String, // e.g. "☉"
     pub zodiac_degree: f64,    // 0..30
+    /// Longitudinal speed, degrees per day (negative while retrograde)
+    pub speed_deg_per_day: f64,
+    pub retrograde: bool,
     pub gate: u8,
     pub line: u8,
     pub color: u8,
     pub tone: u8,
     pub base: u8,
     pub gate_name: Option<String>,
+    pub gate_keynote: Option<String>,
     pub gate_description: Option<String>,
     pub line_description: Option<String>,
+    /// The planet is exalted or in detriment in its line (from the gates database)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fixing: Option<Fixing>,
+    /// Full "gate.line.color.tone.base" notation (only with --depth)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    /// Substructure levels (color/tone/base) that may change within
+    /// the one-minute birth time uncertainty (only with --depth)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uncertain: Option<Vec<String>>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+/// One of the four gates composing the Incarnation Cross
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrossGate {
+    /// "personality" or "design"
+    pub side: String,
+    pub planet: String,
+    pub planet_symbol: String,
+    pub gate: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gate_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keynote: Option<String>,
+}
+
+/// Planet activating a gate
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GateActivator {
+    pub planet: String,
+    pub planet_symbol: String,
+    /// "personality" or "design"
+    pub side: String,
+    pub line: u8,
+}
+
+/// Activated gate with every planet that activates it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActiveGate {
+    pub gate: u8,
+    /// "personality", "design" or "both"
+    pub activation: String,
+    pub gate_name: String,
+    /// Short gate keynote for compact output
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keynote: Option<String>,
+    pub activators: Vec<GateActivator>,
+}
+
+/// Awareness stream of the Spleen, Ajna or Solar Plexus with at least one activated gate
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AwarenessStream {
+    /// Stream key, e.g. "taste", "knowing", "emotion"
+    pub key: String,
+    pub name: String,
+    /// Center key of the awareness center ("splenic", "ajna", "solar_plexus")
+    pub center: String,
+    pub center_name: String,
+    /// Every gate of the stream in flow order
+    pub gates: Vec<u8>,
+    pub active_gates: Vec<u8>,
+    /// Stream channels defined in the chart, by channel key ("18-58", ...)
+    pub defined_channels: Vec<String>,
+    /// Every channel of the stream is defined
+    pub complete: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// One planetary activation for the activations table
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Activation {
+    pub planet: String,
+    pub planet_symbol: String,
+    /// Planet order within its side
+    pub index: usize,
+    /// Personality (conscious) or Design (unconscious)
+    pub conscious: bool,
+    pub gate: u8,
+    pub line: u8,
+    pub color: u8,
+    pub tone: u8,
+    pub base: u8,
+    /// "gate.line.color.tone.base"
+    pub notation: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fixing: Option<Fixing>,
+}
+
+/// Gate that would complete a channel with a hanging gate
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GateCompletion {
+    pub gate: u8,
+    pub channel: String,
+}
+
+/// Activated gate that doesn't form a channel in the chart
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HangingGate {
+    pub gate: u8,
+    pub gate_name: String,
+    /// "personality", "design" or "both"
+    pub activation: String,
+    pub center: CenterKey,
+    pub center_name: String,
+    /// Missing gates, each completing one potential channel
+    pub completions: Vec<GateCompletion>,
+}
+
+/// Gate activated by more than one planet
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepeatedGate {
+    pub gate: u8,
+    pub gate_name: String,
+    pub count: usize,
+}
+
+/// Emphasis summary: repeated activations and resonance gates
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GateEmphasis {
+    pub repeated: Vec<RepeatedGate>,
+    /// Gates activated in both Personality and Design
+    pub resonance: Vec<u8>,
+}
+
+/// Node activation framed as the life theme / environment of a life period
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NodeTheme {
+    /// "before_uranus_opposition" (South Node) or "after_uranus_opposition" (North Node)
+    pub phase: String,
+    /// "personality" or "design"
+    pub side: String,
+    pub planet: String,
+    pub planet_symbol: String,
+    pub gate: u8,
+    pub line: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gate_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gate_description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_description: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChannelInfo {
     pub key: String,
     pub name: String,
+    /// Both gates of the channel (in key order) with their activation side
+    pub gates: Vec<GateActivation>,
+    /// "conscious" (Personality), "unconscious" (Design) or "mixed"
+    pub awareness: String,
+    /// The two centers the channel connects
+    pub centers: Vec<CenterKey>,
+    /// Circuit key ("individual", "tribal", ...) and its localized name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub circuit: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub circuit_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sub_circuit: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sub_circuit_name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+/// Gate with its activation side
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GateActivation {
+    pub gate: u8,
+    /// "personality", "design", "both" or "inactive"
+    pub activation: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CenterInfo {
+    pub key: CenterKey,
     pub name: String,
     pub defined: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub gates: Option<Vec<GateActivation>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub behavior_normal: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub behavior_distorted: Option<String>,
+    /// Open centers only (full mode)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub not_self_question: Option<String>,
+    /// Open centers only (full mode)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wisdom: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+/// Non-standard calculation settings a chart was computed with (research mode)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResearchSettings {
+    /// Solar arc between the Design and Personality Sun, degrees
+    pub design_arc: f64,
+    /// Shift of the wheel start (Gate 41) from 302° ecliptic, degrees
+    pub wheel_offset: f64,
+    /// Nodes from the true (osculating) instead of the mean lunar node
+    #[serde(default)]
+    pub true_node: bool,
+    /// Gate wheel on the sidereal zodiac of this ayanamsa (`--sidereal <ayanamsa> --sidereal-wheel`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ayanamsa: Option<String>,
+}
+
+/// Sidereal zodiac used for sign display (experimental sidereal mode)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SiderealSettings {
+    pub ayanamsa: String,
+    /// Ayanamsa value at the birth moment, degrees
+    pub ayanamsa_degrees: f64,
+    /// The HD gate wheel was shifted to the sidereal zodiac too (not only the signs)
+    pub wheel: bool,
+}
+
+/// At-a-glance counts of how "saturated" a chart is
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ChartSummary {
+    pub defined_centers: usize,
+    pub channels: usize,
+    pub gates: usize,
+    /// Gates activated by Personality planets (gates activated on both sides count in both)
+    pub conscious_gates: usize,
+    /// Gates activated by Design planets
+    pub unconscious_gates: usize,
+}
+
+/// Moon phase at a moment: Sun–Moon angle, phase name and illuminated fraction
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MoonPhase {
+    pub name: String,
+    /// new_moon, waxing_crescent, first_quarter, waxing_gibbous,
+    /// full_moon, waning_gibbous, last_quarter or waning_crescent
+    pub key: String,
+    /// Moon longitude minus Sun longitude, 0–360°
+    pub angle: f64,
+    /// Illuminated part of the disc, percent
+    pub illumination: f64,
+}
+
+/// One of the four Variable arrows, pointing left or right by the tone of its activation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VariableArrow {
+    /// digestion (Design Sun), environment (Design Node),
+    /// awareness (Personality Sun) or perspective (Personality Node)
+    pub key: String,
+    pub name: String,
+    /// "left" (tones 1–3) or "right" (tones 4–6)
+    pub side: String,
+    pub tone: u8,
+    pub keynote: String,
+}
+
+/// Cognition: the sense the Design Sun tone works through
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Cognition {
+    pub tone: u8,
+    /// smell, taste, outer_vision, inner_vision, feeling or touch
+    pub key: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// The four transformations (arrows) of the chart
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Variable {
+    /// e.g. "PLR DRL": Personality Sun and Node, then Design Sun and Node
+    pub notation: String,
+    pub arrows: Vec<VariableArrow>,
+}
+
+/// Gates completing channels that would join two areas of definition
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Bridge {
+    /// The two areas joined, as center names
+    pub areas: Vec<Vec<String>>,
+    /// Gates the chart is missing for the connection
+    pub gates: Vec<u8>,
+    /// Channels these gates complete
+    pub channels: Vec<String>,
+}
+
+/// Age at the time of the report and the HD life phase it falls in
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LifeCycle {
+    pub age: u32,
+    /// pre_saturn_return, saturn_return, post_saturn_return,
+    /// uranus_opposition, pre_kiron_return or kiron_return
+    pub phase: String,
+    pub name: String,
+    pub note: String,
+}
+
+/// Birth-time shift that moves a Sun or Moon activation to another gate or line
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeShift {
+    /// Signed shift of the birth time in minutes
+    pub minutes: i64,
+    pub planet: String,
+    /// "personality" or "design"
+    pub side: String,
+    /// gate.line before and after the shift
+    pub from: String,
+    pub to: String,
+}
+
+/// Nearest earlier and later birth times that change the Sun or Moon gate/line
+/// (`None` when stable for a whole day that way)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeSensitivity {
+    pub earlier: Option<TimeShift>,
+    pub later: Option<TimeShift>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HdChart {
     pub birth_date: String,
     pub birth_time: String,
     pub utc_offset: f64,
+    /// Birth (Personality) moment as a Julian Day, UT
+    pub personality_jd: f64,
+    /// Design moment as a Julian Day, UT: the Sun `design_arc` degrees before birth
+    pub design_jd: f64,
+    /// Design moment in UTC, "YYYY-MM-DD HH:MM:SS"
+    pub design_utc: String,
+    pub moon_phase: MoonPhase,
+    pub summary: ChartSummary,
+    /// Set per run from the current date, never cached
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub life_cycle: Option<LifeCycle>,
+    /// Set per run with --sensitivity, never cached
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_sensitivity: Option<TimeSensitivity>,
 
     #[serde(rename = "type")]
     pub hd_type: String,
+    /// Stable, language-independent type
+    pub type_key: HdType,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub type_description: Option<String>,
     pub profile: String,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile_key: Option<Profile>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub profile_description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub personality_line: Option<InfoItem>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub design_line: Option<InfoItem>,
     pub authority: String,
+    pub authority_key: Authority,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub authority_description: Option<String>,
     pub strategy: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub strategy_description: Option<String>,
+    /// Feeling of living by strategy and authority (e.g. Satisfaction for Generators)
+    pub signature: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature_description: Option<String>,
+    /// Feeling that signals the Not-Self (e.g. Frustration for Generators)
+    pub not_self_theme: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub not_self_description: Option<String>,
+    /// Single, split, triple split or quad split definition (none for Reflectors)
+    pub definition: String,
+    /// none, single, split, triple_split or quad_split
+    pub definition_key: String,
+    /// Gates that would join the separate areas of a split definition
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bridges: Option<Vec<Bridge>>,
     pub incarnation_cross: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cross_description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cross_gates: Option<Vec<CrossGate>>,
     pub personality: Vec<PlanetPosition>,
     pub design: Vec<PlanetPosition>,
+    /// South Nodes (before the Uranus opposition) then North Nodes (after)
+    pub nodes: Vec<NodeTheme>,
+    /// Every activated gate, ascending, with the planets activating it
+    pub active_gates: Vec<ActiveGate>,
+    /// Every planetary activation, Personality then Design
+    pub activations: Vec<Activation>,
+    pub emphasis: GateEmphasis,
+    /// Activated gates without a channel, grouped by center
+    pub hanging_gates: Vec<HangingGate>,
     pub channels: Vec<ChannelInfo>,
     pub centers: Vec<CenterInfo>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub business: Option<Vec<InfoItem>>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub variable: Option<Variable>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cognition: Option<Cognition>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub motivation: Option<Vec<InfoItem>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub environment: Option<Vec<InfoItem>>,
@@ -87,6 +605,9 @@ pub struct HdChart {
     pub diet: Option<Vec<InfoItem>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub fear: Option<Vec<InfoItem>>,
+    /// Complete or partially active awareness streams (shown with the fears)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub awareness_streams: Option<Vec<AwarenessStream>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sexuality: Option<Vec<InfoItem>>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -94,23 +615,532 @@ pub struct HdChart {
     /// Perspective / Vision
     #[serde(skip_serializing_if = "Option::is_none")]
     pub vision: Option<Vec<InfoItem>>,
+    /// Defined channels counted per circuit group
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub circuitry: Option<Vec<CircuitryShare>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub circuit_scores: Option<Vec<CircuitScoreItem>>,
+    /// General meaning of each color/tone/base in the chart (only with --depth)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub advanced_depth: Option<Vec<InfoItem>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub research: Option<ResearchSettings>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sidereal: Option<SiderealSettings>,
 }
-#[derive(Debug, Clone, Serialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+
+/// Optional report topic that can be switched on or off individually
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Topic {
+    /// Primary Health System: motivation, vision, environment, diet
+    Phs,
+    Business,
+    /// Gate descriptions for planets and nodes
+    GateDescriptions,
+    /// Line descriptions (planets, nodes, profile lines)
+    Lines,
+    /// Fear, sexuality and love
+    Themes,
+    Circuits,
+}
+
+/// Per-topic switches; `None` follows the `--short`/full default
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct Verbosity {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phs: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub business: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gate_descriptions: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lines: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub themes: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub circuits: Option<bool>,
+}
+
+impl Verbosity {
+    fn slot(&mut self, topic: Topic) -> &mut Option<bool> {
+        match topic {
+            Topic::Phs => &mut self.phs,
+            Topic::Business => &mut self.business,
+            Topic::GateDescriptions => &mut self.gate_descriptions,
+            Topic::Lines => &mut self.lines,
+            Topic::Themes => &mut self.themes,
+            Topic::Circuits => &mut self.circuits,
+        }
+    }
+
+    pub fn set(&mut self, topic: Topic, on: bool) {
+        *self.slot(topic) = Some(on);
+    }
+
+    /// Fill unset topics from `fallback` (CLI flags over config defaults)
+    pub fn or(self, fallback: Verbosity) -> Verbosity {
+        Verbosity {
+            phs: self.phs.or(fallback.phs),
+            business: self.business.or(fallback.business),
+            gate_descriptions: self.gate_descriptions.or(fallback.gate_descriptions),
+            lines: self.lines.or(fallback.lines),
+            themes: self.themes.or(fallback.themes),
+            circuits: self.circuits.or(fallback.circuits),
+        }
+    }
+
+    /// Whether any topic is explicitly switched on (needs full chart data)
+    pub fn any_enabled(&self) -> bool {
+        [
+            self.phs,
+            self.business,
+            self.gate_descriptions,
+            self.lines,
+            self.themes,
+            self.circuits,
+        ]
+        .contains(&Some(true))
+    }
+}
+
+impl HdChart {
+    /// Drop topics switched off in `verbosity`. Unset topics follow `full`,
+    /// except themes which are shown in short mode too. The chart must have been
+    /// built in full mode if any topic is switched on.
+    pub fn apply_verbosity(&mut self, verbosity: &Verbosity, full: bool) {
+        if !full {
+            self.type_description = None;
+            self.profile_description = None;
+            self.authority_description = None;
+            self.strategy_description = None;
+            self.cross_description = None;
+            self.cross_gates = None;
+            for ch in &mut self.channels {
+                ch.description = None;
+            }
+            for c in &mut self.centers {
+                c.gates = None;
+                c.behavior_normal = None;
+                c.behavior_distorted = None;
+            }
+        }
+        if !verbosity.gate_descriptions.unwrap_or(full) {
+            for p in self.personality.iter_mut().chain(self.design.iter_mut()) {
+                p.gate_description = None;
+            }
+            for n in &mut self.nodes {
+                n.gate_description = None;
+            }
+        }
+        if !verbosity.lines.unwrap_or(full) {
+            for p in self.personality.iter_mut().chain(self.design.iter_mut()) {
+                p.line_description = None;
+            }
+            for n in &mut self.nodes {
+                n.line_description = None;
+            }
+            self.personality_line = None;
+            self.design_line = None;
+        }
+        if !verbosity.phs.unwrap_or(full) {
+            self.variable = None;
+            self.cognition = None;
+            self.motivation = None;
+            self.vision = None;
+            self.environment = None;
+            self.diet = None;
+        }
+        if !verbosity.business.unwrap_or(full) {
+            self.business = None;
+        }
+        if !verbosity.themes.unwrap_or(true) {
+            self.fear = None;
+            self.awareness_streams = None;
+            self.sexuality = None;
+            self.love = None;
+        }
+        if !verbosity.circuits.unwrap_or(full) {
+            self.circuitry = None;
+            self.circuit_scores = None;
+        }
+    }
+
+    /// Fill color/tone/base detail for every planet position (--depth)
+    pub fn apply_depth(&mut self) {
+        for p in self.personality.iter_mut().chain(self.design.iter_mut()) {
+            p.detail = Some(format!(
+                "{}.{}.{}.{}.{}",
+                p.gate, p.line, p.color, p.tone, p.base
+            ));
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct PlanetShortInfo {
     pub name: String,
     pub symbol: String,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InfoItem {
     pub label: String,
     pub description: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub planets: Option<std::collections::HashSet<PlanetShortInfo>>,
+    pub planets: Option<std::collections::BTreeSet<PlanetShortInfo>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub gate_id: Option<u8>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub gate_name: Option<String>,
 }
+
+/// Transit planet activation with the time window it stays in its gate
+#[derive(Debug, Clone, Serialize)]
+pub struct TransitActivation {
+    pub planet: String,
+    pub planet_symbol: String,
+    pub gate: u8,
+    pub line: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ends: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_hours: Option<f64>,
+}
+
+/// Natal hanging gate completed into a channel by a transit gate
+#[derive(Debug, Clone, Serialize)]
+pub struct TransitCompletion {
+    pub channel: String,
+    pub channel_name: String,
+    pub natal_gate: u8,
+    pub transit_gate: u8,
+    pub planets: Vec<String>,
+}
+
+/// Natally open center temporarily defined by transits
+#[derive(Debug, Clone, Serialize)]
+pub struct TransitCenter {
+    pub name: String,
+    pub channels: Vec<String>,
+}
+
+/// Validated input moment, reported by `--dry-run` instead of a chart
+#[derive(Debug, Clone, Serialize)]
+pub struct ResolvedMoment {
+    /// "birth", "transit" or a saved profile name
+    pub label: String,
+    /// "YYYY-MM-DD HH:MM:SS" in UTC
+    pub utc: String,
+    pub julian_day: f64,
+}
+
+/// Saved profile compared against transits at a given moment
+#[derive(Debug, Clone, Serialize)]
+pub struct TransitReport {
+    pub profile: String,
+    pub moment: String,
+    pub moon_phase: MoonPhase,
+    pub completed_gates: Vec<TransitCompletion>,
+    pub temporary_centers: Vec<TransitCenter>,
+    pub activations: Vec<TransitActivation>,
+}
+
+/// Personally significant transit on a planner day
+#[derive(Debug, Clone, Serialize)]
+pub struct PlannerEvent {
+    /// channel / center / sun
+    pub kind: String,
+    pub weight: u32,
+    pub description: String,
+}
+
+/// One day of a transit plan with its events and total score
+#[derive(Debug, Clone, Serialize)]
+pub struct PlannerDay {
+    pub date: String,
+    pub score: u32,
+    pub events: Vec<PlannerEvent>,
+}
+
+/// Days of a date range ranked by transit events relevant to a natal chart
+#[derive(Debug, Clone, Serialize)]
+pub struct TransitPlan {
+    pub profile: String,
+    pub from: String,
+    pub to: String,
+    pub days: Vec<PlannerDay>,
+}
+
+/// Stretch of a lunar cycle with the Moon in one gate
+#[derive(Debug, Clone, Serialize)]
+pub struct LunarGate {
+    /// Day of the cycle, from 1
+    pub day: u32,
+    /// Moon enters the gate, "YYYY-MM-DD HH:MM" UTC
+    pub start: String,
+    pub gate: u8,
+    pub gate_name: Option<String>,
+    /// Channels the Moon completes with natal gates (`gate` is the natal one)
+    pub channels: Vec<GateCompletion>,
+    /// Centers defined only while the Moon stays in the gate
+    pub centers: Vec<String>,
+}
+
+/// The Moon's passage through the gates over one lunar cycle, for lunar authority
+#[derive(Debug, Clone, Serialize)]
+pub struct LunarCycle {
+    pub profile: String,
+    pub from: String,
+    pub to: String,
+    /// No defined centers in the natal chart
+    pub reflector: bool,
+    pub gates: Vec<LunarGate>,
+}
+
+/// The Sun entering a gate (or a line of it)
+#[derive(Debug, Clone, Serialize)]
+pub struct SunIngress {
+    /// Exact moment, "YYYY-MM-DD HH:MM" UTC
+    pub moment: String,
+    pub julian_day: f64,
+    pub gate: u8,
+    pub line: u8,
+    pub gate_name: Option<String>,
+}
+
+/// Upcoming solar return of a saved profile
+#[derive(Debug, Clone, Serialize)]
+pub struct SolarReturn {
+    pub profile: String,
+    /// Exact return moment, "YYYY-MM-DD HH:MM" UTC
+    pub moment: String,
+    pub julian_day: f64,
+    pub days_until: f64,
+}
+
+/// Date of a planetary life-cycle milestone (Saturn return, Uranus opposition, ...)
+#[derive(Debug, Clone, Serialize)]
+pub struct CycleEvent {
+    pub key: String,
+    pub name: String,
+    /// Exact moment, "YYYY-MM-DD HH:MM" UTC (date only when approximate)
+    pub moment: String,
+    pub julian_day: f64,
+    /// Age at the milestone in years
+    pub age: f64,
+    /// Estimated from the mean orbital period instead of the ephemeris
+    pub approximate: bool,
+}
+
+/// Planet of one member activating a gate of a composite channel
+#[derive(Debug, Clone, Serialize)]
+pub struct MemberActivator {
+    pub member: String,
+    pub planet: String,
+    pub planet_symbol: String,
+    /// "personality" or "design"
+    pub side: String,
+    pub line: u8,
+}
+
+/// Members supplying one gate of a composite channel
+#[derive(Debug, Clone, Serialize)]
+pub struct GateContribution {
+    pub gate: u8,
+    pub members: Vec<String>,
+    /// Every planet activating the gate, by member
+    pub activators: Vec<MemberActivator>,
+}
+
+/// Gates of a composite channel one member supplies
+#[derive(Debug, Clone, Serialize)]
+pub struct MemberSupply {
+    pub member: String,
+    pub gates: Vec<u8>,
+}
+
+/// Channel defined in a composite (connection / group) chart
+#[derive(Debug, Clone, Serialize)]
+pub struct CompositeChannel {
+    pub key: String,
+    pub name: String,
+    /// companionship / dominance / compromise / electromagnetic
+    pub kind: String,
+    /// Members who have the full channel in their own chart
+    pub owners: Vec<String>,
+    pub gates: Vec<GateContribution>,
+    /// Per member, in member order: the channel gates that member supplies
+    pub supply: Vec<MemberSupply>,
+}
+
+/// One person's contribution to a composite chart
+#[derive(Debug, Clone, Serialize)]
+pub struct CompositeMember {
+    pub name: String,
+    pub gates: Vec<u8>,
+    pub defined_centers: Vec<String>,
+    pub own_channels: Vec<String>,
+    /// Channels completed only together with other members
+    pub bridged_channels: Vec<String>,
+}
+
+/// Aggregate bodygraph of two or more people
+#[derive(Debug, Clone, Serialize)]
+pub struct CompositeChart {
+    pub members: Vec<CompositeMember>,
+    #[serde(rename = "type")]
+    pub hd_type: String,
+    pub defined_centers: Vec<String>,
+    pub open_centers: Vec<String>,
+    pub channels: Vec<CompositeChannel>,
+}
+
+/// Planet position on the Dream Rave wheel
+#[derive(Debug, Clone, Serialize)]
+pub struct DreamActivation {
+    pub planet: String,
+    pub planet_symbol: String,
+    /// "personality" or "design"
+    pub side: String,
+    pub dream_gate: u8,
+    pub center: String,
+}
+
+/// Dream center with its activated dream gates
+#[derive(Debug, Clone, Serialize)]
+pub struct DreamCenter {
+    pub key: String,
+    pub name: String,
+    pub defined: bool,
+    pub gates: Vec<u8>,
+}
+
+/// Dream Rave (sleep) bodygraph of a birth moment
+#[derive(Debug, Clone, Serialize)]
+pub struct DreamRaveChart {
+    /// Birth moment in UTC
+    pub moment: String,
+    pub activations: Vec<DreamActivation>,
+    pub centers: Vec<DreamCenter>,
+    /// Defined dream channels, e.g. "3-4"
+    pub channels: Vec<String>,
+}
+
+/// One sphere of the Gene Keys Activation Sequence
+#[derive(Debug, Clone, Serialize)]
+pub struct GeneKeySphere {
+    /// lifes_work, evolution, radiance or purpose
+    pub key: String,
+    pub name: String,
+    pub planet: String,
+    /// "personality" or "design"
+    pub side: String,
+    pub gate: u8,
+    pub line: u8,
+    /// Frequency names of the key: Shadow, Gift and Siddhi
+    pub shadow: String,
+    pub gift: String,
+    pub siddhi: String,
+}
+
+/// Gene Keys Activation Sequence: the four prime gates of a birth moment
+#[derive(Debug, Clone, Serialize)]
+pub struct GeneKeysChart {
+    /// Birth moment in UTC
+    pub moment: String,
+    pub spheres: Vec<GeneKeySphere>,
+}
+
+/// Raw longitude of one body (`ephemeris` command)
+#[derive(Debug, Clone, Serialize)]
+pub struct EphemerisBody {
+    pub planet: String,
+    pub planet_symbol: String,
+    /// Geocentric ecliptic longitude, degrees
+    pub longitude: f64,
+    /// Degrees per day, negative while retrograde
+    pub speed_deg_per_day: f64,
+    /// Gate and line on the HD wheel (only with --gates)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gate: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<u8>,
+}
+
+/// Planetary longitudes at one moment, straight from the engine, without a chart
+#[derive(Debug, Clone, Serialize)]
+pub struct Ephemeris {
+    /// Moment in UTC
+    pub moment: String,
+    pub julian_day: f64,
+    pub engine: String,
+    /// ΔT applied to the moment, seconds (0 with --no-delta-t)
+    pub delta_t_seconds: f64,
+    /// Apparent (nutation and aberration applied) or true geometric positions
+    pub apparent: bool,
+    pub bodies: Vec<EphemerisBody>,
+}
+
+/// Penta gate with the group members who carry it
+#[derive(Debug, Clone, Serialize)]
+pub struct PentaGate {
+    pub gate: u8,
+    pub role: String,
+    pub members: Vec<String>,
+}
+
+/// Penta channel as formed by the group
+#[derive(Debug, Clone, Serialize)]
+pub struct PentaChannel {
+    pub key: String,
+    /// "upper" or "lower"
+    pub part: String,
+    pub role: String,
+    /// "defined" (both gates in the group), "gap" (one gate) or "missing"
+    pub status: String,
+    pub gates: Vec<PentaGate>,
+}
+
+/// Penta analysis of a 3–5 person group
+#[derive(Debug, Clone, Serialize)]
+pub struct PentaReport {
+    pub members: Vec<String>,
+    pub channels: Vec<PentaChannel>,
+    /// Roles of the Penta gates no member carries
+    pub missing_roles: Vec<String>,
+    pub dynamics: Vec<String>,
+}
+
+/// Connection between two people in a profile matrix
+#[derive(Debug, Clone, Serialize)]
+pub struct MatrixPair {
+    pub a: String,
+    pub b: String,
+    pub electromagnetic: usize,
+    pub dominance: usize,
+    pub compromise: usize,
+    pub companionship: usize,
+    /// Type of the pair's composite chart
+    #[serde(rename = "type")]
+    pub hd_type: String,
+    /// Number of centers defined in the composite
+    pub defined_centers: usize,
+}
+
+/// Pairwise connection overview of several people
+#[derive(Debug, Clone, Serialize)]
+pub struct ProfileMatrix {
+    pub members: Vec<String>,
+    pub pairs: Vec<MatrixPair>,
+}
+
+impl ProfileMatrix {
+    /// Pair summary regardless of order; `None` for the same person
+    pub fn pair(&self, a: &str, b: &str) -> Option<&MatrixPair> {
+        self.pairs
+            .iter()
+            .find(|p| (p.a == a && p.b == b) || (p.a == b && p.b == a))
+    }
+}
@@ -0,0 +1,104 @@
+use crate::astro_calc::{self, Calendar};
+use crate::cli;
+use directories::ProjectDirs;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+/// Birth data saved under a short name (e.g. "me", "mom"). The zone is kept as
+/// entered (`--utc`, `--tz` or `--place`) and resolved to an offset at use time
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SavedProfile {
+    pub date: String,
+    pub time: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub utc: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tz: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub place: Option<String>,
+}
+
+impl SavedProfile {
+    /// Birth Julian Day, with the offset of the saved zone at the birth date and time
+    pub fn julian_day(&self, calendar: Calendar) -> Result<f64, String> {
+        let date = cli::parse_date(&self.date)?;
+        let time = cli::parse_time(&self.time)?;
+        let utc_offset =
+            cli::resolve_utc_offset(self.utc.as_deref(), self.tz.as_deref(), self.place.as_deref(), date, time)?;
+        Ok(astro_calc::calc_julian_day(date, time, utc_offset, calendar))
+    }
+
+    /// The zone as entered, e.g. "UTC+3", "Europe/Berlin" or "Berlin, Germany"
+    pub fn zone_label(&self) -> String {
+        match (&self.utc, &self.tz, &self.place) {
+            (Some(utc), _, _) => format!("UTC{}", utc),
+            (None, Some(tz), _) => tz.clone(),
+            (None, None, Some(place)) => place.clone(),
+            (None, None, None) => String::new(),
+        }
+    }
+}
+
+/// Saved profiles, persisted next to the config file
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct ProfileStore {
+    pub profiles: BTreeMap<String, SavedProfile>,
+}
+
+impl ProfileStore {
+    /// Load profiles from file or return an empty store
+    pub fn load() -> Self {
+        if let Some(path) = Self::get_store_path() {
+            if path.exists() {
+                if let Ok(content) = fs::read_to_string(path) {
+                    if let Ok(store) = serde_json::from_str(&content) {
+                        return store;
+                    }
+                }
+            }
+        }
+        Self::default()
+    }
+
+    /// Save profiles to file
+    pub fn save(&self) -> Result<(), String> {
+        let store_path = Self::get_store_path().ok_or("Could not determine profiles path")?;
+
+        // Ensure directory exists
+        if let Some(parent) = store_path.parent() {
+            fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+
+        let content = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        fs::write(store_path, content).map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    /// Get profile by name
+    pub fn get(&self, name: &str) -> Result<&SavedProfile, String> {
+        self.profiles
+            .get(name)
+            .ok_or_else(|| rust_i18n::t!("error.profile_not_found", name = name).to_string())
+    }
+
+    /// Add or replace a profile
+    pub fn add(&mut self, name: &str, profile: SavedProfile) -> Result<(), String> {
+        self.profiles.insert(name.to_string(), profile);
+        self.save()
+    }
+
+    /// Remove a profile
+    pub fn remove(&mut self, name: &str) -> Result<(), String> {
+        if self.profiles.remove(name).is_none() {
+            return Err(rust_i18n::t!("error.profile_not_found", name = name).to_string());
+        }
+        self.save()
+    }
+
+    fn get_store_path() -> Option<PathBuf> {
+        ProjectDirs::from("com", "nimblemo", "hd-cli")
+            .map(|proj_dirs| proj_dirs.config_dir().join("profiles.json"))
+    }
+}
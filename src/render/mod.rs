@@ -0,0 +1,2608 @@
+/// Pretty terminal report for a chart (tables, colors, wrapped descriptions)
+use crate::models::{HdChart, MoonPhase, ResearchSettings, TimeShift};
+use style::*;
+use comfy_table::{
+    presets, Attribute, Cell, Color as TableColor, ColumnConstraint, ContentArrangement, Table,
+};
+use std::fmt::Write;
+use terminal_size::{terminal_size, Width};
+use textwrap::{Options, WordSplitter};
+use unicode_width::UnicodeWidthStr;
+
+mod reports;
+pub(crate) mod style;
+
+pub use reports::{
+    render_composite, render_cycles, render_dreamrave, render_ephemeris, render_genekeys,
+    render_lunar_cycle, render_matrix, render_penta, render_planner, render_solar_returns,
+    render_sun_ingresses, render_transit,
+};
+
+/// Which report sections to render
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Section {
+    MainInfo,
+    Bodygraph,
+    Business,
+    Channels,
+    Planets,
+    ActiveGates,
+    Activations,
+    Emphasis,
+    Nodes,
+    Centers,
+    Fear,
+    Sexuality,
+    Love,
+    Extra,
+    Circuits,
+    AdvancedDepth,
+    HangingGates,
+}
+
+impl Section {
+    /// Top-level `HdChart` JSON keys carrying this section's data; birth date,
+    /// time and offset belong to no section and are always kept
+    pub fn json_keys(&self) -> &'static [&'static str] {
+        match self {
+            Section::MainInfo => &[
+                "personality_jd",
+                "design_jd",
+                "design_utc",
+                "moon_phase",
+                "summary",
+                "life_cycle",
+                "time_sensitivity",
+                "type",
+                "type_key",
+                "type_description",
+                "profile",
+                "profile_key",
+                "profile_description",
+                "personality_line",
+                "design_line",
+                "authority",
+                "authority_key",
+                "authority_description",
+                "strategy",
+                "strategy_description",
+                "signature",
+                "signature_description",
+                "not_self_theme",
+                "not_self_description",
+                "definition",
+                "definition_key",
+                "bridges",
+                "incarnation_cross",
+                "cross_description",
+                "cross_gates",
+                "research",
+                "sidereal",
+            ],
+            Section::Bodygraph => &[],
+            Section::Business => &["business"],
+            Section::Channels => &["channels"],
+            Section::Planets => &["personality", "design"],
+            Section::ActiveGates => &["active_gates"],
+            Section::Activations => &["activations"],
+            Section::Emphasis => &["emphasis"],
+            Section::Nodes => &["nodes"],
+            Section::Centers => &["centers"],
+            Section::Fear => &["fear", "awareness_streams"],
+            Section::Sexuality => &["sexuality"],
+            Section::Love => &["love"],
+            Section::Extra => &[
+                "variable",
+                "cognition",
+                "motivation",
+                "environment",
+                "diet",
+                "vision",
+            ],
+            Section::Circuits => &["circuitry", "circuit_scores"],
+            Section::AdvancedDepth => &["advanced_depth"],
+            Section::HangingGates => &["hanging_gates"],
+        }
+    }
+}
+
+/// Layout of the channels table
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum ChannelGrouping {
+    /// One flat table
+    #[default]
+    None,
+    /// Grouped by circuit and sub-circuit
+    Circuit,
+    /// Grouped by center (a channel appears under both of its centers)
+    Center,
+}
+
+/// Row order of the activations table
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum ActivationOrder {
+    /// Personality then Design, each in planet order
+    #[default]
+    Side,
+    /// By planet, Personality and Design side by side
+    Planet,
+    /// By gate and line
+    Gate,
+}
+
+/// How zodiac positions are written
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum AngleFormat {
+    /// Decimal degrees: 15.62°
+    #[default]
+    Decimal,
+    /// Degrees, arc minutes and seconds: 15°37'22"
+    Dms,
+}
+
+/// An angle in degrees written in the given format, for every renderer of positions
+pub fn format_angle(degrees: f64, format: AngleFormat) -> String {
+    match format {
+        AngleFormat::Decimal => format!("{:.2}°", degrees),
+        AngleFormat::Dms => {
+            let seconds = (degrees * 3600.0).round() as i64;
+            format!("{}°{:02}'{:02}\"", seconds / 3600, seconds / 60 % 60, seconds % 60)
+        }
+    }
+}
+
+/// What the attached console can display
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConsoleSupport {
+    /// ANSI escape sequences are interpreted
+    pub ansi: bool,
+    /// UTF-8 output (box drawing, symbols) is displayed correctly
+    pub unicode: bool,
+}
+
+impl ConsoleSupport {
+    /// Prepare the console for rendering. Windows consoles get virtual terminal
+    /// processing and the UTF-8 code page switched on; what couldn't be enabled
+    /// (old cmd.exe / conhost) is reported as unsupported. Other platforms support both.
+    pub fn init() -> Self {
+        #[cfg(windows)]
+        {
+            #[link(name = "kernel32")]
+            extern "system" {
+                fn SetConsoleOutputCP(code_page: u32) -> i32;
+            }
+            const CP_UTF8: u32 = 65001;
+
+            let ansi = colored::control::set_virtual_terminal(true).is_ok();
+            // SAFETY: plain Win32 call without pointers; fails harmlessly without a console
+            let unicode = unsafe { SetConsoleOutputCP(CP_UTF8) } != 0;
+            ConsoleSupport { ansi, unicode }
+        }
+        #[cfg(not(windows))]
+        {
+            ConsoleSupport {
+                ansi: true,
+                unicode: true,
+            }
+        }
+    }
+}
+
+/// Color capability of the terminal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorDepth {
+    /// 24-bit RGB escapes
+    TrueColor,
+    /// xterm 256-color palette
+    Ansi256,
+}
+
+impl ColorDepth {
+    /// Truecolor when COLORTERM says so, 256 colors otherwise
+    pub fn detect() -> Self {
+        match std::env::var("COLORTERM").as_deref() {
+            Ok("truecolor") | Ok("24bit") => ColorDepth::TrueColor,
+            _ => ColorDepth::Ansi256,
+        }
+    }
+
+    /// Rewrite RGB color escapes in rendered output to this depth
+    pub fn adapt(&self, text: &str) -> String {
+        match self {
+            ColorDepth::TrueColor => text.to_string(),
+            ColorDepth::Ansi256 => rgb_escapes_to_256(text),
+        }
+    }
+}
+
+/// ASCII abbreviations of the planet and zodiac glyphs
+const ASCII_GLYPHS: &[(char, &str)] = &[
+    ('☉', "Su"),
+    ('⊕', "Ea"),
+    ('☾', "Mo"),
+    ('☊', "NN"),
+    ('☋', "SN"),
+    ('☿', "Me"),
+    ('♀', "Ve"),
+    ('♂', "Ma"),
+    ('♃', "Ju"),
+    ('♄', "Sa"),
+    ('♅', "Ur"),
+    ('♆', "Ne"),
+    ('♇', "Pl"),
+    ('⚷', "Ch"),
+    ('⚸', "Lil"),
+    ('♈', "Ar"),
+    ('♉', "Ta"),
+    ('♊', "Ge"),
+    ('♋', "Cn"),
+    ('♌', "Le"),
+    ('♍', "Vi"),
+    ('♎', "Li"),
+    ('♏', "Sc"),
+    ('♐', "Sg"),
+    ('♑', "Cp"),
+    ('♒', "Aq"),
+    ('♓', "Pi"),
+];
+
+/// Text with ASCII symbols (`--ascii`): planet and zodiac glyphs become their
+/// abbreviations ("☉" → "Su", "♉" → "Ta"); activation and definition markers, the
+/// retrograde sign, arrows, dashes and tree lines become one character each.
+/// Glyphs inside table cells must be replaced before the table is laid out
+pub fn ascii_symbols(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        if let Some((_, abbr)) = ASCII_GLYPHS.iter().find(|(glyph, _)| *glyph == c) {
+            out.push_str(abbr);
+            continue;
+        }
+        out.push(match c {
+            '●' | '•' | '★' => '*',
+            '○' => 'o',
+            '℞' => 'R',
+            '▲' => '^',
+            '▼' => 'v',
+            '▶' | '→' => '>',
+            '↔' | '—' | '–' | '─' => '-',
+            '═' => '=',
+            '│' | '║' => '|',
+            '└' => '+',
+            _ => c,
+        });
+    }
+    out
+}
+
+/// Replace `38;2;r;g;b` / `48;2;r;g;b` parameters in SGR escapes with the nearest
+/// `38;5;n` / `48;5;n` palette entry
+fn rgb_escapes_to_256(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("\x1b[") {
+        out.push_str(&rest[..start + 2]);
+        rest = &rest[start + 2..];
+        let Some(end) = rest.find(|c: char| !(c.is_ascii_digit() || c == ';')) else {
+            break;
+        };
+        if !rest[end..].starts_with('m') {
+            continue;
+        }
+        let params: Vec<&str> = rest[..end].split(';').collect();
+        let mut mapped: Vec<String> = Vec::with_capacity(params.len());
+        let mut i = 0;
+        while i < params.len() {
+            let is_rgb = matches!(params[i], "38" | "48")
+                && params.get(i + 1) == Some(&"2")
+                && i + 4 < params.len();
+            if is_rgb {
+                let channel = |k: usize| params[i + k].parse::<u8>().unwrap_or(0);
+                let index = nearest_ansi256(channel(2), channel(3), channel(4));
+                mapped.push(format!("{};5;{}", params[i], index));
+                i += 5;
+            } else {
+                mapped.push(params[i].to_string());
+                i += 1;
+            }
+        }
+        out.push_str(&mapped.join(";"));
+        rest = &rest[end..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Closest xterm-256 color (6×6×6 cube or grayscale ramp) to an RGB value
+pub fn nearest_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    const LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+    let nearest_level = |v: u8| {
+        (0..LEVELS.len())
+            .min_by_key(|&i| (LEVELS[i] as i32 - v as i32).abs())
+            .unwrap()
+    };
+    let dist = |(r2, g2, b2): (u8, u8, u8)| {
+        let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2);
+        d(r, r2) + d(g, g2) + d(b, b2)
+    };
+
+    let (ri, gi, bi) = (nearest_level(r), nearest_level(g), nearest_level(b));
+    let cube = (LEVELS[ri], LEVELS[gi], LEVELS[bi]);
+    let cube_index = 16 + 36 * ri + 6 * gi + bi;
+
+    // Grayscale ramp 232..=255: 8, 18, ..., 238
+    let avg = (r as u32 + g as u32 + b as u32) / 3;
+    let gray_step = ((avg.saturating_sub(8) + 5) / 10).min(23) as u8;
+    let gray_value = 8 + gray_step * 10;
+    let gray = (gray_value, gray_value, gray_value);
+
+    if dist(gray) < dist(cube) {
+        232 + gray_step
+    } else {
+        cube_index as u8
+    }
+}
+
+/// Options for the pretty chart report
+#[derive(Debug, Clone)]
+pub struct RenderOptions {
+    /// Output width in columns; `None` uses the terminal width (80 if unknown)
+    pub width: Option<usize>,
+    /// ANSI colors
+    pub color: bool,
+    /// ASCII table borders and rules instead of box drawing
+    pub ascii: bool,
+    /// ASCII abbreviations and markers instead of planet, zodiac and marker glyphs
+    pub ascii_symbols: bool,
+    /// Sections to render; empty renders all of them
+    pub sections: Vec<Section>,
+    /// Sections left out even when listed in `sections`
+    pub excluded_sections: Vec<Section>,
+    /// Wrapping of description paragraphs
+    pub wrap: WrapOptions,
+    /// Grouping of the channels table
+    pub channel_grouping: ChannelGrouping,
+    /// Row order of the activations table
+    pub activation_order: ActivationOrder,
+    /// Decimal degrees or degrees/minutes/seconds for zodiac positions
+    pub angle_format: AngleFormat,
+    /// Numbered section headers and a table of contents up front
+    pub toc: bool,
+    /// URL template for gate and channel hyperlinks (`{kind}` = gate/channel,
+    /// `{id}` = gate number or channel key); only used with colors enabled
+    pub link_template: Option<String>,
+}
+
+/// How description paragraphs are wrapped
+#[derive(Debug, Clone, Copy)]
+pub struct WrapOptions {
+    /// Maximum paragraph width, independent of the terminal width
+    pub max_width: Option<usize>,
+    /// Indent of top-level descriptions; nested ones get two more columns
+    pub indent: usize,
+    /// Break words longer than the line width
+    pub break_words: bool,
+    /// Allow line breaks after hyphens inside words
+    pub split_hyphens: bool,
+}
+
+impl Default for WrapOptions {
+    fn default() -> Self {
+        Self {
+            max_width: None,
+            indent: 4,
+            break_words: true,
+            split_hyphens: true,
+        }
+    }
+}
+
+/// Resolved width, wrap and hyperlink settings for description text
+#[derive(Debug, Clone, Copy)]
+struct TextLayout<'a> {
+    width: usize,
+    wrap: WrapOptions,
+    /// Hyperlink URL template, only set for terminal (colored) output
+    links: Option<&'a str>,
+}
+
+impl TextLayout<'_> {
+    /// Reference URL for a gate or channel (`kind` is "gate" or "channel")
+    fn link_url(&self, kind: &str, id: &str) -> Option<String> {
+        self.links
+            .map(|template| template.replace("{kind}", kind).replace("{id}", id))
+    }
+
+    /// `text` as a clickable reference when hyperlinks are enabled
+    fn link(&self, text: &str, kind: &str, id: &str) -> String {
+        match self.link_url(kind, id) {
+            Some(url) => hyperlink(text, &url),
+            None => text.to_string(),
+        }
+    }
+}
+
+/// Wrap `text` in an OSC-8 terminal hyperlink
+fn hyperlink(text: &str, url: &str) -> String {
+    format!("\x1b]8;;{}\x1b\\{}\x1b]8;;\x1b\\", url, text)
+}
+
+/// Turn every occurrence of the given texts into hyperlinks in an already laid out
+/// table (escape sequences inside cells would break comfy-table's width calculation)
+fn link_rendered(rendered: &str, links: &[(String, String)]) -> String {
+    let mut out = String::with_capacity(rendered.len());
+    let mut rest = rendered;
+    // Earliest match first (longest text on ties), so inserted URLs are never rescanned
+    while let Some((pos, text, url)) = links
+        .iter()
+        .filter(|(text, _)| !text.is_empty())
+        .filter_map(|(text, url)| rest.find(text.as_str()).map(|pos| (pos, text, url)))
+        .min_by_key(|(pos, text, _)| (*pos, std::cmp::Reverse(text.len())))
+    {
+        out.push_str(&rest[..pos]);
+        out.push_str(&hyperlink(text, url));
+        rest = &rest[pos + text.len()..];
+    }
+    out.push_str(rest);
+    out
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        Self {
+            width: None,
+            color: true,
+            ascii: false,
+            ascii_symbols: false,
+            sections: Vec::new(),
+            excluded_sections: Vec::new(),
+            wrap: WrapOptions::default(),
+            channel_grouping: ChannelGrouping::None,
+            activation_order: ActivationOrder::Side,
+            angle_format: AngleFormat::Decimal,
+            toc: false,
+            link_template: None,
+        }
+    }
+}
+
+impl RenderOptions {
+    pub fn resolved_width(&self) -> usize {
+        self.width.unwrap_or_else(|| {
+            if let Some((Width(w), _)) = terminal_size() {
+                w as usize
+            } else {
+                80
+            }
+        })
+    }
+
+    fn text_layout(&self) -> TextLayout<'_> {
+        let width = self.resolved_width();
+        TextLayout {
+            width: self.wrap.max_width.map_or(width, |max| max.min(width)),
+            wrap: self.wrap,
+            links: self.link_template.as_deref().filter(|_| self.color),
+        }
+    }
+
+    /// Cell or line text with ASCII symbols when `ascii_symbols` is set
+    pub fn symbols(&self, text: &str) -> String {
+        if self.ascii_symbols {
+            ascii_symbols(text)
+        } else {
+            text.to_string()
+        }
+    }
+
+    pub fn shows(&self, section: Section) -> bool {
+        (self.sections.is_empty() || self.sections.contains(&section))
+            && !self.excluded_sections.contains(&section)
+    }
+}
+
+/// Left-align `text` to `width` terminal columns (CJK characters take two)
+pub fn pad_to_width(text: &str, width: usize) -> String {
+    let padding = width.saturating_sub(text.width());
+    format!("{}{}", text, " ".repeat(padding))
+}
+
+/// Table with the report's common preset, sized to the render width
+/// comfy-table preset: box drawing, or plain ASCII for pipes and old consoles
+pub fn table_preset(ascii: bool) -> &'static str {
+    if ascii {
+        presets::ASCII_FULL
+    } else {
+        presets::UTF8_FULL
+    }
+}
+
+/// Horizontal rule framing report headers
+pub fn header_rule(ascii: bool) -> &'static str {
+    if ascii {
+        "==============================================================="
+    } else {
+        "═══════════════════════════════════════════════════════════════"
+    }
+}
+
+/// Non-standard settings a chart was calculated with, e.g. "Design arc 87.5° (standard 88°)"
+pub fn research_settings_text(research: &ResearchSettings) -> String {
+    let mut parts = Vec::new();
+    if research.design_arc != crate::astro_calc::DEFAULT_DESIGN_ARC {
+        parts.push(rust_i18n::t!(
+            "cli.research.design_arc",
+            arc = research.design_arc,
+            standard = crate::astro_calc::DEFAULT_DESIGN_ARC
+        ));
+    }
+    if research.wheel_offset != 0.0 {
+        parts.push(rust_i18n::t!(
+            "cli.research.wheel_offset",
+            offset = format!("{:+.2}", research.wheel_offset),
+            start = format!("{:.2}", crate::data::gates::WHEEL_START_DEGREE + research.wheel_offset)
+        ));
+    }
+    if research.true_node {
+        parts.push(rust_i18n::t!("cli.research.true_node"));
+    }
+    if let Some(ref ayanamsa) = research.ayanamsa {
+        let ayanamsa_key = format!("cli.sidereal.ayanamsa.{}", ayanamsa);
+        parts.push(rust_i18n::t!(
+            "cli.research.sidereal_wheel",
+            ayanamsa = rust_i18n::t!(&ayanamsa_key)
+        ));
+    }
+    parts.join(", ")
+}
+
+/// "Waxing Gibbous (142.3°, 89.5% illuminated)"
+pub fn moon_phase_text(phase: &MoonPhase) -> String {
+    rust_i18n::t!(
+        "cli.moon.value",
+        name = phase.name,
+        angle = phase.angle,
+        illumination = phase.illumination
+    )
+    .to_string()
+}
+
+/// Report palette: table headers, key values, plain values and rules
+const TC_CORAL: TableColor = TableColor::Rgb { r: 255, g: 160, b: 122 };
+const TC_GOLD: TableColor = TableColor::Rgb { r: 255, g: 215, b: 0 };
+const TC_BEIGE: TableColor = TableColor::Rgb { r: 230, g: 228, b: 208 };
+
+/// Table cell in `color` (and bold) unless colors are off
+fn styled_cell(cell: Cell, color: TableColor, bold: bool, opts: &RenderOptions) -> Cell {
+    if !opts.color {
+        return cell;
+    }
+    let cell = cell.fg(color);
+    if bold {
+        cell.add_attribute(Attribute::Bold)
+    } else {
+        cell
+    }
+}
+
+/// Turn `colored` styling off for the whole process when colors are off
+fn apply_color_choice(opts: &RenderOptions) {
+    if !opts.color {
+        colored::control::set_override(false);
+    }
+}
+
+fn new_table(opts: &RenderOptions) -> Table {
+    let mut table = Table::new();
+    table
+        .load_preset(table_preset(opts.ascii))
+        .set_content_arrangement(ContentArrangement::Dynamic);
+    if opts.color {
+        // Colors were requested explicitly or stdout is a terminal
+        table.enforce_styling();
+    }
+    if let Some(w) = opts.width {
+        table.set_width(w as u16);
+    }
+    table
+}
+
+/// Render the full chart report as a string
+pub fn render_chart(chart: &HdChart, opts: &RenderOptions) -> String {
+    let mut out = String::new();
+    let layout = opts.text_layout();
+
+    apply_color_choice(opts);
+
+    // Header
+    writeln!(
+        out,
+        "\n{}",
+        header_rule(opts.ascii).truecolor(95, 158, 160)
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "      {}",
+        rust_i18n::t!("cli.header").truecolor(255, 255, 255).bold()
+    )
+    .unwrap();
+    let summary = &chart.summary;
+    writeln!(
+        out,
+        "      {}",
+        rust_i18n::t!(
+            "cli.summary",
+            centers = summary.defined_centers,
+            channels = summary.channels,
+            gates = summary.gates,
+            conscious = summary.conscious_gates,
+            unconscious = summary.unconscious_gates
+        )
+        .truecolor(255, 215, 0)
+    )
+    .unwrap();
+    if let Some(ref cycle) = chart.life_cycle {
+        writeln!(
+            out,
+            "      {}",
+            rust_i18n::t!(
+                "cli.cycle.value",
+                age = cycle.age,
+                name = cycle.name,
+                note = cycle.note
+            )
+            .truecolor(230, 228, 208)
+        )
+        .unwrap();
+    }
+    if let Some(ref sidereal) = chart.sidereal {
+        let wheel_key = if sidereal.wheel {
+            "cli.sidereal.wheel_sidereal"
+        } else {
+            "cli.sidereal.wheel_tropical"
+        };
+        let ayanamsa_key = format!("cli.sidereal.ayanamsa.{}", sidereal.ayanamsa);
+        writeln!(
+            out,
+            "      {}",
+            rust_i18n::t!(
+                "cli.sidereal.flag",
+                ayanamsa = rust_i18n::t!(&ayanamsa_key),
+                degrees = format!("{:.2}", sidereal.ayanamsa_degrees),
+                wheel = rust_i18n::t!(wheel_key)
+            )
+            .truecolor(220, 60, 60)
+        )
+        .unwrap();
+    }
+    if let Some(ref research) = chart.research {
+        writeln!(
+            out,
+            "      {} {}",
+            rust_i18n::t!("cli.research.title").truecolor(220, 60, 60).bold(),
+            research_settings_text(research).truecolor(220, 60, 60)
+        )
+        .unwrap();
+    }
+    writeln!(
+        out,
+        "{}",
+        header_rule(opts.ascii).truecolor(95, 158, 160)
+    )
+    .unwrap();
+
+    // Sections render into their own buffers so empty ones can be skipped and
+    // the rest numbered for the table of contents
+    let mut sections: Vec<(String, String)> = Vec::new();
+    let mut add_section = |title: std::borrow::Cow<str>, write: &dyn Fn(&mut String)| {
+        let mut body = String::new();
+        write(&mut body);
+        if !body.is_empty() {
+            sections.push((title.to_string(), body));
+        }
+    };
+
+    if opts.shows(Section::MainInfo) {
+        add_section(rust_i18n::t!("cli.section.main_info"), &|o| {
+            write_main_info(o, chart, layout)
+        });
+    }
+
+    if opts.shows(Section::Bodygraph) {
+        add_section(rust_i18n::t!("cli.section.bodygraph"), &|o| {
+            let drawing = crate::bodygraph::render_bodygraph(chart, opts);
+            if !drawing.is_empty() {
+                writeln!(o, "\n{}", drawing).unwrap();
+            }
+        });
+    }
+
+    // Business
+    if let (true, Some(ref biz)) = (opts.shows(Section::Business), &chart.business) {
+        add_section(rust_i18n::t!("cli.section.business"), &|o| {
+            write_gate_section_items(o, biz, layout)
+        });
+    }
+
+    if opts.shows(Section::Channels) {
+        add_section(rust_i18n::t!("cli.section.channels"), &|o| {
+            write_channels(o, &chart.channels, &chart.centers, opts)
+        });
+    }
+
+    if opts.shows(Section::Planets) {
+        add_section(rust_i18n::t!("cli.section.planets"), &|o| {
+            write_combined_planet_table(o, &chart.design, &chart.personality, opts)
+        });
+    }
+
+    // Active gates with their activating planets
+    if opts.shows(Section::ActiveGates) {
+        add_section(rust_i18n::t!("cli.section.active_gates"), &|o| {
+            write_active_gates(o, &chart.active_gates, opts)
+        });
+    }
+    if opts.shows(Section::Activations) && !chart.activations.is_empty() {
+        add_section(rust_i18n::t!("cli.section.activations"), &|o| {
+            write_activations(o, &chart.activations, opts)
+        });
+    }
+    if opts.shows(Section::Emphasis) {
+        add_section(rust_i18n::t!("cli.section.emphasis"), &|o| {
+            write_gate_emphasis(o, &chart.emphasis)
+        });
+    }
+
+    if opts.shows(Section::HangingGates) && !chart.hanging_gates.is_empty() {
+        add_section(rust_i18n::t!("cli.section.hanging_gates"), &|o| {
+            write_hanging_gates(o, &chart.hanging_gates)
+        });
+    }
+
+    // Nodes (life theme)
+    if opts.shows(Section::Nodes) {
+        add_section(rust_i18n::t!("cli.section.nodes"), &|o| {
+            write_node_themes(o, &chart.nodes, layout)
+        });
+    }
+
+    if opts.shows(Section::Centers) {
+        add_section(rust_i18n::t!("cli.section.centers"), &|o| {
+            write_centers(o, &chart.centers, opts)
+        });
+    }
+
+    // Additional information
+    let has_extra = chart.variable.is_some()
+        || chart.cognition.is_some()
+        || chart.motivation.is_some()
+        || chart.environment.is_some()
+        || chart.diet.is_some()
+        || chart.vision.is_some();
+
+    // Fear Section, with the awareness streams the fears belong to
+    if opts.shows(Section::Fear) {
+        add_section(rust_i18n::t!("cli.section.fear"), &|o| {
+            if let Some(ref items) = chart.fear {
+                write_gate_section_items(o, items, layout);
+            }
+            if let Some(ref streams) = chart.awareness_streams {
+                write_awareness_streams(o, streams, opts);
+            }
+        });
+    }
+
+    // Sexuality Section
+    if let (true, Some(ref items)) = (opts.shows(Section::Sexuality), &chart.sexuality) {
+        add_section(rust_i18n::t!("cli.section.sexuality"), &|o| {
+            write_gate_section_items(o, items, layout)
+        });
+    }
+
+    // Love Section
+    if let (true, Some(ref items)) = (opts.shows(Section::Love), &chart.love) {
+        add_section(rust_i18n::t!("cli.section.love"), &|o| {
+            write_gate_section_items(o, items, layout)
+        });
+    }
+
+    if has_extra && opts.shows(Section::Extra) {
+        add_section(rust_i18n::t!("cli.section.extra"), &|o| {
+            writeln!(o).unwrap(); // Spacing
+
+            if let Some(ref v) = chart.variable {
+                write_variable(o, v, layout);
+            }
+            if let Some(ref c) = chart.cognition {
+                let item = crate::models::InfoItem {
+                    label: format!("{} {}: {}", rust_i18n::t!("cli.label.tone"), c.tone, c.name),
+                    description: c.description.clone().unwrap_or_default(),
+                    planets: None,
+                    gate_id: None,
+                    gate_name: None,
+                };
+                write_info_items(o, &rust_i18n::t!("cli.label.cognition"), &[item], layout);
+            }
+            if let Some(ref m) = chart.motivation {
+                write_info_items(o, &rust_i18n::t!("cli.label.motivation"), m, layout);
+            }
+            if let Some(ref v) = chart.vision {
+                write_info_items(o, &rust_i18n::t!("cli.label.vision"), v, layout);
+            }
+            if let Some(ref e) = chart.environment {
+                write_info_items(o, &rust_i18n::t!("cli.label.environment"), e, layout);
+            }
+            if let Some(ref d) = chart.diet {
+                write_info_items(o, &rust_i18n::t!("cli.label.diet"), d, layout);
+            }
+        });
+    }
+
+    let scores = chart.circuit_scores.as_ref().filter(|s| !s.is_empty());
+    if opts.shows(Section::Circuits) && (chart.circuitry.is_some() || scores.is_some()) {
+        add_section(rust_i18n::t!("cli.section.circuits"), &|o| {
+            if let Some(ref shares) = chart.circuitry {
+                write_circuitry(o, shares, layout);
+            }
+            if let Some(scores) = scores {
+                write_circuit_scores_table(o, scores, opts);
+            }
+        });
+    }
+
+    if let (true, Some(ref items)) = (opts.shows(Section::AdvancedDepth), &chart.advanced_depth) {
+        add_section(rust_i18n::t!("cli.section.advanced_depth"), &|o| {
+            write_gate_section_items(o, items, layout)
+        });
+    }
+
+    let titles: Vec<String> = sections
+        .iter()
+        .enumerate()
+        .map(|(i, (title, _))| {
+            if opts.toc {
+                format!("{}. {}", i + 1, title)
+            } else {
+                title.clone()
+            }
+        })
+        .collect();
+
+    if opts.toc {
+        write_table_of_contents(&mut out, &titles, &sections);
+    }
+
+    for (title, (_, body)) in titles.iter().zip(&sections) {
+        write_section_header(&mut out, title);
+        out.push_str(body);
+    }
+
+       opts.symbols(&out)
+}
+
+/// Section title line (teal, bold) preceded by a blank line
+fn write_section_header(out: &mut String, title: &str) {
+    writeln!(out, "\n{}", title.truecolor(95, 158, 160).bold()).unwrap();
+}
+
+/// Numbered section list with the line each section starts at
+fn write_table_of_contents(out: &mut String, titles: &[String], sections: &[(String, String)]) {
+    // Lines taken by the contents block itself: blank, title, blank, one per entry
+    let toc_lines = 3 + titles.len();
+    // A section header is a blank line followed by the title
+    let mut line = out.matches('\n').count() + toc_lines;
+    let mut entries = Vec::new();
+    for (title, (_, body)) in titles.iter().zip(sections) {
+        entries.push((title, line + 2));
+        line += 2 + body.matches('\n').count();
+    }
+
+    write_section_header(out, &rust_i18n::t!("cli.section.contents"));
+    writeln!(out).unwrap();
+    for (title, start) in entries {
+        writeln!(
+            out,
+            "  {} {}",
+            title.truecolor(255, 215, 0),
+            rust_i18n::t!("cli.label.toc_line", line = start).truecolor(230, 228, 208)
+        )
+        .unwrap();
+    }
+}
+
+/// A birth time this close to a Sun/Moon change is worth double-checking
+const SENSITIVE_MINUTES: i64 = 10;
+
+fn write_main_info(out: &mut String, chart: &HdChart, layout: TextLayout) {
+    writeln!(out).unwrap(); // Spacing
+
+    let label_color = |s: &str| s.truecolor(255, 160, 122); // Soft Coral
+    let value_color = |s: &str| s.truecolor(255, 215, 0); // Gold
+    let desc_color = colored::Color::TrueColor {
+        r: 230,
+        g: 228,
+        b: 208,
+    }; // Beige
+
+    writeln!(
+        out,
+        "  {} {} {} UTC{}",
+        label_color(&rust_i18n::t!("cli.label.date")),
+        value_color(&chart.birth_date),
+        value_color(&chart.birth_time),
+        value_color(&format!("{:+}", chart.utc_offset))
+    )
+    .unwrap();
+    // The Design moment and both Julian Days, to cross-check against other software
+    writeln!(
+        out,
+        "  {} {} UTC",
+        label_color(&rust_i18n::t!("cli.label.design_date")),
+        value_color(&chart.design_utc)
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "  {} {} {} · {} {}",
+        label_color(&rust_i18n::t!("cli.label.julian_day")),
+        label_color(&rust_i18n::t!("cli.label.personality")),
+        value_color(&format!("{:.6}", chart.personality_jd)),
+        label_color(&rust_i18n::t!("cli.label.design")),
+        value_color(&format!("{:.6}", chart.design_jd))
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "  {} {}",
+        label_color(&rust_i18n::t!("cli.label.moon_phase")),
+        value_color(&moon_phase_text(&chart.moon_phase))
+    )
+    .unwrap();
+    if let Some(ref sensitivity) = chart.time_sensitivity {
+        let shift_text = |shift: &Option<TimeShift>| match shift {
+            Some(shift) => {
+                let side_key = format!("cli.label.{}", shift.side);
+                rust_i18n::t!(
+                    "cli.sensitivity.shift",
+                    minutes = format!("{:+}", shift.minutes),
+                    planet = shift.planet,
+                    side = rust_i18n::t!(&side_key),
+                    from = shift.from,
+                    to = shift.to
+                )
+                .to_string()
+            }
+            None => rust_i18n::t!("cli.sensitivity.stable").to_string(),
+        };
+        writeln!(
+            out,
+            "  {} {} · {}",
+            label_color(&rust_i18n::t!("cli.label.sensitivity")),
+            value_color(&shift_text(&sensitivity.earlier)),
+            value_color(&shift_text(&sensitivity.later))
+        )
+        .unwrap();
+        let sensitive = [&sensitivity.earlier, &sensitivity.later]
+            .iter()
+            .flat_map(|s| s.iter())
+            .any(|s| s.minutes.abs() <= SENSITIVE_MINUTES);
+        if sensitive {
+            write_wrapped(
+                out,
+                layout,
+                &rust_i18n::t!("cli.sensitivity.warning", minutes = SENSITIVE_MINUTES),
+                0,
+                Some(desc_color),
+                false,
+            );
+        }
+    }
+    writeln!(out).unwrap(); // Empty line after Date for spacing
+
+    writeln!(
+        out,
+        "  {} {}",
+        label_color(&rust_i18n::t!("cli.label.type")),
+        value_color(&chart.hd_type).bold()
+    )
+    .unwrap();
+    if let Some(ref desc) = chart.type_description {
+        write_wrapped(out, layout, desc, 0, Some(desc_color), false);
+    }
+    writeln!(out).unwrap(); // Empty line after item
+
+    writeln!(
+        out,
+        "  {} {}",
+        label_color(&rust_i18n::t!("cli.label.profile")),
+        value_color(&chart.profile).bold()
+    )
+    .unwrap();
+    if let Some(ref desc) = chart.profile_description {
+        write_wrapped(out, layout, desc, 0, Some(desc_color), false);
+    }
+    for item in [&chart.personality_line, &chart.design_line].into_iter().flatten() {
+        writeln!(out, "    {}", label_color(&item.label)).unwrap();
+        if !item.description.is_empty() {
+            write_wrapped(out, layout, &item.description, 1, Some(desc_color), false);
+        }
+    }
+    writeln!(out).unwrap(); // Empty line after item
+
+    writeln!(
+        out,
+        "  {} {}",
+        label_color(&rust_i18n::t!("cli.label.authority")),
+        value_color(&chart.authority).bold()
+    )
+    .unwrap();
+    if let Some(ref desc) = chart.authority_description {
+        write_wrapped(out, layout, desc, 0, Some(desc_color), false);
+    }
+    writeln!(out).unwrap(); // Empty line after item
+
+    writeln!(
+        out,
+        "  {} {}",
+        label_color(&rust_i18n::t!("cli.label.strategy")),
+        value_color(&chart.strategy).bold()
+    )
+    .unwrap();
+    if let Some(ref desc) = chart.strategy_description {
+        write_wrapped(out, layout, desc, 0, Some(desc_color), false);
+    }
+    writeln!(out).unwrap(); // Empty line after item
+
+    writeln!(
+        out,
+        "  {} {}",
+        label_color(&rust_i18n::t!("cli.label.signature")),
+        value_color(&chart.signature).bold()
+    )
+    .unwrap();
+    if let Some(ref desc) = chart.signature_description {
+        write_wrapped(out, layout, desc, 0, Some(desc_color), false);
+    }
+    writeln!(
+        out,
+        "  {} {}",
+        label_color(&rust_i18n::t!("cli.label.not_self")),
+        value_color(&chart.not_self_theme).bold()
+    )
+    .unwrap();
+    if let Some(ref desc) = chart.not_self_description {
+        write_wrapped(out, layout, desc, 0, Some(desc_color), false);
+    }
+    writeln!(out).unwrap(); // Empty line after item
+
+    writeln!(
+        out,
+        "  {} {}",
+        label_color(&rust_i18n::t!("cli.label.definition")),
+        value_color(&chart.definition).bold()
+    )
+    .unwrap();
+    if let Some(ref bridges) = chart.bridges {
+        writeln!(out, "  {}", label_color(&rust_i18n::t!("cli.label.bridges"))).unwrap();
+        for bridge in bridges {
+            let gates = bridge
+                .gates
+                .iter()
+                .map(|g| g.to_string())
+                .collect::<Vec<_>>()
+                .join(" + ");
+            writeln!(
+                out,
+                "    {} {} ({}): {}",
+                value_color(&rust_i18n::t!("cli.label.gate")),
+                value_color(&gates),
+                bridge.channels.join(", "),
+                bridge
+                    .areas
+                    .iter()
+                    .map(|a| a.join(", "))
+                    .collect::<Vec<_>>()
+                    .join(" ↔ ")
+                    .truecolor(230, 228, 208)
+            )
+            .unwrap();
+        }
+    }
+    writeln!(out).unwrap(); // Empty line after item
+
+    writeln!(
+        out,
+        "  {} {}",
+        label_color(&rust_i18n::t!("cli.label.cross")),
+        value_color(&chart.incarnation_cross).bold()
+    )
+    .unwrap();
+    if let Some(ref desc) = chart.cross_description {
+        write_wrapped(out, layout, desc, 0, Some(desc_color), false);
+    }
+    if let Some(ref cross_gates) = chart.cross_gates {
+        for cg in cross_gates {
+            let side_key = format!("cli.label.{}", cg.side);
+            let gate_txt = match cg.gate_name {
+                Some(ref name) => format!(
+                    "{} {}: {}",
+                    rust_i18n::t!("cli.label.gate"),
+                    cg.gate,
+                    layout.link(name, "gate", &cg.gate.to_string())
+                ),
+                None => format!("{} {}", rust_i18n::t!("cli.label.gate"), cg.gate),
+            };
+            writeln!(
+                out,
+                "    {} - {}",
+                label_color(&format!(
+                    "{} {} ({})",
+                    cg.planet_symbol,
+                    cg.planet,
+                    rust_i18n::t!(&side_key)
+                )),
+                value_color(&gate_txt)
+            )
+            .unwrap();
+            if let Some(ref keynote) = cg.keynote {
+                write_wrapped(out, layout, keynote, 1, Some(desc_color), false);
+            }
+        }
+    }
+    writeln!(out).unwrap(); // Empty line after item
+}
+
+fn write_channels(
+    out: &mut String,
+    channels: &[crate::models::ChannelInfo],
+    centers: &[crate::models::CenterInfo],
+    opts: &RenderOptions,
+) {
+    if channels.is_empty() {
+        return;
+    }
+    let plain = !opts.color;
+
+    let add_style = |cell: Cell, color: TableColor, bold: bool| -> Cell {
+        if plain {
+            cell
+        } else {
+            let mut c = cell.fg(color);
+            if bold {
+                c = c.add_attribute(Attribute::Bold);
+            }
+            c
+        }
+    };
+    let coral = TableColor::Rgb {
+        r: 255,
+        g: 160,
+        b: 122,
+    };
+    let gold = TableColor::Rgb {
+        r: 255,
+        g: 215,
+        b: 0,
+    };
+    let teal = TableColor::Rgb {
+        r: 95,
+        g: 158,
+        b: 160,
+    };
+
+    writeln!(out).unwrap(); // Отступ
+
+    let has_descriptions = channels.iter().any(|ch| ch.description.is_some());
+    let groups = group_channels(channels, centers, opts.channel_grouping);
+
+    let mut table = new_table(opts);
+
+    let mut headers = Vec::new();
+    match opts.channel_grouping {
+        ChannelGrouping::None => {}
+        ChannelGrouping::Circuit => {
+            headers.push(add_style(Cell::new(&rust_i18n::t!("cli.label.circuit")), teal, true));
+            headers.push(add_style(
+                Cell::new(&rust_i18n::t!("cli.label.sub_circuit")),
+                teal,
+                true,
+            ));
+        }
+        ChannelGrouping::Center => {
+            headers.push(add_style(Cell::new(&rust_i18n::t!("cli.label.center")), teal, true));
+        }
+    }
+    headers.push(add_style(Cell::new(&rust_i18n::t!("cli.label.channel")), coral, true));
+    headers.push(add_style(Cell::new(&rust_i18n::t!("cli.label.name")), gold, true));
+    if has_descriptions {
+        headers.push(add_style(
+            Cell::new(&rust_i18n::t!("cli.label.description")),
+            coral,
+            true,
+        ));
+    }
+    table.set_header(headers);
+
+    for group in &groups {
+        let group_total: usize = group.subgroups.iter().map(|(_, chs)| chs.len()).sum();
+        let mut first_in_group = true;
+        for (sub_label, chs) in &group.subgroups {
+            for (i, ch) in chs.iter().enumerate() {
+                let mut row = Vec::new();
+                // Group and sub-group labels (with channel counts) only on their first row
+                match opts.channel_grouping {
+                    ChannelGrouping::None => {}
+                    ChannelGrouping::Circuit => {
+                        row.push(add_style(
+                            Cell::new(if first_in_group {
+                                format!("{} ({})", group.label, group_total)
+                            } else {
+                                String::new()
+                            }),
+                            teal,
+                            true,
+                        ));
+                        row.push(add_style(
+                            Cell::new(if i == 0 {
+                                format!("{} ({})", sub_label, chs.len())
+                            } else {
+                                String::new()
+                            }),
+                            teal,
+                            false,
+                        ));
+                    }
+                    ChannelGrouping::Center => {
+                        row.push(add_style(
+                            Cell::new(if first_in_group {
+                                format!("{} ({})", group.label, group_total)
+                            } else {
+                                String::new()
+                            }),
+                            teal,
+                            true,
+                        ));
+                    }
+                }
+                first_in_group = false;
+
+                row.push(add_style(
+                    Cell::new(channel_source_key(ch)),
+                    activation_color(&ch.awareness),
+                    false,
+                ));
+                row.push(add_style(Cell::new(&ch.name), gold, true));
+                if has_descriptions {
+                    let desc = ch.description.clone().unwrap_or_default();
+                    row.push(add_style(
+                        Cell::new(&desc),
+                        TableColor::Rgb {
+                            r: 230,
+                            g: 228,
+                            b: 208,
+                        },
+                        false,
+                    ));
+                }
+                table.add_row(row);
+            }
+        }
+    }
+    let layout = opts.text_layout();
+    let links: Vec<(String, String)> = channels
+        .iter()
+        .filter_map(|ch| Some((ch.name.clone(), layout.link_url("channel", &ch.key)?)))
+        .collect();
+    writeln!(out, "{}", link_rendered(&table.to_string(), &links)).unwrap();
+}
+
+/// Channels of one group (circuit or center), split into sub-groups
+struct ChannelGroup<'a> {
+    label: String,
+    subgroups: Vec<(String, Vec<&'a crate::models::ChannelInfo>)>,
+}
+
+/// Sub-circuit key -> (name, channels)
+type SubCircuitChannels<'a> =
+    std::collections::BTreeMap<String, (String, Vec<&'a crate::models::ChannelInfo>)>;
+
+/// Group channels for the channels table; a channel is listed under both of its
+/// centers when grouping by center
+fn group_channels<'a>(
+    channels: &'a [crate::models::ChannelInfo],
+    centers: &[crate::models::CenterInfo],
+    grouping: ChannelGrouping,
+) -> Vec<ChannelGroup<'a>> {
+    match grouping {
+        ChannelGrouping::None => vec![ChannelGroup {
+            label: String::new(),
+            subgroups: vec![(String::new(), channels.iter().collect())],
+        }],
+        ChannelGrouping::Center => centers
+            .iter()
+            .filter_map(|center| {
+                let chs: Vec<_> = channels
+                    .iter()
+                    .filter(|ch| ch.centers.contains(&center.key))
+                    .collect();
+                if chs.is_empty() {
+                    None
+                } else {
+                    Some(ChannelGroup {
+                        label: center.name.clone(),
+                        subgroups: vec![(String::new(), chs)],
+                    })
+                }
+            })
+            .collect(),
+        ChannelGrouping::Circuit => {
+            // BTreeMap keeps circuits and sub-circuits in a stable order;
+            // channels without circuit data go last
+            let mut by_circuit: std::collections::BTreeMap<
+                (bool, String),
+                (String, SubCircuitChannels),
+            > = std::collections::BTreeMap::new();
+            for ch in channels {
+                let circuit = ch.circuit.clone().unwrap_or_default();
+                let sub = ch.sub_circuit.clone().unwrap_or_default();
+                let entry = by_circuit
+                    .entry((ch.circuit.is_none(), circuit.clone()))
+                    .or_insert_with(|| {
+                        (
+                            ch.circuit_name.clone().unwrap_or_else(|| "—".to_string()),
+                            std::collections::BTreeMap::new(),
+                        )
+                    });
+                entry
+                    .1
+                    .entry(sub)
+                    .or_insert_with(|| {
+                        (
+                            ch.sub_circuit_name
+                                .clone()
+                                .unwrap_or_else(|| "—".to_string()),
+                            Vec::new(),
+                        )
+                    })
+                    .1
+                    .push(ch);
+            }
+            by_circuit
+                .into_values()
+                .map(|(label, subs)| ChannelGroup {
+                    label,
+                    subgroups: subs.into_values().collect(),
+                })
+                .collect()
+        }
+    }
+}
+
+fn write_centers(out: &mut String, centers: &[crate::models::CenterInfo], opts: &RenderOptions) {
+    let plain = !opts.color;
+
+    let add_style = |cell: Cell, color: TableColor, bold: bool| -> Cell {
+        if plain {
+            cell
+        } else {
+            let mut c = cell.fg(color);
+            if bold {
+                c = c.add_attribute(Attribute::Bold);
+            }
+            c
+        }
+    };
+
+    writeln!(out).unwrap(); // Spacing
+
+    let mut table = new_table(opts);
+
+    let has_gates = centers.iter().any(|c| c.gates.is_some());
+
+    let mut headers = vec![add_style(
+        Cell::new(&rust_i18n::t!("cli.label.center")),
+        TableColor::Rgb {
+            r: 255,
+            g: 160,
+            b: 122,
+        },
+        true,
+    )];
+    if has_gates {
+        headers.push(add_style(
+            Cell::new(&rust_i18n::t!("cli.label.gates")),
+            TableColor::Rgb {
+                r: 255,
+                g: 160,
+                b: 122,
+            },
+            true,
+        ));
+    }
+    headers.push(add_style(
+        Cell::new(&rust_i18n::t!("cli.label.status")),
+        TableColor::Rgb {
+            r: 255,
+            g: 160,
+            b: 122,
+        },
+        true,
+    ));
+    table.set_header(headers);
+    for center in centers {
+        let status = if center.defined {
+            format!("● {}", rust_i18n::t!("cli.label.defined"))
+        } else {
+            format!("○ {}", rust_i18n::t!("cli.label.open"))
+        };
+        let height_color = if center.defined {
+            TableColor::Rgb {
+                r: 255,
+                g: 215,
+                b: 0,
+            }
+        } else {
+            TableColor::DarkGrey
+        }; // Gold for defined
+        let color = height_color;
+
+        // Combine behavior descriptions if available
+        let content = if let (Some(ref norm), Some(ref dist)) =
+            (&center.behavior_normal, &center.behavior_distorted)
+        {
+            format!("{}\n\n{}", norm, dist)
+        } else if let Some(ref beh) = center
+            .behavior_normal
+            .as_ref()
+            .or(center.behavior_distorted.as_ref())
+        {
+            // Fallback if only one exists (unlikely given calc.rs logic)
+            beh.to_string()
+        } else {
+            status.to_string()
+        };
+        // Open-center analysis beneath the behavior texts
+        let mut content = content;
+        for (label, text) in [
+            ("cli.label.not_self_question", &center.not_self_question),
+            ("cli.label.wisdom", &center.wisdom),
+        ] {
+            if let Some(text) = text {
+                write!(content, "\n\n{} {}", rust_i18n::t!(label), text).unwrap();
+            }
+        }
+
+        let mut row = vec![add_style(Cell::new(&center.name), color, true)];
+        if has_gates {
+            let gates_txt = center
+                .gates
+                .as_ref()
+                .map(|gates| {
+                    gates
+                        .iter()
+                        .map(|g| {
+                            let key = format!("cli.activation.{}", g.activation);
+                            format!("{:>2} {}", g.gate, rust_i18n::t!(&key))
+                        })
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                })
+                .unwrap_or_default();
+            row.push(add_style(
+                Cell::new(&gates_txt),
+                TableColor::Rgb {
+                    r: 95,
+                    g: 158,
+                    b: 160,
+                },
+                false,
+            ));
+        }
+        row.push(add_style(
+            Cell::new(&content),
+            TableColor::Rgb {
+                r: 230,
+                g: 228,
+                b: 208,
+            },
+            false,
+        ));
+        table.add_row(row);
+    }
+    writeln!(out, "{}", table).unwrap();
+}
+
+/// Variable notation with one line per arrow
+fn write_variable(out: &mut String, variable: &crate::models::Variable, layout: TextLayout) {
+    writeln!(
+        out,
+        "  {} {}",
+        rust_i18n::t!("cli.label.variable").truecolor(255, 215, 0),
+        variable.notation.truecolor(255, 215, 0).bold()
+    )
+    .unwrap();
+    let desc_color = colored::Color::TrueColor {
+        r: 230,
+        g: 228,
+        b: 208,
+    };
+    for arrow in &variable.arrows {
+        let side_key = format!("cli.variable.side.{}", arrow.side);
+        writeln!(
+            out,
+            "    {}",
+            rust_i18n::t!(
+                "cli.variable.value",
+                arrow = arrow.name,
+                side = rust_i18n::t!(&side_key),
+                tone = arrow.tone
+            )
+            .truecolor(255, 160, 122)
+        )
+        .unwrap();
+        write_wrapped(out, layout, &arrow.keynote, 1, Some(desc_color), false);
+    }
+}
+
+fn write_info_items(
+    out: &mut String,
+    title: &str,
+    items: &[crate::models::InfoItem],
+    layout: TextLayout,
+) {
+    writeln!(out, "  {}", title.truecolor(255, 215, 0)).unwrap(); // Gold Title
+
+    let label_color = colored::Color::TrueColor {
+        r: 255,
+        g: 160,
+        b: 122,
+    };
+    let desc_color = colored::Color::TrueColor {
+        r: 230,
+        g: 228,
+        b: 208,
+    };
+
+    for item in items {
+        writeln!(out, "    {}", item.label.color(label_color)).unwrap();
+        if !item.description.is_empty() {
+            write_wrapped(out, layout, &item.description, 1, Some(desc_color), false);
+        }
+    }
+}
+
+/// Gate.Line marked with the line fixing (▲ ▼ ★), with the gate keynote underneath
+fn format_gate_line(p: &crate::models::PlanetPosition) -> String {
+    let fixing = p.fixing.map(|f| f.symbol()).unwrap_or("");
+    match p.gate_keynote {
+        Some(ref k) if !k.is_empty() => format!("{}.{}{}\n{}", p.gate, p.line, fixing, k),
+        _ => format!("{}.{}{}", p.gate, p.line, fixing),
+    }
+}
+
+/// Zodiac position of an activation, "♉ 24.43°", marked ℞ (R in ASCII) when retrograde
+fn format_sign(p: &crate::models::PlanetPosition, opts: &RenderOptions) -> String {
+    let sign = format!("{} {}", opts.symbols(&p.zodiac_symbol), format_angle(p.zodiac_degree, opts.angle_format));
+    match (p.retrograde, opts.ascii) {
+        (false, _) => sign,
+        (true, false) => format!("{} ℞", sign),
+        (true, true) => format!("{} R", sign),
+    }
+}
+
+/// Color.Tone.Base, marked with "~" when a level may change within the birth minute
+fn format_substructure(p: &crate::models::PlanetPosition) -> String {
+    let mut ctb = format!("{}.{}.{}", p.color, p.tone, p.base);
+    if p.uncertain.as_ref().is_some_and(|u| !u.is_empty()) {
+        ctb.push('~');
+    }
+    ctb
+}
+
+/// Channel key with the activation side of each gate, e.g. "20(P)-34(D)"
+fn channel_source_key(ch: &crate::models::ChannelInfo) -> String {
+    if ch.gates.is_empty() {
+        return ch.key.clone();
+    }
+    ch.gates
+        .iter()
+        .map(|g| {
+            let side_key = format!("cli.activation.short.{}", g.activation);
+            format!("{}({})", g.gate, rust_i18n::t!(&side_key))
+        })
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Black/red convention: Personality (conscious) in the terminal's default
+/// black/white, Design (unconscious) in red, both sides in a red-white blend
+pub fn activation_color(side: &str) -> TableColor {
+    match side {
+        "personality" | "conscious" => TableColor::White,
+        "design" | "unconscious" => TableColor::Rgb {
+            r: 220,
+            g: 60,
+            b: 60,
+        },
+        _ => TableColor::Rgb {
+            r: 240,
+            g: 150,
+            b: 150,
+        },
+    }
+}
+
+/// Same convention for `colored` text output
+pub(crate) fn activation_text_color(side: &str) -> colored::Color {
+    match activation_color(side) {
+        TableColor::Rgb { r, g, b } => colored::Color::TrueColor { r, g, b },
+        _ => colored::Color::White,
+    }
+}
+
+/// Narrowest terminal that fits the side-by-side planet table (wider with depth columns)
+const SIDE_BY_SIDE_PLANETS_MIN_WIDTH: usize = 80;
+const SIDE_BY_SIDE_PLANETS_DEPTH_MIN_WIDTH: usize = 110;
+
+fn write_combined_planet_table(
+    out: &mut String,
+    design: &[crate::models::PlanetPosition],
+    personality: &[crate::models::PlanetPosition],
+    opts: &RenderOptions,
+) {
+    let layout = opts.text_layout();
+
+    // Color/Tone/Base columns only when depth detail is present
+    let has_depth = personality.iter().any(|p| p.detail.is_some());
+    let min_width = if has_depth {
+        SIDE_BY_SIDE_PLANETS_DEPTH_MIN_WIDTH
+    } else {
+        SIDE_BY_SIDE_PLANETS_MIN_WIDTH
+    };
+
+    // Narrow terminals get Design and Personality beneath each other instead of
+    // six squashed columns
+    let table = if opts.resolved_width() < min_width {
+        stacked_planet_table(design, personality, opts, has_depth)
+    } else {
+        side_by_side_planet_table(design, personality, opts, has_depth)
+    };
+    writeln!(out, "{}", table).unwrap();
+
+    let has_uncertain = personality
+        .iter()
+        .chain(design.iter())
+        .any(|p| p.uncertain.as_ref().is_some_and(|u| !u.is_empty()));
+    if has_uncertain {
+        writeln!(
+            out,
+            "{}",
+            rust_i18n::t!("cli.depth.uncertain_note").truecolor(230, 228, 208)
+        )
+        .unwrap();
+    }
+
+    if personality.iter().chain(design.iter()).any(|p| p.fixing.is_some()) {
+        writeln!(out, "{}", rust_i18n::t!("cli.fixing.note").truecolor(230, 228, 208)).unwrap();
+    }
+
+    // Output descriptions (Design + Personality) - only if descriptions exist
+    let has_descriptions = personality.iter().any(|p| p.gate_description.is_some());
+
+    if has_descriptions {
+        // Standardized Headers
+        writeln!(
+            out,
+            "\n{}",
+            rust_i18n::t!("cli.section.personality")
+                .truecolor(95, 158, 160)
+                .bold()
+        )
+        .unwrap();
+        // Removed extra newline here
+        write_descriptions(out, personality, layout);
+
+        writeln!(
+            out,
+            "\n{}",
+            rust_i18n::t!("cli.section.design")
+                .truecolor(95, 158, 160)
+                .bold()
+        )
+        .unwrap();
+        // Removed extra newline here
+        write_descriptions(out, design, layout);
+    }
+}
+
+/// Design on the left, Personality on the right, mirrored around the signs
+fn side_by_side_planet_table(
+    design: &[crate::models::PlanetPosition],
+    personality: &[crate::models::PlanetPosition],
+    opts: &RenderOptions,
+    has_depth: bool,
+) -> Table {
+    let plain = !opts.color;
+    let tc_label = TableColor::Rgb {
+        r: 255,
+        g: 160,
+        b: 122,
+    };
+    let tc_white = activation_color("personality");
+    let tc_design = activation_color("design");
+
+    let add_style = |cell: Cell, color: TableColor, bold: bool| -> Cell {
+        if plain {
+            cell
+        } else {
+            let mut c = cell.fg(color);
+            if bold {
+                c = c.add_attribute(Attribute::Bold);
+            }
+            c
+        }
+    };
+
+    let gate_line_header = format!(
+        "{}.{}",
+        rust_i18n::t!("cli.label.gate"),
+        rust_i18n::t!("cli.label.line")
+    );
+    let depth_header = format!(
+        "{}.{}.{}",
+        rust_i18n::t!("cli.label.color"),
+        rust_i18n::t!("cli.label.tone"),
+        rust_i18n::t!("cli.label.base")
+    );
+
+    let mut headers = vec![
+        add_style(
+            Cell::new(&rust_i18n::t!("planet.name_header")),
+            tc_label,
+            true,
+        ), // "Planet"
+        add_style(Cell::new(&gate_line_header), tc_label, true),
+    ];
+    if has_depth {
+        headers.push(add_style(Cell::new(&depth_header), tc_label, true));
+    }
+    headers.push(add_style(Cell::new(&rust_i18n::t!("cli.label.sign")), tc_label, true));
+    headers.push(add_style(Cell::new(&rust_i18n::t!("cli.label.sign")), tc_label, true));
+    if has_depth {
+        headers.push(add_style(Cell::new(&depth_header), tc_label, true));
+    }
+    headers.push(add_style(Cell::new(&gate_line_header), tc_label, true));
+    headers.push(add_style(
+        Cell::new(&rust_i18n::t!("planet.name_header")),
+        tc_label,
+        true,
+    ));
+
+    let mut table = new_table(opts);
+    table.set_header(headers);
+
+    // Set minimum width for Sign columns to prevent squashing
+    let sign_columns = if has_depth { [3, 4] } else { [2, 3] };
+    let min_sign_width = ColumnConstraint::LowerBoundary(comfy_table::Width::Fixed(15));
+    for idx in sign_columns {
+        if let Some(col) = table.column_mut(idx) {
+            col.set_constraint(min_sign_width);
+        }
+    }
+
+    for (des, pers) in design.iter().zip(personality.iter()) {
+        let des_sign = format_sign(des, opts);
+        let pers_sign = format_sign(pers, opts);
+
+        let des_gate_line = format_gate_line(des);
+        let pers_gate_line = format_gate_line(pers);
+
+        let mut row = vec![
+            add_style(
+                Cell::new(&format!("{} {}", opts.symbols(&des.planet_symbol), des.planet)),
+                tc_design,
+                false,
+            ),
+            add_style(Cell::new(&des_gate_line), tc_design, true),
+        ];
+        if has_depth {
+            let des_ctb = format_substructure(des);
+            row.push(add_style(Cell::new(&des_ctb), tc_design, false));
+        }
+        row.push(add_style(Cell::new(&des_sign), tc_design, false));
+        row.push(add_style(Cell::new(&pers_sign), tc_white, false));
+        if has_depth {
+            let pers_ctb = format_substructure(pers);
+            row.push(add_style(Cell::new(&pers_ctb), tc_white, false));
+        }
+        row.push(add_style(Cell::new(&pers_gate_line), tc_white, true));
+        row.push(add_style(
+            Cell::new(&format!("{} {}", opts.symbols(&pers.planet_symbol), pers.planet)),
+            tc_white,
+            false,
+        ));
+        table.add_row(row);
+    }
+    table
+}
+
+/// One row per activation: each planet's Design row with its Personality row beneath
+fn stacked_planet_table(
+    design: &[crate::models::PlanetPosition],
+    personality: &[crate::models::PlanetPosition],
+    opts: &RenderOptions,
+    has_depth: bool,
+) -> Table {
+    let plain = !opts.color;
+
+    let tc_label = TableColor::Rgb {
+        r: 255,
+        g: 160,
+        b: 122,
+    };
+
+    let add_style = |cell: Cell, color: TableColor, bold: bool| -> Cell {
+        if plain {
+            cell
+        } else {
+            let mut c = cell.fg(color);
+            if bold {
+                c = c.add_attribute(Attribute::Bold);
+            }
+            c
+        }
+    };
+
+    let mut headers = vec![
+        add_style(
+            Cell::new(&rust_i18n::t!("planet.name_header")),
+            tc_label,
+            true,
+        ),
+        add_style(Cell::new(""), tc_label, true),
+        add_style(
+            Cell::new(format!(
+                "{}.{}",
+                rust_i18n::t!("cli.label.gate"),
+                rust_i18n::t!("cli.label.line")
+            )),
+            tc_label,
+            true,
+        ),
+    ];
+    if has_depth {
+        headers.push(add_style(
+            Cell::new(format!(
+                "{}.{}.{}",
+                rust_i18n::t!("cli.label.color"),
+                rust_i18n::t!("cli.label.tone"),
+                rust_i18n::t!("cli.label.base")
+            )),
+            tc_label,
+            true,
+        ));
+    }
+    headers.push(add_style(Cell::new(&rust_i18n::t!("cli.label.sign")), tc_label, true));
+
+    let mut table = new_table(opts);
+    table.set_header(headers);
+
+    for (des, pers) in design.iter().zip(personality.iter()) {
+        for (p, first) in [(des, true), (pers, false)] {
+            let color = activation_color(&p.activation);
+            let side_key = format!("cli.activation.short.{}", p.activation);
+            let gate_line = format_gate_line(p);
+            // Planet name once per pair
+            let planet = if first {
+                format!("{} {}", opts.symbols(&p.planet_symbol), p.planet)
+            } else {
+                String::new()
+            };
+
+            let mut row = vec![
+                add_style(Cell::new(planet), color, false),
+                add_style(Cell::new(rust_i18n::t!(&side_key)), color, true),
+                add_style(Cell::new(gate_line), color, true),
+            ];
+            if has_depth {
+                row.push(add_style(Cell::new(format_substructure(p)), color, false));
+            }
+            row.push(add_style(
+                Cell::new(format_sign(p, opts)),
+                color,
+                false,
+            ));
+            table.add_row(row);
+        }
+    }
+    table
+}
+
+fn write_descriptions(out: &mut String, data: &[crate::models::PlanetPosition], layout: TextLayout) {
+    let desc_color = colored::Color::TrueColor {
+        r: 230,
+        g: 228,
+        b: 208,
+    }; // Beige
+    let label_color = colored::Color::TrueColor {
+        r: 255,
+        g: 160,
+        b: 122,
+    }; // Soft Coral
+    let value_color = colored::Color::TrueColor {
+        r: 255,
+        g: 215,
+        b: 0,
+    }; // Gold
+
+    for p in data {
+        if let (Some(g_desc), Some(l_desc)) = (&p.gate_description, &p.line_description) {
+            let gate_hdr_txt = if let Some(g_name) = &p.gate_name {
+                format!(
+                    "{} {}: {}",
+                    rust_i18n::t!("cli.label.gate"),
+                    p.gate,
+                    layout.link(g_name, "gate", &p.gate.to_string())
+                )
+            } else {
+                format!("{} {}", rust_i18n::t!("cli.label.gate"), p.gate)
+            };
+
+            // Header for Gate
+            writeln!(
+                out,
+                "\n  {} - {}",
+                format!("{} {}", p.planet_symbol, p.planet)
+                    .color(activation_text_color(&p.activation))
+                    .bold(),
+                gate_hdr_txt.color(value_color).bold()
+            )
+            .unwrap();
+            write_wrapped(out, layout, g_desc, 0, Some(desc_color), false);
+
+            // Header for Line (Label/Gold/Bold)
+            let fixing = match p.fixing {
+                Some(fixing) => {
+                    let key = format!("cli.fixing.{}", fixing.key());
+                    format!(" {} {}", fixing.symbol(), rust_i18n::t!(&key))
+                }
+                None => String::new(),
+            };
+            writeln!(
+                out,
+                "    {}{}",
+                format!("{} {}:", rust_i18n::t!("cli.label.line"), p.line)
+                    .color(label_color)
+                    .bold(),
+                fixing.color(value_color)
+            )
+            .unwrap();
+            write_wrapped(out, layout, l_desc, 1, Some(desc_color), false);
+        }
+    }
+}
+
+fn write_active_gates(
+    out: &mut String,
+    active_gates: &[crate::models::ActiveGate],
+    opts: &RenderOptions,
+) {
+    let plain = !opts.color;
+    if active_gates.is_empty() {
+        return;
+    }
+
+    writeln!(out).unwrap();
+
+    let tc_label = TableColor::Rgb {
+        r: 255,
+        g: 160,
+        b: 122,
+    };
+    let tc_gold = TableColor::Rgb {
+        r: 255,
+        g: 215,
+        b: 0,
+    };
+    let add_style = |cell: Cell, color: TableColor, bold: bool| -> Cell {
+        if plain {
+            cell
+        } else {
+            let mut c = cell.fg(color);
+            if bold {
+                c = c.add_attribute(Attribute::Bold);
+            }
+            c
+        }
+    };
+
+    let mut table = new_table(opts);
+    table.set_header(vec![
+        add_style(Cell::new(rust_i18n::t!("cli.label.gate").as_ref()), tc_label, true),
+        add_style(Cell::new(rust_i18n::t!("cli.label.name").as_ref()), tc_label, true),
+        add_style(Cell::new(rust_i18n::t!("cli.label.planets").as_ref()), tc_label, true),
+    ]);
+
+    for ag in active_gates {
+        let activators = ag
+            .activators
+            .iter()
+            .map(|a| {
+                let side_key = format!("cli.activation.short.{}", a.side);
+                format!(
+                    "{} {} {}.{} ({})",
+                    opts.symbols(&a.planet_symbol),
+                    a.planet,
+                    ag.gate,
+                    a.line,
+                    rust_i18n::t!(&side_key)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        table.add_row(vec![
+            add_style(Cell::new(ag.gate), tc_gold, true),
+            add_style(Cell::new(gate_name_with_keynote(ag)), tc_gold, false),
+            add_style(Cell::new(&activators), activation_color(&ag.activation), false),
+        ]);
+    }
+    let layout = opts.text_layout();
+    let links: Vec<(String, String)> = active_gates
+        .iter()
+        .filter_map(|ag| {
+            Some((ag.gate_name.clone(), layout.link_url("gate", &ag.gate.to_string())?))
+        })
+        .collect();
+    writeln!(out, "{}", link_rendered(&table.to_string(), &links)).unwrap();
+}
+
+/// Gate name with the gate keynote underneath
+fn gate_name_with_keynote(ag: &crate::models::ActiveGate) -> String {
+    match ag.keynote {
+        Some(ref k) if !k.is_empty() => format!("{}\n{}", ag.gate_name, k),
+        _ => ag.gate_name.clone(),
+    }
+}
+
+fn write_activations(out: &mut String, activations: &[crate::models::Activation], opts: &RenderOptions) {
+    let plain = !opts.color;
+
+    writeln!(out).unwrap();
+
+    let tc_label = TableColor::Rgb {
+        r: 255,
+        g: 160,
+        b: 122,
+    };
+    let add_style = |cell: Cell, color: TableColor, bold: bool| -> Cell {
+        if plain {
+            cell
+        } else {
+            let mut c = cell.fg(color);
+            if bold {
+                c = c.add_attribute(Attribute::Bold);
+            }
+            c
+        }
+    };
+
+    let mut rows: Vec<&crate::models::Activation> = activations.iter().collect();
+    match opts.activation_order {
+        ActivationOrder::Side => {}
+        ActivationOrder::Planet => rows.sort_by_key(|a| (a.index, !a.conscious)),
+        ActivationOrder::Gate => rows.sort_by_key(|a| (a.gate, a.line, !a.conscious)),
+    }
+
+    let mut table = new_table(opts);
+    table.set_header(vec![
+        add_style(Cell::new(rust_i18n::t!("planet.name_header").as_ref()), tc_label, true),
+        add_style(Cell::new(rust_i18n::t!("cli.activations.notation").as_ref()), tc_label, true),
+        add_style(Cell::new(rust_i18n::t!("cli.activations.awareness").as_ref()), tc_label, true),
+    ]);
+    for a in rows {
+        let (side, awareness) = if a.conscious {
+            ("personality", rust_i18n::t!("cli.activations.conscious"))
+        } else {
+            ("design", rust_i18n::t!("cli.activations.unconscious"))
+        };
+        let color = activation_color(side);
+        table.add_row(vec![
+            add_style(Cell::new(format!("{} {}", opts.symbols(&a.planet_symbol), a.planet)), color, false),
+            add_style(
+                Cell::new(format!("{}{}", a.notation, a.fixing.map(|f| f.symbol()).unwrap_or(""))),
+                color,
+                true,
+            ),
+            add_style(Cell::new(awareness.as_ref()), color, false),
+        ]);
+    }
+    writeln!(out, "{}", table).unwrap();
+}
+
+/// Active awareness streams: inactive gates in parentheses, complete streams marked
+fn write_awareness_streams(
+    out: &mut String,
+    streams: &[crate::models::AwarenessStream],
+    opts: &RenderOptions,
+) {
+    let plain = !opts.color;
+    let layout = opts.text_layout();
+
+    writeln!(out).unwrap();
+
+    let tc_label = TableColor::Rgb {
+        r: 255,
+        g: 160,
+        b: 122,
+    };
+    let tc_value = TableColor::Rgb {
+        r: 255,
+        g: 215,
+        b: 0,
+    };
+    let tc_text = TableColor::Rgb {
+        r: 230,
+        g: 228,
+        b: 208,
+    };
+    let add_style = |cell: Cell, color: TableColor, bold: bool| -> Cell {
+        if plain {
+            cell
+        } else {
+            let mut c = cell.fg(color);
+            if bold {
+                c = c.add_attribute(Attribute::Bold);
+            }
+            c
+        }
+    };
+
+    let mut table = new_table(opts);
+    table.set_header(vec![
+        add_style(Cell::new(rust_i18n::t!("cli.awareness.center").as_ref()), tc_label, true),
+        add_style(Cell::new(rust_i18n::t!("cli.awareness.stream_header").as_ref()), tc_label, true),
+        add_style(Cell::new(rust_i18n::t!("cli.awareness.gates").as_ref()), tc_label, true),
+        add_style(Cell::new(rust_i18n::t!("cli.awareness.status").as_ref()), tc_label, true),
+    ]);
+    for stream in streams {
+        let gate = |g: &u8| {
+            if stream.active_gates.contains(g) {
+                g.to_string()
+            } else {
+                format!("({})", g)
+            }
+        };
+        // Gates come in channel pairs along the flow
+        let gates = stream
+            .gates
+            .chunks(2)
+            .map(|pair| pair.iter().map(gate).collect::<Vec<_>>().join(" → "))
+            .collect::<Vec<_>>()
+            .join(" · ");
+        let status = if stream.complete {
+            rust_i18n::t!("cli.awareness.complete").to_string()
+        } else {
+            rust_i18n::t!(
+                "cli.awareness.partial",
+                active = stream.active_gates.len(),
+                total = stream.gates.len()
+            )
+            .to_string()
+        };
+        table.add_row(vec![
+            add_style(Cell::new(&stream.center_name), tc_text, false),
+            add_style(Cell::new(&stream.name), tc_value, true),
+            add_style(Cell::new(gates), tc_text, false),
+            add_style(Cell::new(status), if stream.complete { tc_value } else { tc_text }, stream.complete),
+        ]);
+    }
+    writeln!(out, "{}", table).unwrap();
+
+    let desc_color = colored::Color::TrueColor {
+        r: 230,
+        g: 228,
+        b: 208,
+    };
+    for stream in streams {
+        if let Some(ref desc) = stream.description {
+            writeln!(out, "\n  {}", stream.name.truecolor(255, 215, 0).bold()).unwrap();
+            write_wrapped(out, layout, desc, 0, Some(desc_color), false);
+        }
+    }
+}
+
+fn write_gate_emphasis(out: &mut String, emphasis: &crate::models::GateEmphasis) {
+    if emphasis.repeated.is_empty() && emphasis.resonance.is_empty() {
+        return;
+    }
+
+
+    if !emphasis.repeated.is_empty() {
+        let repeated = emphasis
+            .repeated
+            .iter()
+            .map(|r| format!("{} ×{}", r.gate, r.count))
+            .collect::<Vec<_>>()
+            .join(", ");
+        writeln!(
+            out,
+            "  {} {}",
+            rust_i18n::t!("cli.label.repeated").truecolor(255, 160, 122),
+            repeated.truecolor(255, 215, 0)
+        )
+        .unwrap();
+    }
+
+    if !emphasis.resonance.is_empty() {
+        let resonance = emphasis
+            .resonance
+            .iter()
+            .map(|g| g.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        writeln!(
+            out,
+            "  {} {}",
+            rust_i18n::t!("cli.label.resonance").truecolor(255, 160, 122),
+            resonance.truecolor(255, 215, 0)
+        )
+        .unwrap();
+    }
+}
+
+/// Hanging gates under their center, each with the gates that would complete it
+fn write_hanging_gates(out: &mut String, hanging: &[crate::models::HangingGate]) {
+    let mut center = None;
+    for hg in hanging {
+        if center != Some(hg.center) {
+            center = Some(hg.center);
+            writeln!(out, "\n  {}", hg.center_name.truecolor(95, 158, 160).bold()).unwrap();
+        }
+        let side_key = format!("cli.activation.short.{}", hg.activation);
+        let completions = hg
+            .completions
+            .iter()
+            .map(|c| format!("{} ({})", c.gate, c.channel))
+            .collect::<Vec<_>>()
+            .join(", ");
+        writeln!(
+            out,
+            "    {} {}: {} [{}] {} {}",
+            rust_i18n::t!("cli.label.gate").truecolor(255, 160, 122),
+            hg.gate.to_string().color(activation_text_color(&hg.activation)).bold(),
+            hg.gate_name.truecolor(255, 215, 0),
+            rust_i18n::t!(&side_key),
+            "→".truecolor(95, 158, 160),
+            completions.truecolor(230, 228, 208)
+        )
+        .unwrap();
+    }
+}
+
+fn write_node_themes(out: &mut String, nodes: &[crate::models::NodeTheme], layout: TextLayout) {
+    if nodes.is_empty() {
+        return;
+    }
+
+
+    let desc_color = colored::Color::TrueColor {
+        r: 230,
+        g: 228,
+        b: 208,
+    }; // Beige
+    let label_color = colored::Color::TrueColor {
+        r: 255,
+        g: 160,
+        b: 122,
+    }; // Soft Coral
+    let value_color = colored::Color::TrueColor {
+        r: 255,
+        g: 215,
+        b: 0,
+    }; // Gold
+
+    let mut current_phase = "";
+    for node in nodes {
+        if node.phase != current_phase {
+            current_phase = &node.phase;
+            let phase_key = format!("cli.nodes.{}", node.phase);
+            writeln!(out, "\n  {}", rust_i18n::t!(&phase_key).color(value_color)).unwrap();
+        }
+
+        let side_key = format!("cli.label.{}", node.side);
+        let gate_txt = match node.gate_name {
+            Some(ref name) => format!(
+                "{} {}.{}: {}",
+                rust_i18n::t!("cli.label.gate"),
+                node.gate,
+                node.line,
+                layout.link(name, "gate", &node.gate.to_string())
+            ),
+            None => format!("{} {}.{}", rust_i18n::t!("cli.label.gate"), node.gate, node.line),
+        };
+        writeln!(
+            out,
+            "    {} - {}",
+            format!(
+                "{} {} ({})",
+                node.planet_symbol,
+                node.planet,
+                rust_i18n::t!(&side_key)
+            )
+            .color(label_color)
+            .bold(),
+            gate_txt.color(value_color).bold()
+        )
+        .unwrap();
+        if let Some(ref desc) = node.gate_description {
+            write_wrapped(out, layout, desc, 1, Some(desc_color), false);
+        }
+        if let Some(ref desc) = node.line_description {
+            write_wrapped(out, layout, desc, 1, Some(desc_color), false);
+        }
+    }
+}
+
+fn write_gate_section_items(
+    out: &mut String,
+    items: &[crate::models::InfoItem],
+    layout: TextLayout,
+) {
+    writeln!(out).unwrap(); // Spacing
+
+    let desc_color = colored::Color::TrueColor {
+        r: 230,
+        g: 228,
+        b: 208,
+    }; // Beige
+    let label_color = colored::Color::TrueColor {
+        r: 255,
+        g: 160,
+        b: 122,
+    }; // Soft Coral
+    let value_color = colored::Color::TrueColor {
+        r: 255,
+        g: 215,
+        b: 0,
+    }; // Gold
+
+    for item in items {
+        if let (Some(planets), Some(gate_id), Some(gate_name)) =
+            (&item.planets, item.gate_id, &item.gate_name)
+        {
+            // New Format: Planet - Gate
+            // "☉ Sun, ⊕ Earth - Gate 5: Name"
+            let mut planets_vec: Vec<_> = planets.iter().collect();
+            planets_vec.sort();
+
+            let planets_str = planets_vec
+                .iter()
+                .map(|p| format!("{} {}", p.symbol, p.name))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            let gate_part = format!(
+                "{} {}: {}",
+                rust_i18n::t!("cli.label.gate"),
+                gate_id,
+                layout.link(gate_name, "gate", &gate_id.to_string())
+            );
+
+            writeln!(
+                out,
+                "  {} - {}",
+                planets_str.color(label_color).bold(),
+                gate_part.color(value_color).bold()
+            )
+            .unwrap();
+            write_wrapped(out, layout, &item.description, 0, Some(desc_color), false);
+        } else if let Some(planets) = &item.planets {
+            // Planets without a gate: "☉ Sun, ⊕ Earth - Color 3 (Desire):"
+            let mut planets_vec: Vec<_> = planets.iter().collect();
+            planets_vec.sort();
+            let planets_str = planets_vec
+                .iter()
+                .map(|p| format!("{} {}", p.symbol, p.name))
+                .collect::<Vec<_>>()
+                .join(", ");
+            writeln!(
+                out,
+                "  {} - {}",
+                planets_str.color(label_color).bold(),
+                item.label.color(value_color).bold()
+            )
+            .unwrap();
+            write_wrapped(out, layout, &item.description, 0, Some(desc_color), false);
+        } else {
+            // Fallback / Standard InfoItem
+            writeln!(out, "  {}", item.label.truecolor(255, 160, 122)).unwrap();
+            write_wrapped(out, layout, &item.description, 0, Some(desc_color), false);
+        }
+    }
+}
+
+fn write_wrapped(
+    out: &mut String,
+    layout: TextLayout,
+    text: &str,
+    level: usize,
+    color: Option<colored::Color>,
+    dimmed: bool,
+) {
+    let indent_str = " ".repeat(layout.wrap.indent + level * 2);
+    let splitter = if layout.wrap.split_hyphens {
+        WordSplitter::HyphenSplitter
+    } else {
+        WordSplitter::NoHyphenation
+    };
+    let options = Options::new(layout.width)
+        .initial_indent(&indent_str)
+        .subsequent_indent(&indent_str)
+        .break_words(layout.wrap.break_words)
+        .word_splitter(splitter);
+
+    let wrapped = textwrap::fill(text, &options);
+
+    let mut style = if let Some(c) = color {
+        wrapped.color(c)
+    } else {
+        wrapped.normal()
+    };
+
+    if dimmed {
+        style = style.dimmed();
+    }
+
+    writeln!(out, "{}", style).unwrap();
+}
+
+/// Defined channels per circuit group, with the circuit keynotes in full mode
+fn write_circuitry(out: &mut String, shares: &[crate::models::CircuitryShare], layout: TextLayout) {
+    writeln!(out).unwrap();
+    writeln!(
+        out,
+        "  {}",
+        rust_i18n::t!("cli.circuitry.title").truecolor(255, 215, 0)
+    )
+    .unwrap();
+    let desc_color = colored::Color::TrueColor {
+        r: 230,
+        g: 228,
+        b: 208,
+    };
+    for share in shares {
+        writeln!(
+            out,
+            "    {}",
+            rust_i18n::t!(
+                "cli.circuitry.value",
+                name = share.name,
+                count = share.channels,
+                percent = share.percent
+            )
+            .truecolor(255, 160, 122)
+        )
+        .unwrap();
+        if let Some(ref keynote) = share.keynote {
+            write_wrapped(out, layout, keynote, 1, Some(desc_color), false);
+        }
+    }
+}
+
+fn write_circuit_scores_table(
+    out: &mut String,
+    scores: &[crate::models::CircuitScoreItem],
+    opts: &RenderOptions,
+) {
+    let plain = !opts.color;
+    use crate::circuit_score::group_by_circuit;
+
+    writeln!(out).unwrap();
+
+    let add_style = |cell: Cell, color: TableColor, bold: bool| -> Cell {
+        if plain {
+            cell
+        } else {
+            let mut c = cell.fg(color);
+            if bold {
+                c = c.add_attribute(Attribute::Bold);
+            }
+            c
+        }
+    };
+
+    let tc_coral = TableColor::Rgb {
+        r: 255,
+        g: 160,
+        b: 122,
+    };
+    let tc_gold = TableColor::Rgb {
+        r: 255,
+        g: 215,
+        b: 0,
+    };
+    let tc_teal = TableColor::Rgb {
+        r: 95,
+        g: 158,
+        b: 160,
+    };
+    let tc_beige = TableColor::Rgb {
+        r: 230,
+        g: 228,
+        b: 208,
+    };
+    let tc_grey = TableColor::DarkGrey;
+
+    let mut table = new_table(opts);
+    table.set_header(vec![
+        add_style(
+            Cell::new(rust_i18n::t!("cli.label.circuit").as_ref()),
+            tc_coral,
+            true,
+        ),
+        add_style(
+            Cell::new(rust_i18n::t!("cli.label.score").as_ref()),
+            tc_coral,
+            true,
+        ),
+        add_style(
+            Cell::new(rust_i18n::t!("cli.label.planets").as_ref()),
+            tc_coral,
+            true,
+        ),
+        add_style(
+            Cell::new(rust_i18n::t!("cli.label.channels").as_ref()),
+            tc_coral,
+            true,
+        ),
+        add_style(
+            Cell::new(rust_i18n::t!("cli.label.description").as_ref()),
+            tc_coral,
+            true,
+        ),
+    ]);
+
+    let grouped = group_by_circuit(scores);
+
+    for (_, circuit_name, circuit_desc, circuit_total, sub_items) in &grouped {
+        let desc = if circuit_desc.is_empty() {
+            "—".to_string()
+        } else {
+            circuit_desc.clone()
+        };
+        table.add_row(vec![
+            add_style(Cell::new(format!("▶ {}", circuit_name)), tc_gold, true),
+            add_style(Cell::new(format!("{:.1}", circuit_total)), tc_gold, true),
+            add_style(Cell::new(""), tc_grey, false),
+            add_style(Cell::new(""), tc_grey, false),
+            add_style(Cell::new(&desc), tc_beige, false),
+        ]);
+
+        // ── Sub-circuit rows ────────────────────────────────────────────
+        for item in sub_items {
+            let sub_label = format!("  └ {}", item.sub_circuit_name);
+            let desc = if item.description.is_empty() {
+                "—".to_string()
+            } else {
+                item.description.clone()
+            };
+            table.add_row(vec![
+                add_style(Cell::new(&sub_label), tc_teal, false),
+                add_style(Cell::new(format!("{:.1}", item.score)), tc_teal, true),
+                add_style(
+                    Cell::new(format!("{}p", item.planet_count)),
+                    tc_beige,
+                    false,
+                ),
+                add_style(
+                    Cell::new(format!("{}ch", item.channel_count)),
+                    tc_beige,
+                    false,
+                ),
+                add_style(Cell::new(&desc), tc_beige, false),
+            ]);
+        }
+    }
+
+    writeln!(out, "{}", table).unwrap();
+}
@@ -0,0 +1,1437 @@
+/// Terminal reports other than the chart itself (transits, composite charts, group
+/// analyses, calendars), in the chart report's table and color style
+use super::{
+    activation_color, apply_color_choice, header_rule, moon_phase_text, new_table, styled_cell,
+    RenderOptions, TC_BEIGE, TC_CORAL, TC_GOLD,
+};
+use crate::models::{
+    CompositeChart, CycleEvent, DreamRaveChart, Ephemeris, GeneKeysChart, LunarCycle, PentaReport,
+    ProfileMatrix, SolarReturn, SunIngress, TransitPlan, TransitReport,
+};
+use super::style::*;
+use comfy_table::{Attribute, Cell, Color as TableColor, ContentArrangement};
+use std::fmt::Write;
+
+fn format_duration(hours: f64) -> String {
+    let days = (hours / 24.0).floor();
+    let rest = (hours - days * 24.0).round();
+    if days > 0.0 {
+        format!("{}d {}h", days, rest)
+    } else {
+        format!("{}h", rest)
+    }
+}
+
+/// Transits to a saved profile: completed hanging gates, temporarily defined centers
+/// and the activation windows of the transiting planets
+pub fn render_transit(report: &TransitReport, opts: &RenderOptions) -> String {
+    let mut out = String::new();
+
+    if !opts.color {
+        colored::control::set_override(false);
+    }
+
+    let add_style = |cell: Cell, color: TableColor, bold: bool| -> Cell {
+        if !opts.color {
+            cell
+        } else {
+            let mut c = cell.fg(color);
+            if bold {
+                c = c.add_attribute(Attribute::Bold);
+            }
+            c
+        }
+    };
+
+    let tc_coral = TableColor::Rgb {
+        r: 255,
+        g: 160,
+        b: 122,
+    };
+    let tc_gold = TableColor::Rgb {
+        r: 255,
+        g: 215,
+        b: 0,
+    };
+    let tc_teal = TableColor::Rgb {
+        r: 95,
+        g: 158,
+        b: 160,
+    };
+    let tc_beige = TableColor::Rgb {
+        r: 230,
+        g: 228,
+        b: 208,
+    };
+
+    writeln!(
+        out,
+        "\n{}",
+        header_rule(opts.ascii).truecolor(95, 158, 160)
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "      {}",
+        rust_i18n::t!("cli.transit.header", profile = report.profile)
+            .truecolor(255, 255, 255)
+            .bold()
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "{}",
+        header_rule(opts.ascii).truecolor(95, 158, 160)
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "\n  {} {} UTC",
+        rust_i18n::t!("cli.label.date").truecolor(255, 160, 122),
+        report.moment.truecolor(255, 215, 0)
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "  {} {}",
+        rust_i18n::t!("cli.label.moon_phase").truecolor(255, 160, 122),
+        moon_phase_text(&report.moon_phase).truecolor(255, 215, 0)
+    )
+    .unwrap();
+
+    // Hanging gates completed by transits
+    writeln!(
+        out,
+        "\n{}",
+        rust_i18n::t!("cli.transit.completed")
+            .truecolor(95, 158, 160)
+            .bold()
+    )
+    .unwrap();
+    writeln!(out).unwrap();
+    if report.completed_gates.is_empty() {
+        writeln!(out, "  {}", rust_i18n::t!("cli.transit.none").dimmed()).unwrap();
+    } else {
+        let mut table = new_table(opts);
+        table
+            .set_content_arrangement(ContentArrangement::Dynamic)
+            .set_header(vec![
+                add_style(Cell::new(rust_i18n::t!("cli.label.channel").as_ref()), tc_coral, true),
+                add_style(Cell::new(rust_i18n::t!("cli.label.name").as_ref()), tc_coral, true),
+                add_style(Cell::new(rust_i18n::t!("cli.transit.natal_gate").as_ref()), tc_coral, true),
+                add_style(Cell::new(rust_i18n::t!("cli.transit.transit_gate").as_ref()), tc_coral, true),
+                add_style(Cell::new(rust_i18n::t!("cli.label.planets").as_ref()), tc_coral, true),
+            ]);
+        for c in &report.completed_gates {
+            table.add_row(vec![
+                add_style(Cell::new(&c.channel), tc_teal, false),
+                add_style(Cell::new(&c.channel_name), tc_gold, true),
+                add_style(Cell::new(c.natal_gate), tc_beige, true),
+                add_style(Cell::new(c.transit_gate), tc_beige, true),
+                add_style(Cell::new(opts.symbols(&c.planets.join(", "))), tc_beige, false),
+            ]);
+        }
+        writeln!(out, "{}", table).unwrap();
+    }
+
+    // Open centers temporarily defined
+    writeln!(
+        out,
+        "\n{}",
+        rust_i18n::t!("cli.transit.centers")
+            .truecolor(95, 158, 160)
+            .bold()
+    )
+    .unwrap();
+    writeln!(out).unwrap();
+    if report.temporary_centers.is_empty() {
+        writeln!(out, "  {}", rust_i18n::t!("cli.transit.none").dimmed()).unwrap();
+    } else {
+        for c in &report.temporary_centers {
+            writeln!(
+                out,
+                "  {} {}",
+                format!("● {}", c.name).truecolor(255, 215, 0).bold(),
+                format!("({})", c.channels.join(", ")).truecolor(230, 228, 208)
+            )
+            .unwrap();
+        }
+    }
+
+    // Activation windows
+    if !report.activations.is_empty() {
+        writeln!(
+            out,
+            "\n{}",
+            rust_i18n::t!("cli.transit.activations")
+                .truecolor(95, 158, 160)
+                .bold()
+        )
+        .unwrap();
+        writeln!(out).unwrap();
+
+        let mut table = new_table(opts);
+        table
+            .set_content_arrangement(ContentArrangement::Dynamic)
+            .set_header(vec![
+                add_style(Cell::new(rust_i18n::t!("planet.name_header").as_ref()), tc_coral, true),
+                add_style(
+                    Cell::new(format!(
+                        "{}.{}",
+                        rust_i18n::t!("cli.label.gate"),
+                        rust_i18n::t!("cli.label.line")
+                    )),
+                    tc_coral,
+                    true,
+                ),
+                add_style(Cell::new(rust_i18n::t!("cli.transit.starts").as_ref()), tc_coral, true),
+                add_style(Cell::new(rust_i18n::t!("cli.transit.ends").as_ref()), tc_coral, true),
+                add_style(Cell::new(rust_i18n::t!("cli.transit.duration").as_ref()), tc_coral, true),
+            ]);
+        for a in &report.activations {
+            let dash = "—".to_string();
+            table.add_row(vec![
+                add_style(
+                    Cell::new(format!("{} {}", opts.symbols(&a.planet_symbol), a.planet)),
+                    tc_coral,
+                    false,
+                ),
+                add_style(Cell::new(format!("{}.{}", a.gate, a.line)), tc_gold, true),
+                add_style(Cell::new(a.starts.as_ref().unwrap_or(&dash)), tc_beige, false),
+                add_style(Cell::new(a.ends.as_ref().unwrap_or(&dash)), tc_beige, false),
+                add_style(
+                    Cell::new(a.duration_hours.map(format_duration).unwrap_or(dash.clone())),
+                    tc_beige,
+                    false,
+                ),
+            ]);
+        }
+        writeln!(out, "{}", table).unwrap();
+    }
+
+    opts.symbols(&out)
+}
+
+/// Composite (group or relationship) chart
+pub fn render_composite(chart: &CompositeChart, opts: &RenderOptions) -> String {
+    let mut out = String::new();
+
+    if !opts.color {
+        colored::control::set_override(false);
+    }
+
+    let add_style = |cell: Cell, color: TableColor, bold: bool| -> Cell {
+        if !opts.color {
+            cell
+        } else {
+            let mut c = cell.fg(color);
+            if bold {
+                c = c.add_attribute(Attribute::Bold);
+            }
+            c
+        }
+    };
+
+    let tc_coral = TableColor::Rgb {
+        r: 255,
+        g: 160,
+        b: 122,
+    };
+    let tc_gold = TableColor::Rgb {
+        r: 255,
+        g: 215,
+        b: 0,
+    };
+    let tc_teal = TableColor::Rgb {
+        r: 95,
+        g: 158,
+        b: 160,
+    };
+    let tc_beige = TableColor::Rgb {
+        r: 230,
+        g: 228,
+        b: 208,
+    };
+
+    let names: Vec<&str> = chart.members.iter().map(|m| m.name.as_str()).collect();
+
+    writeln!(
+        out,
+        "\n{}",
+        header_rule(opts.ascii).truecolor(95, 158, 160)
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "      {}",
+        rust_i18n::t!("cli.composite.header", names = names.join(" + "))
+            .truecolor(255, 255, 255)
+            .bold()
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "{}",
+        header_rule(opts.ascii).truecolor(95, 158, 160)
+    )
+    .unwrap();
+
+    // Aggregate bodygraph
+    writeln!(
+        out,
+        "\n{}",
+        rust_i18n::t!("cli.section.main_info")
+            .truecolor(95, 158, 160)
+            .bold()
+    )
+    .unwrap();
+    writeln!(out).unwrap();
+    writeln!(
+        out,
+        "  {} {}",
+        rust_i18n::t!("cli.label.type").truecolor(255, 160, 122),
+        chart.hd_type.truecolor(255, 215, 0).bold()
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "  {} {}",
+        format!("● {}:", rust_i18n::t!("cli.label.defined")).truecolor(255, 160, 122),
+        chart.defined_centers.join(", ").truecolor(255, 215, 0)
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "  {} {}",
+        format!("○ {}:", rust_i18n::t!("cli.label.open")).truecolor(255, 160, 122),
+        chart.open_centers.join(", ").truecolor(230, 228, 208)
+    )
+    .unwrap();
+
+    // Channels with contributors
+    if !chart.channels.is_empty() {
+        writeln!(
+            out,
+            "\n{}",
+            rust_i18n::t!("cli.section.channels")
+                .truecolor(95, 158, 160)
+                .bold()
+        )
+        .unwrap();
+        writeln!(out).unwrap();
+
+        let mut table = new_table(opts);
+        table
+            .set_content_arrangement(ContentArrangement::Dynamic)
+            .set_header(vec![
+                add_style(Cell::new(rust_i18n::t!("cli.label.channel").as_ref()), tc_coral, true),
+                add_style(Cell::new(rust_i18n::t!("cli.label.name").as_ref()), tc_coral, true),
+                add_style(Cell::new(rust_i18n::t!("cli.composite.kind").as_ref()), tc_coral, true),
+                add_style(
+                    Cell::new(rust_i18n::t!("cli.composite.supply").as_ref()),
+                    tc_coral,
+                    true,
+                ),
+            ]);
+        for ch in &chart.channels {
+            let contributors = ch
+                .supply
+                .iter()
+                .map(|s| {
+                    let gates: Vec<String> = s.gates.iter().map(|g| g.to_string()).collect();
+                    format!("{}: {}", s.member, gates.join(", "))
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            let kind_key = format!("cli.composite.kinds.{}", ch.kind);
+            table.add_row(vec![
+                add_style(Cell::new(&ch.key), tc_teal, false),
+                add_style(Cell::new(&ch.name), tc_gold, true),
+                add_style(Cell::new(rust_i18n::t!(&kind_key).as_ref()), tc_beige, false),
+                add_style(Cell::new(&contributors), tc_beige, false),
+            ]);
+        }
+        writeln!(out, "{}", table).unwrap();
+    }
+
+    // Per-person contribution breakdown
+    writeln!(
+        out,
+        "\n{}",
+        rust_i18n::t!("cli.composite.members")
+            .truecolor(95, 158, 160)
+            .bold()
+    )
+    .unwrap();
+    writeln!(out).unwrap();
+
+    let mut table = new_table(opts);
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            add_style(Cell::new(rust_i18n::t!("cli.composite.member").as_ref()), tc_coral, true),
+            add_style(Cell::new(rust_i18n::t!("cli.section.centers").as_ref()), tc_coral, true),
+            add_style(Cell::new(rust_i18n::t!("cli.composite.own").as_ref()), tc_coral, true),
+            add_style(Cell::new(rust_i18n::t!("cli.composite.bridged").as_ref()), tc_coral, true),
+        ]);
+    for m in &chart.members {
+        table.add_row(vec![
+            add_style(Cell::new(&m.name), tc_gold, true),
+            add_style(Cell::new(m.defined_centers.join(", ")), tc_beige, false),
+            add_style(Cell::new(m.own_channels.join(", ")), tc_teal, false),
+            add_style(Cell::new(m.bridged_channels.join(", ")), tc_beige, false),
+        ]);
+    }
+    writeln!(out, "{}", table).unwrap();
+
+    opts.symbols(&out)
+}
+
+/// Dream Rave chart with its own gates and centers
+pub fn render_dreamrave(chart: &DreamRaveChart, opts: &RenderOptions) -> String {
+    let mut out = String::new();
+
+    if !opts.color {
+        colored::control::set_override(false);
+    }
+
+    let add_style = |cell: Cell, color: TableColor, bold: bool| -> Cell {
+        if !opts.color {
+            cell
+        } else {
+            let mut c = cell.fg(color);
+            if bold {
+                c = c.add_attribute(Attribute::Bold);
+            }
+            c
+        }
+    };
+
+    let tc_coral = TableColor::Rgb {
+        r: 255,
+        g: 160,
+        b: 122,
+    };
+    let tc_gold = TableColor::Rgb {
+        r: 255,
+        g: 215,
+        b: 0,
+    };
+    let tc_teal = TableColor::Rgb {
+        r: 95,
+        g: 158,
+        b: 160,
+    };
+
+    writeln!(
+        out,
+        "\n{}",
+        header_rule(opts.ascii).truecolor(95, 158, 160)
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "      {}",
+        rust_i18n::t!("cli.dreamrave.header", moment = chart.moment)
+            .truecolor(255, 255, 255)
+            .bold()
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "{}",
+        header_rule(opts.ascii).truecolor(95, 158, 160)
+    )
+    .unwrap();
+
+    // Dream centers
+    writeln!(
+        out,
+        "\n{}",
+        rust_i18n::t!("cli.section.centers")
+            .truecolor(95, 158, 160)
+            .bold()
+    )
+    .unwrap();
+    writeln!(out).unwrap();
+    for center in &chart.centers {
+        let (mark, key) = if center.defined {
+            ("●", "cli.label.defined")
+        } else {
+            ("○", "cli.label.open")
+        };
+        let gates = center
+            .gates
+            .iter()
+            .map(|g| g.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        writeln!(
+            out,
+            "  {} {} ({}) {}",
+            mark.truecolor(255, 215, 0),
+            center.name.truecolor(255, 215, 0).bold(),
+            rust_i18n::t!(key).to_lowercase().truecolor(255, 160, 122),
+            gates.truecolor(230, 228, 208)
+        )
+        .unwrap();
+    }
+    if !chart.channels.is_empty() {
+        writeln!(
+            out,
+            "\n  {} {}",
+            rust_i18n::t!("cli.dreamrave.channels").truecolor(255, 160, 122),
+            chart.channels.join(", ").truecolor(255, 215, 0)
+        )
+        .unwrap();
+    }
+
+    // Activations
+    writeln!(
+        out,
+        "\n{}",
+        rust_i18n::t!("cli.section.planets")
+            .truecolor(95, 158, 160)
+            .bold()
+    )
+    .unwrap();
+    writeln!(out).unwrap();
+
+    let mut table = new_table(opts);
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            add_style(Cell::new(rust_i18n::t!("planet.name_header").as_ref()), tc_coral, true),
+            add_style(Cell::new(rust_i18n::t!("cli.dreamrave.gate").as_ref()), tc_coral, true),
+            add_style(Cell::new(rust_i18n::t!("cli.dreamrave.center_label").as_ref()), tc_coral, true),
+        ]);
+    let center_name = |key: &str| {
+        chart
+            .centers
+            .iter()
+            .find(|c| c.key == key)
+            .map(|c| c.name.clone())
+            .unwrap_or_else(|| key.to_string())
+    };
+    for a in &chart.activations {
+        let side_key = format!("cli.activation.short.{}", a.side);
+        table.add_row(vec![
+            add_style(
+                Cell::new(format!("{} {} ({})", opts.symbols(&a.planet_symbol), a.planet, rust_i18n::t!(&side_key))),
+                activation_color(&a.side),
+                false,
+            ),
+            add_style(Cell::new(a.dream_gate), tc_gold, true),
+            add_style(Cell::new(center_name(&a.center)), tc_teal, false),
+        ]);
+    }
+    writeln!(out, "{}", table).unwrap();
+
+    opts.symbols(&out)
+}
+
+/// Penta analysis of a 3-5 person group
+pub fn render_penta(report: &PentaReport, opts: &RenderOptions) -> String {
+    let mut out = String::new();
+
+    if !opts.color {
+        colored::control::set_override(false);
+    }
+
+    let add_style = |cell: Cell, color: TableColor, bold: bool| -> Cell {
+        if !opts.color {
+            cell
+        } else {
+            let mut c = cell.fg(color);
+            if bold {
+                c = c.add_attribute(Attribute::Bold);
+            }
+            c
+        }
+    };
+
+    let tc_coral = TableColor::Rgb {
+        r: 255,
+        g: 160,
+        b: 122,
+    };
+    let tc_gold = TableColor::Rgb {
+        r: 255,
+        g: 215,
+        b: 0,
+    };
+    let tc_teal = TableColor::Rgb {
+        r: 95,
+        g: 158,
+        b: 160,
+    };
+    let tc_beige = TableColor::Rgb {
+        r: 230,
+        g: 228,
+        b: 208,
+    };
+
+    writeln!(
+        out,
+        "\n{}",
+        header_rule(opts.ascii).truecolor(95, 158, 160)
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "      {}",
+        rust_i18n::t!("cli.penta.header", names = report.members.join(" + "))
+            .truecolor(255, 255, 255)
+            .bold()
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "{}",
+        header_rule(opts.ascii).truecolor(95, 158, 160)
+    )
+    .unwrap();
+
+    writeln!(
+        out,
+        "\n{}",
+        rust_i18n::t!("cli.section.channels")
+            .truecolor(95, 158, 160)
+            .bold()
+    )
+    .unwrap();
+    writeln!(out).unwrap();
+
+    let mut table = new_table(opts);
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            add_style(Cell::new(rust_i18n::t!("cli.label.channel").as_ref()), tc_coral, true),
+            add_style(Cell::new(rust_i18n::t!("cli.penta.role_label").as_ref()), tc_coral, true),
+            add_style(Cell::new(rust_i18n::t!("cli.penta.status_label").as_ref()), tc_coral, true),
+            add_style(Cell::new(rust_i18n::t!("cli.composite.contributors").as_ref()), tc_coral, true),
+        ]);
+    for ch in &report.channels {
+        let carriers = ch
+            .gates
+            .iter()
+            .map(|g| {
+                let members = if g.members.is_empty() {
+                    "—".to_string()
+                } else {
+                    g.members.join(", ")
+                };
+                format!("{} {}: {}", g.gate, g.role, members)
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        let part_key = format!("cli.penta.part.{}", ch.part);
+        let status_key = format!("cli.penta.status.{}", ch.status);
+        let status_color = if ch.status == "defined" { tc_teal } else { tc_coral };
+        table.add_row(vec![
+            add_style(Cell::new(&ch.key), tc_teal, false),
+            add_style(
+                Cell::new(format!("{}\n{}", ch.role, rust_i18n::t!(&part_key))),
+                tc_gold,
+                true,
+            ),
+            add_style(Cell::new(rust_i18n::t!(&status_key).as_ref()), status_color, true),
+            add_style(Cell::new(&carriers), tc_beige, false),
+        ]);
+    }
+    writeln!(out, "{}", table).unwrap();
+
+    if !report.missing_roles.is_empty() {
+        writeln!(
+            out,
+            "\n{}",
+            rust_i18n::t!("cli.penta.missing")
+                .truecolor(95, 158, 160)
+                .bold()
+        )
+        .unwrap();
+        writeln!(out).unwrap();
+        for role in &report.missing_roles {
+            writeln!(out, "  ○ {}", role.truecolor(255, 160, 122)).unwrap();
+        }
+    }
+
+    writeln!(
+        out,
+        "\n{}",
+        rust_i18n::t!("cli.penta.dynamics.title")
+            .truecolor(95, 158, 160)
+            .bold()
+    )
+    .unwrap();
+    writeln!(out).unwrap();
+    for note in &report.dynamics {
+        writeln!(out, "  • {}", note.truecolor(230, 228, 208)).unwrap();
+    }
+
+    opts.symbols(&out)
+}
+
+/// Transit planner: days ranked by personally significant transits
+pub fn render_planner(plan: &TransitPlan, opts: &RenderOptions) -> String {
+    let mut out = String::new();
+
+    if !opts.color {
+        colored::control::set_override(false);
+    }
+
+    let add_style = |cell: Cell, color: TableColor, bold: bool| -> Cell {
+        if !opts.color {
+            cell
+        } else {
+            let mut c = cell.fg(color);
+            if bold {
+                c = c.add_attribute(Attribute::Bold);
+            }
+            c
+        }
+    };
+
+    let tc_coral = TableColor::Rgb {
+        r: 255,
+        g: 160,
+        b: 122,
+    };
+    let tc_gold = TableColor::Rgb {
+        r: 255,
+        g: 215,
+        b: 0,
+    };
+    let tc_teal = TableColor::Rgb {
+        r: 95,
+        g: 158,
+        b: 160,
+    };
+    let tc_beige = TableColor::Rgb {
+        r: 230,
+        g: 228,
+        b: 208,
+    };
+
+    writeln!(
+        out,
+        "\n{}",
+        header_rule(opts.ascii).truecolor(95, 158, 160)
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "      {}",
+        rust_i18n::t!("cli.planner.header", profile = plan.profile)
+            .truecolor(255, 255, 255)
+            .bold()
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "      {}",
+        rust_i18n::t!("cli.planner.range", from = plan.from, to = plan.to).truecolor(255, 215, 0)
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "{}",
+        header_rule(opts.ascii).truecolor(95, 158, 160)
+    )
+    .unwrap();
+    writeln!(out).unwrap();
+
+    if plan.days.is_empty() {
+        writeln!(
+            out,
+            "  {}",
+            rust_i18n::t!("cli.planner.none").truecolor(230, 228, 208)
+        )
+        .unwrap();
+        return out;
+    }
+
+    let mut table = new_table(opts);
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            add_style(Cell::new(rust_i18n::t!("cli.planner.date").as_ref()), tc_coral, true),
+            add_style(Cell::new(rust_i18n::t!("cli.planner.score").as_ref()), tc_coral, true),
+            add_style(Cell::new(rust_i18n::t!("cli.planner.events").as_ref()), tc_coral, true),
+        ]);
+    for day in &plan.days {
+        let events = day
+            .events
+            .iter()
+            .map(|e| e.description.as_str())
+            .collect::<Vec<_>>()
+            .join("\n");
+        table.add_row(vec![
+            add_style(Cell::new(&day.date), tc_teal, false),
+            add_style(Cell::new(day.score), tc_gold, true),
+            add_style(Cell::new(&events), tc_beige, false),
+        ]);
+    }
+    writeln!(out, "{}", table).unwrap();
+
+    opts.symbols(&out)
+}
+
+/// Lunar cycle calendar of Moon gates and temporary definition
+pub fn render_lunar_cycle(cycle: &LunarCycle, opts: &RenderOptions) -> String {
+    let mut out = String::new();
+
+    if !opts.color {
+        colored::control::set_override(false);
+    }
+
+    let add_style = |cell: Cell, color: TableColor, bold: bool| -> Cell {
+        if !opts.color {
+            cell
+        } else {
+            let mut c = cell.fg(color);
+            if bold {
+                c = c.add_attribute(Attribute::Bold);
+            }
+            c
+        }
+    };
+
+    let tc_coral = TableColor::Rgb {
+        r: 255,
+        g: 160,
+        b: 122,
+    };
+    let tc_gold = TableColor::Rgb {
+        r: 255,
+        g: 215,
+        b: 0,
+    };
+    let tc_teal = TableColor::Rgb {
+        r: 95,
+        g: 158,
+        b: 160,
+    };
+    let tc_beige = TableColor::Rgb {
+        r: 230,
+        g: 228,
+        b: 208,
+    };
+
+    writeln!(
+        out,
+        "\n{}",
+        header_rule(opts.ascii).truecolor(95, 158, 160)
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "      {}",
+        rust_i18n::t!("cli.lunar.header", profile = cycle.profile)
+            .truecolor(255, 255, 255)
+            .bold()
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "      {}",
+        rust_i18n::t!("cli.lunar.range", from = cycle.from, to = cycle.to).truecolor(255, 215, 0)
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "{}",
+        header_rule(opts.ascii).truecolor(95, 158, 160)
+    )
+    .unwrap();
+    writeln!(out).unwrap();
+
+    if !cycle.reflector {
+        writeln!(
+            out,
+            "  {}\n",
+            rust_i18n::t!("cli.lunar.not_reflector").truecolor(230, 228, 208)
+        )
+        .unwrap();
+    }
+
+    let mut table = new_table(opts);
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            add_style(Cell::new(rust_i18n::t!("cli.lunar.day").as_ref()), tc_coral, true),
+            add_style(Cell::new(rust_i18n::t!("cli.lunar.start").as_ref()), tc_coral, true),
+            add_style(Cell::new(rust_i18n::t!("cli.lunar.gate").as_ref()), tc_coral, true),
+            add_style(Cell::new(rust_i18n::t!("cli.lunar.definition").as_ref()), tc_coral, true),
+        ]);
+    for g in &cycle.gates {
+        let gate = match &g.gate_name {
+            Some(name) => format!("{} {}", g.gate, name),
+            None => g.gate.to_string(),
+        };
+        let mut definition: Vec<String> = g
+            .channels
+            .iter()
+            .map(|c| rust_i18n::t!("cli.lunar.channel", channel = c.channel, natal = c.gate).to_string())
+            .collect();
+        if !g.centers.is_empty() {
+            definition.push(g.centers.join(", "));
+        }
+        let defined = !definition.is_empty();
+        table.add_row(vec![
+            add_style(Cell::new(g.day), tc_teal, false),
+            add_style(Cell::new(&g.start), tc_beige, false),
+            add_style(Cell::new(gate), if defined { tc_gold } else { tc_beige }, defined),
+            add_style(Cell::new(definition.join("\n")), tc_gold, false),
+        ]);
+    }
+    writeln!(out, "{}", table).unwrap();
+
+    opts.symbols(&out)
+}
+
+/// Sun gate and line ingresses over a date range
+pub fn render_sun_ingresses(ingresses: &[SunIngress], opts: &RenderOptions) -> String {
+    let mut out = String::new();
+
+    if !opts.color {
+        colored::control::set_override(false);
+    }
+
+    let add_style = |cell: Cell, color: TableColor, bold: bool| -> Cell {
+        if !opts.color {
+            cell
+        } else {
+            let mut c = cell.fg(color);
+            if bold {
+                c = c.add_attribute(Attribute::Bold);
+            }
+            c
+        }
+    };
+
+    let tc_coral = TableColor::Rgb {
+        r: 255,
+        g: 160,
+        b: 122,
+    };
+    let tc_gold = TableColor::Rgb {
+        r: 255,
+        g: 215,
+        b: 0,
+    };
+    let tc_beige = TableColor::Rgb {
+        r: 230,
+        g: 228,
+        b: 208,
+    };
+
+    writeln!(
+        out,
+        "\n{}",
+        header_rule(opts.ascii).truecolor(95, 158, 160)
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "      {}",
+        rust_i18n::t!("cli.ingresses.header")
+            .truecolor(255, 255, 255)
+            .bold()
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "{}",
+        header_rule(opts.ascii).truecolor(95, 158, 160)
+    )
+    .unwrap();
+    writeln!(out).unwrap();
+
+    if ingresses.is_empty() {
+        writeln!(
+            out,
+            "  {}",
+            rust_i18n::t!("cli.ingresses.none").truecolor(230, 228, 208)
+        )
+        .unwrap();
+        return out;
+    }
+
+    let mut table = new_table(opts);
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            add_style(Cell::new(rust_i18n::t!("cli.ingresses.moment").as_ref()), tc_coral, true),
+            add_style(Cell::new(rust_i18n::t!("cli.label.gate").as_ref()), tc_coral, true),
+            add_style(Cell::new(rust_i18n::t!("cli.label.name").as_ref()), tc_coral, true),
+        ]);
+    for i in ingresses {
+        table.add_row(vec![
+            add_style(Cell::new(&i.moment), tc_beige, false),
+            add_style(Cell::new(format!("{}.{}", i.gate, i.line)), tc_gold, true),
+            add_style(Cell::new(i.gate_name.as_deref().unwrap_or("")), tc_beige, false),
+        ]);
+    }
+    writeln!(out, "{}", table).unwrap();
+
+    opts.symbols(&out)
+}
+
+/// Upcoming solar returns of saved profiles within `days`
+pub fn render_solar_returns(returns: &[SolarReturn], days: u32, opts: &RenderOptions) -> String {
+    let mut out = String::new();
+
+    if !opts.color {
+        colored::control::set_override(false);
+    }
+
+    let add_style = |cell: Cell, color: TableColor, bold: bool| -> Cell {
+        if !opts.color {
+            cell
+        } else {
+            let mut c = cell.fg(color);
+            if bold {
+                c = c.add_attribute(Attribute::Bold);
+            }
+            c
+        }
+    };
+
+    let tc_coral = TableColor::Rgb {
+        r: 255,
+        g: 160,
+        b: 122,
+    };
+    let tc_gold = TableColor::Rgb {
+        r: 255,
+        g: 215,
+        b: 0,
+    };
+    let tc_beige = TableColor::Rgb {
+        r: 230,
+        g: 228,
+        b: 208,
+    };
+
+    writeln!(
+        out,
+        "\n{}",
+        header_rule(opts.ascii).truecolor(95, 158, 160)
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "      {}",
+        rust_i18n::t!("cli.birthdays.header", days = days)
+            .truecolor(255, 255, 255)
+            .bold()
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "{}",
+        header_rule(opts.ascii).truecolor(95, 158, 160)
+    )
+    .unwrap();
+    writeln!(out).unwrap();
+
+    if returns.is_empty() {
+        writeln!(
+            out,
+            "  {}",
+            rust_i18n::t!("cli.birthdays.none").truecolor(230, 228, 208)
+        )
+        .unwrap();
+        return out;
+    }
+
+    let mut table = new_table(opts);
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            add_style(Cell::new(rust_i18n::t!("cli.composite.member").as_ref()), tc_coral, true),
+            add_style(Cell::new(rust_i18n::t!("cli.birthdays.moment").as_ref()), tc_coral, true),
+            add_style(Cell::new(rust_i18n::t!("cli.birthdays.in").as_ref()), tc_coral, true),
+        ]);
+    for r in returns {
+        table.add_row(vec![
+            add_style(Cell::new(&r.profile), tc_gold, true),
+            add_style(Cell::new(&r.moment), tc_beige, false),
+            add_style(Cell::new(format_duration(r.days_until * 24.0)), tc_beige, false),
+        ]);
+    }
+    writeln!(out, "{}", table).unwrap();
+
+    opts.symbols(&out)
+}
+
+/// Gene Keys Activation Sequence
+pub fn render_genekeys(chart: &GeneKeysChart, opts: &RenderOptions) -> String {
+    let mut out = String::new();
+    apply_color_choice(opts);
+    let cell = |text: &str, color: TableColor, bold: bool| styled_cell(Cell::new(text), color, bold, opts);
+
+    writeln!(
+        out,
+        "\n{}",
+        header_rule(opts.ascii).truecolor(95, 158, 160)
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "      {}",
+        rust_i18n::t!("cli.genekeys.header", moment = chart.moment)
+            .truecolor(255, 255, 255)
+            .bold()
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "{}",
+        header_rule(opts.ascii).truecolor(95, 158, 160)
+    )
+    .unwrap();
+    writeln!(out).unwrap();
+
+    let mut table = new_table(opts);
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            cell(&rust_i18n::t!("cli.genekeys.sphere_label"), TC_CORAL, true),
+            cell(&rust_i18n::t!("cli.genekeys.key"), TC_CORAL, true),
+            cell(&rust_i18n::t!("planet.name_header"), TC_CORAL, true),
+            cell(&rust_i18n::t!("cli.genekeys.shadow"), TC_CORAL, true),
+            cell(&rust_i18n::t!("cli.genekeys.gift"), TC_CORAL, true),
+            cell(&rust_i18n::t!("cli.genekeys.siddhi"), TC_CORAL, true),
+        ]);
+    for sphere in &chart.spheres {
+        let side_key = format!("cli.label.{}", sphere.side);
+        table.add_row(vec![
+            cell(&sphere.name, TC_GOLD, true),
+            cell(&format!("{}.{}", sphere.gate, sphere.line), TC_GOLD, true),
+            cell(&format!("{} ({})", sphere.planet, rust_i18n::t!(&side_key)), TC_BEIGE, false),
+            cell(&sphere.shadow, TC_BEIGE, false),
+            cell(&sphere.gift, TC_BEIGE, false),
+            cell(&sphere.siddhi, TC_BEIGE, false),
+        ]);
+    }
+    writeln!(out, "{}", table).unwrap();
+
+    opts.symbols(&out)
+}
+
+/// Raw planetary longitudes at one moment
+pub fn render_ephemeris(ephemeris: &Ephemeris, opts: &RenderOptions) -> String {
+    let mut out = String::new();
+
+    if !opts.color {
+        colored::control::set_override(false);
+    }
+
+    let add_style = |cell: Cell, color: TableColor, bold: bool| -> Cell {
+        if !opts.color {
+            cell
+        } else {
+            let mut c = cell.fg(color);
+            if bold {
+                c = c.add_attribute(Attribute::Bold);
+            }
+            c
+        }
+    };
+
+    let tc_coral = TableColor::Rgb {
+        r: 255,
+        g: 160,
+        b: 122,
+    };
+    let tc_gold = TableColor::Rgb {
+        r: 255,
+        g: 215,
+        b: 0,
+    };
+    let tc_beige = TableColor::Rgb {
+        r: 230,
+        g: 228,
+        b: 208,
+    };
+
+    writeln!(
+        out,
+        "\n{}",
+        header_rule(opts.ascii).truecolor(95, 158, 160)
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "      {}",
+        rust_i18n::t!("cli.ephemeris.header", moment = ephemeris.moment)
+            .truecolor(255, 255, 255)
+            .bold()
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "{}",
+        header_rule(opts.ascii).truecolor(95, 158, 160)
+    )
+    .unwrap();
+    let positions_key = if ephemeris.apparent {
+        "cli.ephemeris.apparent"
+    } else {
+        "cli.ephemeris.true_positions"
+    };
+    writeln!(
+        out,
+        "  {}",
+        rust_i18n::t!(
+            "cli.ephemeris.settings",
+            jd = format!("{:.6}", ephemeris.julian_day),
+            engine = ephemeris.engine,
+            delta_t = format!("{:.1}", ephemeris.delta_t_seconds),
+            positions = rust_i18n::t!(positions_key)
+        )
+        .truecolor(230, 228, 208)
+    )
+    .unwrap();
+    writeln!(out).unwrap();
+
+    let with_gates = ephemeris.bodies.iter().any(|b| b.gate.is_some());
+    let mut headers = vec![
+        add_style(Cell::new(rust_i18n::t!("planet.name_header").as_ref()), tc_coral, true),
+        add_style(Cell::new(rust_i18n::t!("cli.ephemeris.longitude").as_ref()), tc_coral, true),
+        add_style(Cell::new(rust_i18n::t!("cli.ephemeris.speed").as_ref()), tc_coral, true),
+    ];
+    if with_gates {
+        headers.push(add_style(
+            Cell::new(format!(
+                "{}.{}",
+                rust_i18n::t!("cli.label.gate"),
+                rust_i18n::t!("cli.label.line")
+            )),
+            tc_coral,
+            true,
+        ));
+    }
+    let mut table = new_table(opts);
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(headers);
+    for body in &ephemeris.bodies {
+        let mut row = vec![
+            add_style(
+                Cell::new(format!("{} {}", opts.symbols(&body.planet_symbol), body.planet)),
+                tc_beige,
+                false,
+            ),
+            add_style(Cell::new(format!("{:.6}", body.longitude)), tc_gold, true),
+            add_style(Cell::new(format!("{:+.4}", body.speed_deg_per_day)), tc_beige, false),
+        ];
+        if let (Some(gate), Some(line)) = (body.gate, body.line) {
+            row.push(add_style(Cell::new(format!("{}.{}", gate, line)), tc_gold, true));
+        }
+        table.add_row(row);
+    }
+    writeln!(out, "{}", table).unwrap();
+
+    opts.symbols(&out)
+}
+
+/// Saturn, Uranus and Kiron life-cycle dates
+pub fn render_cycles(events: &[CycleEvent], opts: &RenderOptions) -> String {
+    let mut out = String::new();
+
+    if !opts.color {
+        colored::control::set_override(false);
+    }
+
+    let add_style = |cell: Cell, color: TableColor, bold: bool| -> Cell {
+        if !opts.color {
+            cell
+        } else {
+            let mut c = cell.fg(color);
+            if bold {
+                c = c.add_attribute(Attribute::Bold);
+            }
+            c
+        }
+    };
+
+    let tc_coral = TableColor::Rgb {
+        r: 255,
+        g: 160,
+        b: 122,
+    };
+    let tc_gold = TableColor::Rgb {
+        r: 255,
+        g: 215,
+        b: 0,
+    };
+    let tc_beige = TableColor::Rgb {
+        r: 230,
+        g: 228,
+        b: 208,
+    };
+
+    writeln!(
+        out,
+        "\n{}",
+        header_rule(opts.ascii).truecolor(95, 158, 160)
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "      {}",
+        rust_i18n::t!("cli.cycles.header")
+            .truecolor(255, 255, 255)
+            .bold()
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "{}",
+        header_rule(opts.ascii).truecolor(95, 158, 160)
+    )
+    .unwrap();
+    writeln!(out).unwrap();
+
+    let mut table = new_table(opts);
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            add_style(Cell::new(rust_i18n::t!("cli.cycles.cycle").as_ref()), tc_coral, true),
+            add_style(Cell::new(rust_i18n::t!("cli.cycles.moment").as_ref()), tc_coral, true),
+            add_style(Cell::new(rust_i18n::t!("cli.cycles.age").as_ref()), tc_coral, true),
+        ]);
+    for e in events {
+        let moment = if e.approximate {
+            format!("~{}", e.moment)
+        } else {
+            e.moment.clone()
+        };
+        table.add_row(vec![
+            add_style(Cell::new(&e.name), tc_gold, true),
+            add_style(Cell::new(moment), tc_beige, false),
+            add_style(Cell::new(format!("{:.1}", e.age)), tc_beige, false),
+        ]);
+    }
+    writeln!(out, "{}", table).unwrap();
+
+    if events.iter().any(|e| e.approximate) {
+        writeln!(
+            out,
+            "  {}",
+            rust_i18n::t!("cli.cycles.approximate").truecolor(230, 228, 208)
+        )
+        .unwrap();
+    }
+
+    opts.symbols(&out)
+}
+
+/// Pairwise connections of saved profiles
+pub fn render_matrix(matrix: &ProfileMatrix, opts: &RenderOptions) -> String {
+    let mut out = String::new();
+
+    if !opts.color {
+        colored::control::set_override(false);
+    }
+
+    let add_style = |cell: Cell, color: TableColor, bold: bool| -> Cell {
+        if !opts.color {
+            cell
+        } else {
+            let mut c = cell.fg(color);
+            if bold {
+                c = c.add_attribute(Attribute::Bold);
+            }
+            c
+        }
+    };
+
+    let tc_coral = TableColor::Rgb {
+        r: 255,
+        g: 160,
+        b: 122,
+    };
+    let tc_gold = TableColor::Rgb {
+        r: 255,
+        g: 215,
+        b: 0,
+    };
+    let tc_beige = TableColor::Rgb {
+        r: 230,
+        g: 228,
+        b: 208,
+    };
+
+    writeln!(
+        out,
+        "\n{}",
+        header_rule(opts.ascii).truecolor(95, 158, 160)
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "      {}",
+        rust_i18n::t!("cli.matrix.header", names = matrix.members.join(", "))
+            .truecolor(255, 255, 255)
+            .bold()
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "{}",
+        header_rule(opts.ascii).truecolor(95, 158, 160)
+    )
+    .unwrap();
+    writeln!(out).unwrap();
+
+    let mut header = vec![Cell::new("")];
+    header.extend(
+        matrix
+            .members
+            .iter()
+            .map(|name| add_style(Cell::new(name), tc_coral, true)),
+    );
+    let mut table = new_table(opts);
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(header);
+
+    for row in &matrix.members {
+        let mut cells = vec![add_style(Cell::new(row), tc_coral, true)];
+        for col in &matrix.members {
+            let cell = match matrix.pair(row, col) {
+                Some(p) if row != col => add_style(
+                    Cell::new(format!(
+                        "{}\n{}\n{}",
+                        rust_i18n::t!(
+                            "cli.matrix.channels",
+                            em = p.electromagnetic,
+                            dom = p.dominance
+                        ),
+                        p.hd_type,
+                        rust_i18n::t!("cli.matrix.centers", count = p.defined_centers)
+                    )),
+                    tc_gold,
+                    false,
+                ),
+                _ => add_style(Cell::new("—"), tc_beige, false),
+            };
+            cells.push(cell);
+        }
+        table.add_row(cells);
+    }
+    writeln!(out, "{}", table).unwrap();
+    writeln!(
+        out,
+        "\n{}",
+        rust_i18n::t!("cli.matrix.legend").truecolor(230, 228, 208)
+    )
+    .unwrap();
+
+    opts.symbols(&out)
+}
+
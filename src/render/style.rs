@@ -0,0 +1,110 @@
+//! Terminal text styles with exact RGB colors. `colored` downgrades RGB to the 16
+//! basic colors unless COLORTERM announces truecolor, so these styles write the RGB
+//! escapes themselves and leave the terminal's depth to `ColorDepth::adapt` when the
+//! output is printed. Whether to style at all still follows `colored::control`
+//! (`--color`, NO_COLOR, not a terminal).
+
+use colored::control::SHOULD_COLORIZE;
+pub use colored::Color;
+use std::fmt;
+
+const RESET: &str = "\x1B[0m";
+
+/// Text with a foreground color and attributes, written as ANSI escapes when displayed
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Painted {
+    text: String,
+    fg: Option<Color>,
+    bold: bool,
+    dimmed: bool,
+}
+
+impl Painted {
+    fn is_plain(&self) -> bool {
+        self.fg.is_none() && !self.bold && !self.dimmed
+    }
+
+    /// Opening escape, e.g. "\x1B[1;38;2;255;215;0m"
+    fn escape(&self) -> String {
+        let mut codes: Vec<String> = Vec::new();
+        if self.bold {
+            codes.push("1".to_string());
+        }
+        if self.dimmed {
+            codes.push("2".to_string());
+        }
+        match self.fg {
+            Some(Color::TrueColor { r, g, b }) => codes.push(format!("38;2;{};{};{}", r, g, b)),
+            Some(color) => codes.push(color.to_fg_str().into_owned()),
+            None => {}
+        }
+        format!("\x1B[{}m", codes.join(";"))
+    }
+}
+
+impl fmt::Display for Painted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_plain() || !SHOULD_COLORIZE.should_colorize() {
+            return <str as fmt::Display>::fmt(&self.text, f);
+        }
+        let escape = self.escape();
+        // Styled pieces inside the text end with a reset; restore this style after each
+        let text = self.text.replace(RESET, &format!("{}{}", RESET, escape));
+        f.write_str(&escape)?;
+        <str as fmt::Display>::fmt(&text, f)?;
+        f.write_str(RESET)
+    }
+}
+
+/// Styling methods for strings and already styled text
+pub trait Paint: Sized {
+    fn painted(self) -> Painted;
+
+    fn truecolor(self, r: u8, g: u8, b: u8) -> Painted {
+        self.color(Color::TrueColor { r, g, b })
+    }
+
+    fn color(self, color: Color) -> Painted {
+        Painted {
+            fg: Some(color),
+            ..self.painted()
+        }
+    }
+
+    fn bold(self) -> Painted {
+        Painted {
+            bold: true,
+            ..self.painted()
+        }
+    }
+
+    fn dimmed(self) -> Painted {
+        Painted {
+            dimmed: true,
+            ..self.painted()
+        }
+    }
+
+    /// Same text without any style
+    fn normal(self) -> Painted {
+        Painted {
+            text: self.painted().text,
+            ..Painted::default()
+        }
+    }
+}
+
+impl Paint for &str {
+    fn painted(self) -> Painted {
+        Painted {
+            text: self.to_string(),
+            ..Painted::default()
+        }
+    }
+}
+
+impl Paint for Painted {
+    fn painted(self) -> Painted {
+        self
+    }
+}
@@ -0,0 +1,243 @@
+/// Local HTTP server: JSON chart API and an optional bundled web page (std only, one thread per connection)
+use crate::astro_calc::{CalcOptions, HdPlanet, NodeModel};
+use clap::ValueEnum;
+use crate::data::database::DatabaseWatcher;
+use crate::models::HdChart;
+use crate::{calc, cli, site};
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::thread;
+use std::time::Duration;
+
+/// Bundled viewer served at `/` with `--ui`
+const UI_PAGE: &str = include_str!("ui.html");
+
+/// How often the `--db` file is checked for changes
+const DB_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Server settings (from CLI flags and config)
+#[derive(Debug, Clone)]
+pub struct ServeOptions {
+    /// Address to listen on, e.g. 127.0.0.1:8080
+    pub addr: String,
+    /// Serve the web page at `/` in addition to the API
+    pub ui: bool,
+    /// External database file for `lang`, reloaded when it changes on disk
+    pub db: Option<PathBuf>,
+    /// Database language of every chart (interface strings follow the process locale)
+    pub lang: String,
+    /// Calculation settings charts start from (`--engine`, `--node`, ...)
+    pub calc_opts: CalcOptions,
+}
+
+struct Response {
+    status: &'static str,
+    content_type: &'static str,
+    body: String,
+}
+
+impl Response {
+    fn ok(content_type: &'static str, body: String) -> Self {
+        Self {
+            status: "200 OK",
+            content_type,
+            body,
+        }
+    }
+
+    fn error(status: &'static str, message: &str) -> Self {
+        Self {
+            status,
+            content_type: "application/json",
+            body: serde_json::json!({ "error": message }).to_string(),
+        }
+    }
+}
+
+/// Listen until the process is stopped
+pub fn run(opts: &ServeOptions) -> Result<(), String> {
+    let listener = TcpListener::bind(&opts.addr).map_err(|e| e.to_string())?;
+    let addr = listener.local_addr().map_err(|e| e.to_string())?;
+    println!(
+        "{}",
+        rust_i18n::t!("serve.listening", url = format!("http://{}/", addr))
+    );
+    if opts.ui {
+        println!("{}", rust_i18n::t!("serve.ui"));
+    }
+    if let Some(path) = &opts.db {
+        watch_database(&opts.lang, path.clone())?;
+    }
+
+    for stream in listener.incoming().flatten() {
+        let opts = opts.clone();
+        thread::spawn(move || {
+            if let Err(e) = handle(stream, &opts) {
+                eprintln!("Error: {}", e);
+            }
+        });
+    }
+    Ok(())
+}
+
+/// Load `path` as the database for `lang` and reload it in the background when it changes.
+/// Requests already running keep the database they started with.
+fn watch_database(lang: &str, path: PathBuf) -> Result<(), String> {
+    let mut watcher = DatabaseWatcher::new();
+    watcher.watch(lang, path.clone())?;
+    println!(
+        "{}",
+        rust_i18n::t!("serve.db_watching", path = path.display().to_string())
+    );
+    thread::spawn(move || loop {
+        thread::sleep(DB_POLL_INTERVAL);
+        for (lang, result) in watcher.poll() {
+            match result {
+                Ok(()) => println!("{}", rust_i18n::t!("serve.db_reloaded", lang = lang)),
+                Err(e) => eprintln!("Error: {}", e),
+            }
+        }
+    });
+    Ok(())
+}
+
+fn handle(stream: TcpStream, opts: &ServeOptions) -> Result<(), String> {
+    let mut reader = BufReader::new(&stream);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).map_err(|e| e.to_string())?;
+    // Skip headers; the API has no request body
+    let mut header = String::new();
+    while reader.read_line(&mut header).map_err(|e| e.to_string())? > 2 {
+        header.clear();
+    }
+
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default();
+    let target = parts.next().unwrap_or("/");
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let params = parse_query(query);
+
+    let response = match (method, path) {
+        ("GET", "/api/chart") => match chart_from_params(&params, opts) {
+            Ok(chart) => match serde_json::to_string_pretty(&chart) {
+                Ok(json) => Response::ok("application/json", json),
+                Err(e) => Response::error("500 Internal Server Error", &e.to_string()),
+            },
+            Err(e) => Response::error("400 Bad Request", &e),
+        },
+        ("GET", "/api/bodygraph.svg") => match chart_from_params(&params, opts) {
+            Ok(chart) => Response::ok("image/svg+xml", site::bodygraph_svg(&chart)),
+            Err(e) => Response::error("400 Bad Request", &e),
+        },
+        ("GET", "/") | ("GET", "/index.html") if opts.ui => {
+            Response::ok("text/html; charset=utf-8", UI_PAGE.to_string())
+        }
+        ("GET", _) => Response::error("404 Not Found", "not found"),
+        _ => Response::error("405 Method Not Allowed", "method not allowed"),
+    };
+
+    let mut stream = &stream;
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        response.content_type,
+        response.body.len(),
+        response.body
+    )
+    .map_err(|e| e.to_string())
+}
+
+/// One chart request: birth moment and the calculation settings it runs with
+struct ChartQuery {
+    date: (i32, u8, u8),
+    time: (u8, u8, u8),
+    utc_offset: f64,
+    calc_opts: CalcOptions,
+}
+
+impl ChartQuery {
+    /// Read `date`, `time` and `utc` (same formats as the CLI) plus optional `lang` and `node`;
+    /// anything not passed falls back to the server settings. The locale is process-wide, so
+    /// `lang` may only repeat the server's language rather than switch it per request
+    fn from_params(params: &HashMap<String, String>, opts: &ServeOptions) -> Result<Self, String> {
+        let param = |name: &str| params.get(name).map(String::as_str).filter(|v| !v.is_empty());
+        let required = |name: &str| param(name).ok_or_else(|| format!("missing parameter '{}'", name));
+        let (year, month, day) = cli::parse_date(required("date")?)?;
+        let time = cli::parse_time(required("time")?)?;
+        let utc_offset = cli::parse_utc_offset(required("utc")?, year, month, day)?;
+
+        if let Some(lang) = param("lang") {
+            if !lang.eq_ignore_ascii_case(&opts.lang) {
+                return Err(format!(
+                    "parameter 'lang' must be '{}' (the server's language), got '{}'",
+                    opts.lang, lang
+                ));
+            }
+        }
+
+        let mut calc_opts = opts.calc_opts;
+        if let Some(node) = param("node") {
+            calc_opts.node = NodeModel::from_str(node, true)
+                .map_err(|_| format!("invalid value '{}' for parameter 'node'", node))?;
+        }
+        Ok(Self {
+            date: (year, month, day),
+            time,
+            utc_offset,
+            calc_opts,
+        })
+    }
+
+    fn build_chart(&self, lang: &str) -> Result<HdChart, String> {
+        let (year, month, day) = self.date;
+        let (hour, min, sec) = self.time;
+        calc::build_chart(
+            year, month, day, hour, min, sec, self.utc_offset,
+            &HdPlanet::all(), true, lang, &self.calc_opts,
+        )
+    }
+}
+
+/// Full chart for the request's query parameters
+fn chart_from_params(params: &HashMap<String, String>, opts: &ServeOptions) -> Result<HdChart, String> {
+    ChartQuery::from_params(params, opts)?.build_chart(&opts.lang)
+}
+
+fn parse_query(query: &str) -> HashMap<String, String> {
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (percent_decode(key), percent_decode(value))
+        })
+        .collect()
+}
+
+/// Decode `%XX` escapes and `+` as space (application/x-www-form-urlencoded)
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => out.push(b' '),
+            b'%' if i + 2 < bytes.len() => {
+                let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).ok();
+                match hex.and_then(|h| u8::from_str_radix(h, 16).ok()) {
+                    Some(b) => {
+                        out.push(b);
+                        i += 2;
+                    }
+                    None => out.push(b'%'),
+                }
+            }
+            b => out.push(b),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
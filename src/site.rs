@@ -0,0 +1,409 @@
+/// Static site for one chart: index.html, SVG bodygraph and the chart as JSON
+use crate::data::centers::Center;
+use crate::data::channels;
+use crate::models::HdChart;
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+const DESIGN_COLOR: &str = "#dc3c3c";
+const PERSONALITY_COLOR: &str = "#222222";
+const INACTIVE_COLOR: &str = "#d8d8d8";
+const OPEN_FILL: &str = "#ffffff";
+/// Distance between parallel channels connecting the same two centers
+const CHANNEL_SPACING: f64 = 10.0;
+
+/// Write `index.html`, `bodygraph.svg` and `chart.json` into `out_dir` (created if missing)
+pub fn write_site(chart: &HdChart, out_dir: &Path, ui_lang: &str) -> Result<(), String> {
+    fs::create_dir_all(out_dir).map_err(|e| e.to_string())?;
+    let json = serde_json::to_string_pretty(chart).map_err(|e| e.to_string())?;
+    for (name, content) in [
+        ("index.html", index_html(chart, ui_lang)),
+        ("bodygraph.svg", bodygraph_svg(chart)),
+        ("chart.json", json),
+    ] {
+        fs::write(out_dir.join(name), content).map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+/// Center position in the 400×600 bodygraph
+fn center_pos(center: &Center) -> (f64, f64) {
+    match center {
+        Center::Head => (200.0, 55.0),
+        Center::Ajna => (200.0, 140.0),
+        Center::Throat => (200.0, 235.0),
+        Center::G => (200.0, 330.0),
+        Center::Heart => (272.0, 372.0),
+        Center::Sacral => (200.0, 450.0),
+        Center::Spleen => (75.0, 430.0),
+        Center::SolarPlexus => (325.0, 430.0),
+        Center::Root => (200.0, 545.0),
+    }
+}
+
+/// Traditional fill of a defined center
+fn center_fill(center: &Center) -> &'static str {
+    match center {
+        Center::Head | Center::G => "#f2d256",
+        Center::Ajna => "#7fb069",
+        Center::Heart | Center::Sacral => "#e5484d",
+        Center::Throat | Center::Spleen | Center::SolarPlexus | Center::Root => "#b08968",
+    }
+}
+
+/// SVG points of the center's shape around its position
+fn center_points(center: &Center) -> Vec<(f64, f64)> {
+    let (x, y) = center_pos(center);
+    match center {
+        // Triangle pointing up
+        Center::Head => vec![(x, y - 32.0), (x - 34.0, y + 24.0), (x + 34.0, y + 24.0)],
+        // Triangle pointing down
+        Center::Ajna => vec![(x - 34.0, y - 24.0), (x + 34.0, y - 24.0), (x, y + 32.0)],
+        Center::G => vec![(x, y - 38.0), (x + 38.0, y), (x, y + 38.0), (x - 38.0, y)],
+        Center::Heart => vec![(x - 20.0, y + 16.0), (x + 20.0, y + 16.0), (x, y - 20.0)],
+        // Spleen points right, Solar Plexus points left
+        Center::Spleen => vec![(x - 26.0, y - 34.0), (x + 34.0, y), (x - 26.0, y + 34.0)],
+        Center::SolarPlexus => vec![(x + 26.0, y - 34.0), (x - 34.0, y), (x + 26.0, y + 34.0)],
+        Center::Throat | Center::Sacral | Center::Root => {
+            let h = 28.0;
+            vec![(x - h, y - h), (x + h, y - h), (x + h, y + h), (x - h, y + h)]
+        }
+    }
+}
+
+fn activation_stroke(activation: &str) -> &'static str {
+    match activation {
+        "design" => DESIGN_COLOR,
+        "personality" | "both" => PERSONALITY_COLOR,
+        _ => INACTIVE_COLOR,
+    }
+}
+
+/// Bodygraph: every channel drawn as two halves colored by their gate's activation
+/// (red Design, black Personality, black with red dashes for both), centers filled when defined
+pub fn bodygraph_svg(chart: &HdChart) -> String {
+    let activations: HashMap<u8, &str> = chart
+        .active_gates
+        .iter()
+        .map(|g| (g.gate, g.activation.as_str()))
+        .collect();
+    let defined: HashMap<Center, (&str, bool)> = chart
+        .centers
+        .iter()
+        .map(|c| (c.key, (c.name.as_str(), c.defined)))
+        .collect();
+
+    let mut out = String::new();
+    writeln!(
+        out,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 400 600" width="400" height="600" font-family="sans-serif">"#
+    )
+    .unwrap();
+
+    // Channels between the same two centers are drawn side by side
+    let all_channels = channels::unique_channels(channels::all_channels());
+    let pair = |a: Center, b: Center| if a.key() < b.key() { (a, b) } else { (b, a) };
+    let mut per_pair: HashMap<(Center, Center), usize> = HashMap::new();
+    for ch in &all_channels {
+        *per_pair.entry(pair(ch.center_a, ch.center_b)).or_default() += 1;
+    }
+    let mut drawn: HashMap<(Center, Center), usize> = HashMap::new();
+
+    for ch in &all_channels {
+        let key = pair(ch.center_a, ch.center_b);
+        let index = drawn.entry(key).or_default();
+        let offset = (*index as f64 - (per_pair[&key] - 1) as f64 / 2.0) * CHANNEL_SPACING;
+        *index += 1;
+
+        let (ax, ay) = center_pos(&ch.center_a);
+        let (bx, by) = center_pos(&ch.center_b);
+        let len = ((bx - ax).powi(2) + (by - ay).powi(2)).sqrt();
+        let (px, py) = (-(by - ay) / len * offset, (bx - ax) / len * offset);
+        let (ax, ay, bx, by) = (ax + px, ay + py, bx + px, by + py);
+        let (mx, my) = ((ax + bx) / 2.0, (ay + by) / 2.0);
+        for (gate, (x1, y1)) in [(ch.gate_a, (ax, ay)), (ch.gate_b, (bx, by))] {
+            let activation = activations.get(&gate).copied().unwrap_or("inactive");
+            writeln!(
+                out,
+                r#"  <line x1="{:.1}" y1="{:.1}" x2="{:.1}" y2="{:.1}" stroke="{}" stroke-width="6"><title>{}</title></line>"#,
+                x1, y1, mx, my, activation_stroke(activation), gate
+            )
+            .unwrap();
+            if activation == "both" {
+                writeln!(
+                    out,
+                    r#"  <line x1="{:.1}" y1="{:.1}" x2="{:.1}" y2="{:.1}" stroke="{}" stroke-width="6" stroke-dasharray="6 6"/>"#,
+                    x1, y1, mx, my, DESIGN_COLOR
+                )
+                .unwrap();
+            }
+        }
+    }
+
+    for center in Center::all() {
+        let (name, is_defined) = defined.get(center).copied().unwrap_or((center.key(), false));
+        let points: Vec<String> = center_points(center)
+            .iter()
+            .map(|(x, y)| format!("{:.1},{:.1}", x, y))
+            .collect();
+        writeln!(
+            out,
+            r##"  <polygon points="{}" fill="{}" stroke="#555555" stroke-width="2"><title>{}</title></polygon>"##,
+            points.join(" "),
+            if is_defined { center_fill(center) } else { OPEN_FILL },
+            escape(name)
+        )
+        .unwrap();
+    }
+
+    out.push_str("</svg>\n");
+    out
+}
+
+/// Minimal HTML escaping for text and attribute values
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Label without the trailing colon used in the terminal report
+fn label(key: &str) -> String {
+    rust_i18n::t!(key)
+        .trim_end()
+        .trim_end_matches([':', '：'])
+        .to_string()
+}
+
+fn index_html(chart: &HdChart, ui_lang: &str) -> String {
+    let mut out = String::new();
+    write_page_head(&mut out, chart, ui_lang);
+    write_main_info(&mut out, chart, false);
+    writeln!(out, r#"<img src="bodygraph.svg" alt="Bodygraph">"#).unwrap();
+    write_planets(&mut out, chart, false);
+
+    if !chart.channels.is_empty() {
+        writeln!(out, "<h2>{}</h2>\n<ul>", escape(&rust_i18n::t!("cli.section.channels"))).unwrap();
+        for ch in &chart.channels {
+            writeln!(out, "<li><strong>{}</strong> {}</li>", ch.key, escape(&ch.name)).unwrap();
+        }
+        writeln!(out, "</ul>").unwrap();
+    }
+
+    writeln!(out, "<h2>{}</h2>\n<ul>", escape(&rust_i18n::t!("cli.section.centers"))).unwrap();
+    for center in &chart.centers {
+        writeln!(out, "<li>{}: {}</li>", escape(&center.name), escape(&center_status(center.defined))).unwrap();
+    }
+    writeln!(out, "</ul>").unwrap();
+
+    writeln!(out, r#"<p><a href="chart.json">chart.json</a></p>"#).unwrap();
+    writeln!(out, "</body>\n</html>").unwrap();
+    out
+}
+
+/// Single-file report for `--format html`: the bodygraph inline, both sides'
+/// positions and every description folded into a collapsible section
+pub fn report_html(chart: &HdChart) -> String {
+    let mut out = String::new();
+    write_page_head(&mut out, chart, &rust_i18n::locale());
+    write_main_info(&mut out, chart, true);
+    out.push_str(&bodygraph_svg(chart));
+    write_planets(&mut out, chart, true);
+
+    writeln!(out, "<h2>{}</h2>", escape(&rust_i18n::t!("cli.section.active_gates"))).unwrap();
+    for p in chart.personality.iter().chain(chart.design.iter()) {
+        let side = if p.activation == "design" { " class=\"design\"" } else { "" };
+        write!(
+            out,
+            "<details><summary><span{}>{} {}.{}</span> {}</summary>",
+            side,
+            escape(&p.planet_symbol),
+            p.gate,
+            p.line,
+            escape(p.gate_name.as_deref().unwrap_or(""))
+        )
+        .unwrap();
+        for text in [&p.gate_keynote, &p.gate_description, &p.line_description]
+            .into_iter()
+            .flatten()
+            .filter(|t| !t.is_empty())
+        {
+            write!(out, "<p>{}</p>", escape(text)).unwrap();
+        }
+        writeln!(out, "</details>").unwrap();
+    }
+
+    if !chart.channels.is_empty() {
+        writeln!(out, "<h2>{}</h2>", escape(&rust_i18n::t!("cli.section.channels"))).unwrap();
+        for ch in &chart.channels {
+            write_details(
+                &mut out,
+                &format!("<strong>{}</strong> {}", ch.key, escape(&ch.name)),
+                [&ch.description],
+            );
+        }
+    }
+
+    writeln!(out, "<h2>{}</h2>", escape(&rust_i18n::t!("cli.section.centers"))).unwrap();
+    for center in &chart.centers {
+        write_details(
+            &mut out,
+            &format!("{}: {}", escape(&center.name), escape(&center_status(center.defined))),
+            [
+                &center.behavior_normal,
+                &center.behavior_distorted,
+                &center.not_self_question,
+                &center.wisdom,
+            ],
+        );
+    }
+
+    writeln!(out, "</body>\n</html>").unwrap();
+    out
+}
+
+/// Doctype, styles and the title block with the birth moment
+fn write_page_head(out: &mut String, chart: &HdChart, lang: &str) {
+    let title = format!(
+        "{} — {} {}",
+        rust_i18n::t!("cli.header"),
+        chart.birth_date,
+        chart.birth_time
+    );
+    writeln!(
+        out,
+        r#"<!DOCTYPE html>
+<html lang="{}">
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<meta name="robots" content="noindex">
+<title>{}</title>
+<style>
+body {{ font-family: sans-serif; max-width: 52rem; margin: 2rem auto; padding: 0 1rem; color: #222; line-height: 1.5; }}
+dl {{ display: grid; grid-template-columns: max-content 1fr; gap: .25rem 1rem; }}
+dt {{ color: #c0603f; }}
+img, svg {{ display: block; max-width: 100%; height: auto; margin: 1.5rem auto; }}
+table {{ border-collapse: collapse; width: 100%; }}
+td, th {{ border-bottom: 1px solid #ddd; padding: .3rem .5rem; text-align: left; }}
+details {{ border-bottom: 1px solid #ddd; padding: .3rem 0; }}
+summary {{ cursor: pointer; }}
+.design {{ color: {}; }}
+</style>
+</head>
+<body>
+<h1>{}</h1>
+<p>{} {} UTC{:+}</p>"#,
+        escape(lang),
+        escape(&title),
+        DESIGN_COLOR,
+        escape(&rust_i18n::t!("cli.header")),
+        chart.birth_date,
+        chart.birth_time,
+        chart.utc_offset
+    )
+    .unwrap();
+}
+
+/// Type, profile, authority, strategy and cross; descriptions inline or, when
+/// `collapsible`, folded under the value
+fn write_main_info(out: &mut String, chart: &HdChart, collapsible: bool) {
+    writeln!(out, "<dl>").unwrap();
+    for (key, value, description) in [
+        ("cli.label.type", &chart.hd_type, &chart.type_description),
+        ("cli.label.profile", &chart.profile, &chart.profile_description),
+        ("cli.label.authority", &chart.authority, &chart.authority_description),
+        ("cli.label.strategy", &chart.strategy, &chart.strategy_description),
+        ("cli.label.cross", &chart.incarnation_cross, &chart.cross_description),
+    ] {
+        write!(out, "<dt>{}</dt><dd>", escape(&label(key))).unwrap();
+        let value = format!("<strong>{}</strong>", escape(value));
+        if collapsible {
+            write_details(out, &value, [description]);
+        } else {
+            out.push_str(&value);
+            if let Some(text) = description.as_deref().filter(|d| !d.is_empty()) {
+                write!(out, "<br>{}", escape(text)).unwrap();
+            }
+        }
+        writeln!(out, "</dd>").unwrap();
+    }
+    if collapsible {
+        writeln!(
+            out,
+            "<dt>{}</dt><dd>{} UTC</dd>",
+            escape(&label("cli.label.design_date")),
+            chart.design_utc
+        )
+        .unwrap();
+    }
+    writeln!(out, "</dl>").unwrap();
+}
+
+/// Design and Personality gate.line per planet; `positions` adds the zodiac
+/// position of each side
+fn write_planets(out: &mut String, chart: &HdChart, positions: bool) {
+    let position = |p: &crate::models::PlanetPosition| {
+        if positions {
+            format!(
+                " <small>{} {:.2}°{}</small>",
+                escape(&p.zodiac_symbol),
+                p.zodiac_degree,
+                if p.retrograde { " ℞" } else { "" }
+            )
+        } else {
+            String::new()
+        }
+    };
+    writeln!(
+        out,
+        "<h2>{}</h2>\n<table>\n<tr><th class=\"design\">{}</th><th>{}</th><th>{}</th></tr>",
+        escape(&rust_i18n::t!("cli.section.planets")),
+        escape(&rust_i18n::t!("cli.label.design")),
+        escape(&rust_i18n::t!("planet.name_header")),
+        escape(&rust_i18n::t!("cli.label.personality"))
+    )
+    .unwrap();
+    for (des, pers) in chart.design.iter().zip(chart.personality.iter()) {
+        writeln!(
+            out,
+            "<tr><td class=\"design\">{}.{}{}</td><td>{} {}</td><td>{}.{}{}</td></tr>",
+            des.gate,
+            des.line,
+            position(des),
+            escape(&pers.planet_symbol),
+            escape(&pers.planet),
+            pers.gate,
+            pers.line,
+            position(pers)
+        )
+        .unwrap();
+    }
+    writeln!(out, "</table>").unwrap();
+}
+
+/// `<details>` with an HTML summary and one paragraph per non-empty text; a
+/// plain line when there is nothing to unfold
+fn write_details<const N: usize>(out: &mut String, summary: &str, texts: [&Option<String>; N]) {
+    let texts: Vec<&String> = texts.into_iter().flatten().filter(|t| !t.is_empty()).collect();
+    if texts.is_empty() {
+        writeln!(out, "<p>{}</p>", summary).unwrap();
+        return;
+    }
+    write!(out, "<details><summary>{}</summary>", summary).unwrap();
+    for text in texts {
+        write!(out, "<p>{}</p>", escape(text)).unwrap();
+    }
+    writeln!(out, "</details>").unwrap();
+}
+
+fn center_status(defined: bool) -> std::borrow::Cow<'static, str> {
+    if defined {
+        rust_i18n::t!("cli.label.defined")
+    } else {
+        rust_i18n::t!("cli.label.open")
+    }
+}
@@ -0,0 +1,107 @@
+//! Swiss Ephemeris engine (`swisseph` feature). Links the system `libswe`
+//! (`SWISSEPH_LIB_DIR` points the build at a non-standard location); ephemeris files
+//! are looked up in `SE_EPHE_PATH`, without them libswe falls back to its built-in
+//! Moshier series, still more precise than the default engine.
+
+use crate::astro_calc::{normalize_deg, AstroCrateEngine, AstroEngine, CalcOptions, HdPlanet, NodeModel, PlanetCalcResult};
+use std::os::raw::{c_char, c_double, c_int};
+use std::sync::Mutex;
+
+const SE_SUN: c_int = 0;
+const SE_MOON: c_int = 1;
+const SE_MERCURY: c_int = 2;
+const SE_VENUS: c_int = 3;
+const SE_MARS: c_int = 4;
+const SE_JUPITER: c_int = 5;
+const SE_SATURN: c_int = 6;
+const SE_URANUS: c_int = 7;
+const SE_NEPTUNE: c_int = 8;
+const SE_PLUTO: c_int = 9;
+const SE_MEAN_NODE: c_int = 10;
+const SE_TRUE_NODE: c_int = 11;
+const SE_MEAN_APOG: c_int = 12;
+const SE_CHIRON: c_int = 15;
+const SEFLG_SWIEPH: c_int = 2;
+const SEFLG_TRUEPOS: c_int = 16;
+const SEFLG_NONUT: c_int = 64;
+
+/// Size of the libswe error message buffer (AS_MAXCH)
+const SE_ERR_LEN: usize = 256;
+
+#[link(name = "swe")]
+extern "C" {
+    fn swe_calc_ut(tjd_ut: c_double, ipl: c_int, iflag: c_int, xx: *mut c_double, serr: *mut c_char) -> c_int;
+    fn swe_calc(tjd_et: c_double, ipl: c_int, iflag: c_int, xx: *mut c_double, serr: *mut c_char) -> c_int;
+}
+
+/// libswe keeps global state (open files, caches) and is not reentrant
+static SWE_LOCK: Mutex<()> = Mutex::new(());
+
+/// Swiss Ephemeris engine
+pub struct SwissEphEngine;
+
+impl SwissEphEngine {
+    /// Ecliptic longitude of a libswe body, `None` when libswe reports an error
+    fn longitude(&self, jd: f64, body: c_int, calc_opts: &CalcOptions) -> Option<f64> {
+        let mut xx = [0.0 as c_double; 6];
+        let mut serr = [0 as c_char; SE_ERR_LEN];
+        // libswe positions are apparent by default
+        let iflag = if calc_opts.apparent {
+            SEFLG_SWIEPH
+        } else {
+            SEFLG_SWIEPH | SEFLG_TRUEPOS | SEFLG_NONUT
+        };
+        let _guard = SWE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        // SAFETY: `xx` holds the 6 doubles and `serr` the AS_MAXCH chars libswe writes
+        let flag = unsafe {
+            if calc_opts.delta_t {
+                // libswe applies its own (IERS based) ΔT
+                swe_calc_ut(jd, body, iflag, xx.as_mut_ptr(), serr.as_mut_ptr())
+            } else {
+                swe_calc(jd, body, iflag, xx.as_mut_ptr(), serr.as_mut_ptr())
+            }
+        };
+        (flag >= 0).then_some(xx[0])
+    }
+}
+
+impl AstroEngine for SwissEphEngine {
+    fn positions(&self, jd: f64, bodies: &[HdPlanet], calc_opts: &CalcOptions) -> Vec<PlanetCalcResult> {
+        let node = match calc_opts.node {
+            NodeModel::Mean => SE_MEAN_NODE,
+            NodeModel::True => SE_TRUE_NODE,
+        };
+        bodies
+            .iter()
+            .map(|&body| {
+                // Earth and South Node are the opposite points of Sun and North Node
+                let (se_body, offset) = match body {
+                    HdPlanet::Sun => (SE_SUN, 0.0),
+                    HdPlanet::Earth => (SE_SUN, 180.0),
+                    HdPlanet::Moon => (SE_MOON, 0.0),
+                    HdPlanet::NorthNode => (node, 0.0),
+                    HdPlanet::SouthNode => (node, 180.0),
+                    HdPlanet::Mercury => (SE_MERCURY, 0.0),
+                    HdPlanet::Venus => (SE_VENUS, 0.0),
+                    HdPlanet::Mars => (SE_MARS, 0.0),
+                    HdPlanet::Jupiter => (SE_JUPITER, 0.0),
+                    HdPlanet::Saturn => (SE_SATURN, 0.0),
+                    HdPlanet::Uranus => (SE_URANUS, 0.0),
+                    HdPlanet::Neptune => (SE_NEPTUNE, 0.0),
+                    HdPlanet::Pluto => (SE_PLUTO, 0.0),
+                    HdPlanet::Chiron => (SE_CHIRON, 0.0),
+                    HdPlanet::Lilith => (SE_MEAN_APOG, 0.0),
+                };
+                let ecliptic_lng = match self.longitude(jd, se_body, calc_opts) {
+                    Some(lng) => normalize_deg(lng + offset),
+                    // e.g. Chiron without the asteroid ephemeris file
+                    None => AstroCrateEngine.positions(jd, &[body], calc_opts)[0].ecliptic_lng,
+                };
+                PlanetCalcResult {
+                    planet: body,
+                    ecliptic_lng,
+                }
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,32 @@
+/// User templates (`--template`): the chart rendered through a Tera template,
+/// with every `HdChart` field available by its JSON name
+use crate::models::HdChart;
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+use tera::{Context, Tera};
+
+/// Render `chart` through the Tera template at `path`
+pub fn render_template(chart: &HdChart, path: &Path) -> Result<String, String> {
+    let name = path.display().to_string();
+    let source = fs::read_to_string(path).map_err(|e| {
+        rust_i18n::t!("error.template_read", path = name, error = e.to_string()).to_string()
+    })?;
+    let context = Context::from_serialize(chart).map_err(|e| template_error(&e))?;
+
+    let mut tera = Tera::default();
+    tera.add_raw_template(&name, &source).map_err(|e| template_error(&e))?;
+    tera.render(&name, &context).map_err(|e| template_error(&e))
+}
+
+/// Tera reports the cause (syntax error position, unknown variable, failed
+/// filter) in the error's source chain; join it into one message
+fn template_error(error: &tera::Error) -> String {
+    let mut causes = vec![error.to_string()];
+    let mut source = error.source();
+    while let Some(cause) = source {
+        causes.push(cause.to_string());
+        source = cause.source();
+    }
+    rust_i18n::t!("error.template", error = causes.join(": ")).to_string()
+}
@@ -0,0 +1,611 @@
+/// Transit engine: current planetary activations compared to a natal chart
+use crate::astro_calc::{self, CalcOptions, HdPlanet};
+use crate::calc;
+use crate::data::centers::Center;
+use crate::data::channels::{self, ChannelDef};
+use crate::data::database;
+use crate::data::gates;
+use crate::models::*;
+use std::collections::HashSet;
+
+/// Give up searching for a gate boundary after this many days (slow outer planets)
+const MAX_SCAN_DAYS: f64 = 3660.0;
+
+/// Boundary search precision (~1 minute)
+const BOUNDARY_PRECISION_DAYS: f64 = 1.0 / 1440.0;
+
+/// Solar search tolerance in degrees: aim this far past a gate boundary to land inside the gate
+const SUN_SEARCH_TOLERANCE_DEG: f64 = 0.0002;
+
+/// Length of the lunar cycle a Reflector waits out before deciding
+const LUNAR_CYCLE_DAYS: f64 = 28.5;
+
+/// Mean year length used for ages
+const DAYS_PER_YEAR: f64 = 365.2425;
+
+/// Mean orbital period of Chiron in years: it is not in the ephemeris, so its return is estimated
+const CHIRON_PERIOD_YEARS: f64 = 50.7;
+
+/// A life-cycle milestone: `planet` at `angle` degrees from its natal position,
+/// searched between `from_age` and `to_age` years
+struct CycleDef {
+    key: &'static str,
+    planet: HdPlanet,
+    angle: f64,
+    from_age: f64,
+    to_age: f64,
+}
+
+const LIFE_CYCLES: [CycleDef; 3] = [
+    CycleDef { key: "saturn_return", planet: HdPlanet::Saturn, angle: 0.0, from_age: 26.0, to_age: 32.0 },
+    CycleDef { key: "uranus_opposition", planet: HdPlanet::Uranus, angle: 180.0, from_age: 36.0, to_age: 46.0 },
+    CycleDef { key: "second_saturn_return", planet: HdPlanet::Saturn, angle: 0.0, from_age: 55.0, to_age: 62.0 },
+];
+
+/// Planner weights: a completed channel outranks an authority center, which outranks the Sun
+const CHANNEL_WEIGHT: u32 = 3;
+const AUTHORITY_CENTER_WEIGHT: u32 = 2;
+const SUN_WEIGHT: u32 = 1;
+
+/// Centers that can carry inner authority
+const AUTHORITY_CENTERS: [Center; 5] = [
+    Center::SolarPlexus,
+    Center::Sacral,
+    Center::Spleen,
+    Center::Heart,
+    Center::G,
+];
+
+/// Scan step per planet: small enough never to jump over a whole gate
+fn scan_step_days(planet: HdPlanet) -> f64 {
+    match planet {
+        HdPlanet::Moon => 0.05,
+        HdPlanet::Mercury => 0.25,
+        HdPlanet::Sun | HdPlanet::Earth | HdPlanet::Venus => 0.5,
+        HdPlanet::Mars => 1.0,
+        HdPlanet::NorthNode | HdPlanet::SouthNode | HdPlanet::Lilith => 5.0,
+        HdPlanet::Jupiter => 5.0,
+        HdPlanet::Saturn => 10.0,
+        HdPlanet::Uranus | HdPlanet::Neptune | HdPlanet::Pluto | HdPlanet::Chiron => 20.0,
+    }
+}
+
+fn planet_gate_at(planet: HdPlanet, jd: f64, calc_opts: &CalcOptions) -> u8 {
+    let pos = &astro_calc::positions_for(jd, &[planet], calc_opts)[0];
+    gates::degree_to_gate(pos.ecliptic_lng, calc_opts.wheel_shift(jd)).gate
+}
+
+/// Find the moment the planet crosses out of its current gate,
+/// scanning forward (`direction` = 1.0) or backward (`direction` = -1.0)
+pub fn find_gate_boundary(planet: HdPlanet, jd: f64, direction: f64, calc_opts: &CalcOptions) -> Option<f64> {
+    let gate = planet_gate_at(planet, jd, calc_opts);
+    let step = scan_step_days(planet) * direction;
+
+    let mut inside = jd;
+    let mut outside = jd;
+    loop {
+        outside += step;
+        if (outside - jd).abs() > MAX_SCAN_DAYS {
+            return None;
+        }
+        if planet_gate_at(planet, outside, calc_opts) != gate {
+            break;
+        }
+        inside = outside;
+    }
+
+    // Bisection between the last sample inside the gate and the first outside
+    while (outside - inside).abs() > BOUNDARY_PRECISION_DAYS {
+        let mid = (inside + outside) / 2.0;
+        if planet_gate_at(planet, mid, calc_opts) == gate {
+            inside = mid;
+        } else {
+            outside = mid;
+        }
+    }
+
+    Some((inside + outside) / 2.0)
+}
+
+/// Time window (start, end) during which the planet stays in its current gate
+pub fn gate_window(planet: HdPlanet, jd: f64, calc_opts: &CalcOptions) -> (Option<f64>, Option<f64>) {
+    (
+        find_gate_boundary(planet, jd, -1.0, calc_opts),
+        find_gate_boundary(planet, jd, 1.0, calc_opts),
+    )
+}
+
+/// Signed distance of the planet from `target` degrees, in -180..180
+fn angle_from(planet: HdPlanet, jd: f64, target: f64, calc_opts: &CalcOptions) -> f64 {
+    let lng = astro_calc::positions_for(jd, &[planet], calc_opts)[0].ecliptic_lng;
+    (lng - target + 180.0).rem_euclid(360.0) - 180.0
+}
+
+/// First moment between `from_jd` and `to_jd` when the planet reaches `target` degrees
+/// (a retrograde loop may bring it back to the same degree twice more)
+pub fn find_longitude_crossing(
+    planet: HdPlanet,
+    target: f64,
+    from_jd: f64,
+    to_jd: f64,
+    calc_opts: &CalcOptions,
+) -> Option<f64> {
+    let step = scan_step_days(planet);
+    let mut before = from_jd;
+    let mut before_angle = angle_from(planet, before, target, calc_opts);
+    while before < to_jd {
+        let after = before + step;
+        let after_angle = angle_from(planet, after, target, calc_opts);
+        // A sign change across the target, not the jump at the opposite point
+        if before_angle.signum() != after_angle.signum() && (before_angle - after_angle).abs() < 180.0 {
+            let (mut inside, mut outside) = (before, after);
+            while outside - inside > BOUNDARY_PRECISION_DAYS {
+                let mid = (inside + outside) / 2.0;
+                if angle_from(planet, mid, target, calc_opts).signum() == before_angle.signum() {
+                    inside = mid;
+                } else {
+                    outside = mid;
+                }
+            }
+            return Some((inside + outside) / 2.0);
+        }
+        before = after;
+        before_angle = after_angle;
+    }
+    None
+}
+
+fn cycle_event(key: &str, birth_jd: f64, jd: f64, approximate: bool) -> CycleEvent {
+    let name_key = format!("cli.cycle.{}.name", key);
+    CycleEvent {
+        key: key.to_string(),
+        name: rust_i18n::t!(&name_key).to_string(),
+        moment: if approximate { format_date(jd) } else { format_jd(jd) },
+        julian_day: jd,
+        age: ((jd - birth_jd) / DAYS_PER_YEAR * 10.0).round() / 10.0,
+        approximate,
+    }
+}
+
+/// Dates of the Saturn return, Uranus opposition, Kiron return and second Saturn
+/// return for someone born at `birth_jd`, in chronological order
+pub fn life_cycle_dates(birth_jd: f64, calc_opts: &CalcOptions) -> Vec<CycleEvent> {
+    let mut events: Vec<CycleEvent> = LIFE_CYCLES
+        .iter()
+        .filter_map(|def| {
+            let natal = astro_calc::positions_for(birth_jd, &[def.planet], calc_opts)[0].ecliptic_lng;
+            let jd = find_longitude_crossing(
+                def.planet,
+                natal + def.angle,
+                birth_jd + def.from_age * DAYS_PER_YEAR,
+                birth_jd + def.to_age * DAYS_PER_YEAR,
+                calc_opts,
+            )?;
+            Some(cycle_event(def.key, birth_jd, jd, false))
+        })
+        .collect();
+    let kiron_jd = birth_jd + CHIRON_PERIOD_YEARS * DAYS_PER_YEAR;
+    events.push(cycle_event("kiron_return", birth_jd, kiron_jd, true));
+    events.sort_by(|a, b| a.julian_day.total_cmp(&b.julian_day));
+    events
+}
+
+fn format_jd(jd: f64) -> String {
+    astro_calc::jd_to_utc(jd).format("%Y-%m-%d %H:%M").to_string()
+}
+
+fn format_date(jd: f64) -> String {
+    astro_calc::jd_to_utc(jd).format("%Y-%m-%d").to_string()
+}
+
+fn gate_set(activations: &[(HdPlanet, gates::GatePosition)]) -> Vec<u8> {
+    let mut set: Vec<u8> = activations.iter().map(|(_, gp)| gp.gate).collect();
+    set.sort();
+    set.dedup();
+    set
+}
+
+fn active_channels(active_gates: &[u8]) -> Vec<ChannelDef> {
+    channels::unique_channels(channels::find_active_channels(active_gates))
+}
+
+/// Compare a natal chart (by its Personality JD) against transits at `transit_jd`
+pub fn build_transit_report(
+    profile_name: &str,
+    natal_jd: f64,
+    transit_jd: f64,
+    lang: &str,
+    calc_opts: &CalcOptions,
+) -> Result<TransitReport, String> {
+    let db = &*database::get_database(lang);
+
+    let (pers_gates, des_gates) = calc::natal_activations(natal_jd, &HdPlanet::all(), calc_opts)?;
+    let mut natal_gates = gate_set(&pers_gates);
+    natal_gates.extend(gate_set(&des_gates));
+    natal_gates.sort();
+    natal_gates.dedup();
+
+    let transit = calc::gate_activations(transit_jd, &HdPlanet::all(), calc_opts);
+    let transit_gates = gate_set(&transit);
+
+    let mut combined_gates = natal_gates.clone();
+    combined_gates.extend(transit_gates.iter().copied());
+    combined_gates.sort();
+    combined_gates.dedup();
+
+    let natal_channels = active_channels(&natal_gates);
+    let natal_keys: HashSet<String> = natal_channels.iter().map(|ch| ch.key()).collect();
+    let new_channels: Vec<ChannelDef> = active_channels(&combined_gates)
+        .into_iter()
+        .filter(|ch| !natal_keys.contains(&ch.key()))
+        .collect();
+
+    let planets_in_gate = |gate: u8| -> Vec<String> {
+        transit
+            .iter()
+            .filter(|(_, gp)| gp.gate == gate)
+            .map(|(p, _)| format!("{} {}", p.symbol(), p.name()))
+            .collect()
+    };
+
+    // Hanging gates completed: one side natal, the other brought by transit
+    let mut completed_gates = Vec::new();
+    for ch in &new_channels {
+        for (natal_gate, transit_gate) in [(ch.gate_a, ch.gate_b), (ch.gate_b, ch.gate_a)] {
+            if natal_gates.contains(&natal_gate) && !natal_gates.contains(&transit_gate) {
+                completed_gates.push(TransitCompletion {
+                    channel: ch.key(),
+                    channel_name: calc::channel_name(db, ch),
+                    natal_gate,
+                    transit_gate,
+                    planets: planets_in_gate(transit_gate),
+                });
+            }
+        }
+    }
+
+    // Open centers temporarily defined by the new channels
+    let natal_defined = calc::find_defined_centers(&natal_channels);
+    let mut all_channels = natal_channels.clone();
+    all_channels.extend(new_channels.iter().cloned());
+    let combined_defined = calc::find_defined_centers(&all_channels);
+
+    let temporary_centers: Vec<TransitCenter> = Center::all()
+        .iter()
+        .filter(|c| combined_defined.contains(c) && !natal_defined.contains(c))
+        .map(|c| TransitCenter {
+            name: db
+                .centers
+                .get(c.key())
+                .map(|d| d.name.clone())
+                .unwrap_or_else(|| c.key().to_string()),
+            channels: new_channels
+                .iter()
+                .filter(|ch| ch.center_a == *c || ch.center_b == *c)
+                .map(|ch| ch.key())
+                .collect(),
+        })
+        .collect();
+
+    // Transit planets responsible for the new channels, with their gate windows
+    let new_channel_gates: HashSet<u8> = new_channels
+        .iter()
+        .flat_map(|ch| [ch.gate_a, ch.gate_b])
+        .filter(|g| !natal_gates.contains(g))
+        .collect();
+
+    let activations: Vec<TransitActivation> = transit
+        .iter()
+        .filter(|(_, gp)| new_channel_gates.contains(&gp.gate))
+        .map(|(planet, gp)| {
+            let (start, end) = gate_window(*planet, transit_jd, calc_opts);
+            let duration_hours = match (start, end) {
+                (Some(s), Some(e)) => Some(((e - s) * 24.0 * 10.0).round() / 10.0),
+                _ => None,
+            };
+            TransitActivation {
+                planet: planet.name(),
+                planet_symbol: planet.symbol(),
+                gate: gp.gate,
+                line: gp.line,
+                starts: start.map(format_jd),
+                ends: end.map(format_jd),
+                duration_hours,
+            }
+        })
+        .collect();
+
+    Ok(TransitReport {
+        profile: profile_name.to_string(),
+        moment: format_jd(transit_jd),
+        moon_phase: calc::moon_phase(transit_jd, calc_opts),
+        completed_gates,
+        temporary_centers,
+        activations,
+    })
+}
+
+/// Rank the days of `[from_jd, from_jd + days)`, each sampled at 12:00 UTC, by transits
+/// that matter for the natal chart: hanging gates completed into channels, natally open
+/// authority centers defined, and the Sun passing through a natal gate.
+/// Only days with at least one event are kept, highest score first.
+pub fn build_transit_plan(
+    profile_name: &str,
+    natal_jd: f64,
+    from_jd: f64,
+    days: u32,
+    lang: &str,
+    calc_opts: &CalcOptions,
+) -> Result<TransitPlan, String> {
+    let db = &*database::get_database(lang);
+
+    let (pers_gates, des_gates) = calc::natal_activations(natal_jd, &HdPlanet::all(), calc_opts)?;
+    let mut natal_gates = gate_set(&pers_gates);
+    natal_gates.extend(gate_set(&des_gates));
+    natal_gates.sort();
+    natal_gates.dedup();
+
+    let natal_channels = active_channels(&natal_gates);
+    let natal_keys: HashSet<String> = natal_channels.iter().map(|ch| ch.key()).collect();
+    let natal_defined = calc::find_defined_centers(&natal_channels);
+
+    let center_name = |c: &Center| {
+        db.centers
+            .get(c.key())
+            .map(|d| d.name.clone())
+            .unwrap_or_else(|| c.key().to_string())
+    };
+
+    // JD integers fall on noon UTC
+    let start = from_jd.round();
+    let mut plan_days = Vec::new();
+    for day in 0..days {
+        let jd = start + day as f64;
+        let transit = calc::gate_activations(jd, &HdPlanet::all(), calc_opts);
+        let mut combined_gates = natal_gates.clone();
+        combined_gates.extend(gate_set(&transit));
+        combined_gates.sort();
+        combined_gates.dedup();
+
+        let new_channels: Vec<ChannelDef> = active_channels(&combined_gates)
+            .into_iter()
+            .filter(|ch| !natal_keys.contains(&ch.key()))
+            .collect();
+
+        let mut events = Vec::new();
+        for ch in &new_channels {
+            for (natal_gate, transit_gate) in [(ch.gate_a, ch.gate_b), (ch.gate_b, ch.gate_a)] {
+                if natal_gates.contains(&natal_gate) && !natal_gates.contains(&transit_gate) {
+                    events.push(PlannerEvent {
+                        kind: "channel".to_string(),
+                        weight: CHANNEL_WEIGHT,
+                        description: rust_i18n::t!(
+                            "cli.planner.channel",
+                            channel = ch.key(),
+                            name = calc::channel_name(db, ch),
+                            natal = natal_gate,
+                            transit = transit_gate
+                        )
+                        .to_string(),
+                    });
+                }
+            }
+        }
+
+        let mut all_channels = natal_channels.clone();
+        all_channels.extend(new_channels.iter().cloned());
+        let combined_defined = calc::find_defined_centers(&all_channels);
+        for center in AUTHORITY_CENTERS
+            .iter()
+            .filter(|c| combined_defined.contains(c) && !natal_defined.contains(c))
+        {
+            let channels: Vec<String> = new_channels
+                .iter()
+                .filter(|ch| ch.center_a == *center || ch.center_b == *center)
+                .map(|ch| ch.key())
+                .collect();
+            events.push(PlannerEvent {
+                kind: "center".to_string(),
+                weight: AUTHORITY_CENTER_WEIGHT,
+                description: rust_i18n::t!(
+                    "cli.planner.center",
+                    center = center_name(center),
+                    channels = channels.join(", ")
+                )
+                .to_string(),
+            });
+        }
+
+        if let Some((_, sun)) = transit
+            .iter()
+            .find(|(p, gp)| *p == HdPlanet::Sun && natal_gates.contains(&gp.gate))
+        {
+            events.push(PlannerEvent {
+                kind: "sun".to_string(),
+                weight: SUN_WEIGHT,
+                description: rust_i18n::t!("cli.planner.sun", gate = sun.gate, line = sun.line)
+                    .to_string(),
+            });
+        }
+
+        if !events.is_empty() {
+            plan_days.push(PlannerDay {
+                date: format_date(jd),
+                score: events.iter().map(|e| e.weight).sum(),
+                events,
+            });
+        }
+    }
+    // Stable sort keeps equal scores in chronological order
+    plan_days.sort_by_key(|day| std::cmp::Reverse(day.score));
+
+    Ok(TransitPlan {
+        profile: profile_name.to_string(),
+        from: format_date(start),
+        to: format_date(start + days.saturating_sub(1) as f64),
+        days: plan_days,
+    })
+}
+
+/// Moon gates over one lunar cycle from `from_jd`, with the channels the Moon
+/// completes with the natal gates and the centers it defines for a while
+pub fn build_lunar_cycle(
+    profile_name: &str,
+    natal_jd: f64,
+    from_jd: f64,
+    lang: &str,
+    calc_opts: &CalcOptions,
+) -> Result<LunarCycle, String> {
+    let db = &*database::get_database(lang);
+
+    let (pers_gates, des_gates) = calc::natal_activations(natal_jd, &HdPlanet::all(), calc_opts)?;
+    let mut natal_gates = gate_set(&pers_gates);
+    natal_gates.extend(gate_set(&des_gates));
+    natal_gates.sort();
+    natal_gates.dedup();
+
+    let natal_channels = active_channels(&natal_gates);
+    let natal_defined = calc::find_defined_centers(&natal_channels);
+
+    let end = from_jd + LUNAR_CYCLE_DAYS;
+    let mut gates = Vec::new();
+    let mut jd = from_jd;
+    while jd < end {
+        let gate = planet_gate_at(HdPlanet::Moon, jd, calc_opts);
+        // Channels the Moon gate completes against a natal partner gate
+        let completed: Vec<(ChannelDef, u8)> = if natal_gates.contains(&gate) {
+            Vec::new()
+        } else {
+            channels::unique_channels(channels::all_channels())
+                .into_iter()
+                .filter_map(|ch| {
+                    let natal = if ch.gate_a == gate { ch.gate_b } else { ch.gate_a };
+                    let touches = ch.gate_a == gate || ch.gate_b == gate;
+                    (touches && natal_gates.contains(&natal)).then_some((ch, natal))
+                })
+                .collect()
+        };
+
+        let mut all_channels = natal_channels.clone();
+        all_channels.extend(completed.iter().map(|(ch, _)| ch.clone()));
+        let defined = calc::find_defined_centers(&all_channels);
+        let centers = Center::all()
+            .iter()
+            .filter(|c| defined.contains(c) && !natal_defined.contains(c))
+            .map(|c| {
+                db.centers
+                    .get(c.key())
+                    .map(|d| d.name.clone())
+                    .unwrap_or_else(|| c.key().to_string())
+            })
+            .collect();
+
+        gates.push(LunarGate {
+            day: (jd - from_jd).floor() as u32 + 1,
+            start: format_jd(jd),
+            gate,
+            gate_name: db.gates.get(&gate.to_string()).map(|g| g.name.clone()),
+            channels: completed
+                .iter()
+                .map(|(ch, natal)| GateCompletion {
+                    gate: *natal,
+                    channel: ch.key(),
+                })
+                .collect(),
+            centers,
+        });
+
+        // Step just past the boundary so the next sample falls in the next gate
+        jd = match find_gate_boundary(HdPlanet::Moon, jd, 1.0, calc_opts) {
+            Some(next) => next + BOUNDARY_PRECISION_DAYS,
+            None => break,
+        };
+    }
+
+    Ok(LunarCycle {
+        profile: profile_name.to_string(),
+        from: format_jd(from_jd),
+        to: format_jd(end),
+        reflector: natal_channels.is_empty(),
+        gates,
+    })
+}
+
+/// Julian Day of the Rave New Year in `year`: the Sun entering Gate 41, the start of the wheel
+pub fn rave_new_year_jd(year: i32, calc_opts: &CalcOptions) -> f64 {
+    let new_year = astro_calc::calc_julian_day((year, 1, 1), (0, 0, 0), 0.0, calc_opts.calendar);
+    astro_calc::find_next_sun_longitude(
+        new_year,
+        gates::WHEEL_START_DEGREE + calc_opts.wheel_shift(new_year) + SUN_SEARCH_TOLERANCE_DEG,
+        calc_opts,
+    )
+}
+
+/// Every moment between `from_jd` and `to_jd` the Sun enters a new gate, or a new
+/// line with `lines`, solved exactly against the wheel boundaries (the Sun never retrogrades)
+pub fn sun_ingresses(
+    from_jd: f64,
+    to_jd: f64,
+    lines: bool,
+    lang: &str,
+    calc_opts: &CalcOptions,
+) -> Vec<SunIngress> {
+    let db = &*database::get_database(lang);
+    let step = if lines { gates::LINE_SIZE_DEG } else { gates::GATE_SIZE_DEG };
+
+    let mut ingresses = Vec::new();
+    let mut jd = from_jd;
+    loop {
+        // Sidereal wheels drift with precession; one shift per step keeps the
+        // boundary and the gate it leads into consistent
+        let wheel_shift = calc_opts.wheel_shift(jd);
+        let wheel_start = gates::WHEEL_START_DEGREE + wheel_shift;
+        let lng = astro_calc::positions_for(jd, &[HdPlanet::Sun], calc_opts)[0].ecliptic_lng;
+        let boundary = ((lng - wheel_start).rem_euclid(360.0) / step).floor() + 1.0;
+        jd = astro_calc::find_next_sun_longitude(
+            jd,
+            wheel_start + boundary * step + SUN_SEARCH_TOLERANCE_DEG,
+            calc_opts,
+        );
+        if jd >= to_jd {
+            break;
+        }
+        let sun = astro_calc::positions_for(jd, &[HdPlanet::Sun], calc_opts)[0].ecliptic_lng;
+        let gp = gates::degree_to_gate(sun, wheel_shift);
+        ingresses.push(SunIngress {
+            moment: format_jd(jd),
+            julian_day: jd,
+            gate: gp.gate,
+            line: gp.line,
+            gate_name: db.gates.get(&gp.gate.to_string()).map(|g| g.name.clone()),
+        });
+    }
+    ingresses
+}
+
+/// Solar returns (the Sun back at its natal longitude) of the given people within
+/// `days` after `from_jd`, soonest first
+pub fn upcoming_solar_returns(
+    members: &[(String, f64)],
+    from_jd: f64,
+    days: u32,
+    calc_opts: &CalcOptions,
+) -> Vec<SolarReturn> {
+    let mut returns: Vec<SolarReturn> = members
+        .iter()
+        .filter_map(|(name, natal_jd)| {
+            let natal_sun = astro_calc::positions_for(*natal_jd, &[HdPlanet::Sun], calc_opts)[0].ecliptic_lng;
+            let jd = astro_calc::find_next_sun_longitude(from_jd, natal_sun, calc_opts);
+            let days_until = jd - from_jd;
+            (days_until <= days as f64).then(|| SolarReturn {
+                profile: name.clone(),
+                moment: format_jd(jd),
+                julian_day: jd,
+                days_until: (days_until * 10.0).round() / 10.0,
+            })
+        })
+        .collect();
+    returns.sort_by(|a, b| a.julian_day.total_cmp(&b.julian_day));
+    returns
+}
+
@@ -0,0 +1,175 @@
+/// Self-update: fetch the latest GitHub release binary for this platform and swap it in
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const LATEST_RELEASE_URL: &str =
+    "https://api.github.com/repos/nimblemo/human-design-cli/releases/latest";
+/// Release asset with `<sha256>  <file name>` lines for every binary
+const CHECKSUMS_ASSET: &str = "SHA256SUMS";
+
+#[derive(Debug, Deserialize)]
+struct Release {
+    tag_name: String,
+    assets: Vec<Asset>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Asset {
+    name: String,
+    browser_download_url: String,
+}
+
+/// Result of a successful `self-update` run
+#[derive(Debug, Clone, PartialEq)]
+pub enum UpdateStatus {
+    /// Already on the latest release
+    UpToDate { version: String },
+    /// A newer release exists (check-only mode)
+    Available { current: String, latest: String },
+    /// The executable was replaced
+    Updated { from: String, to: String },
+}
+
+/// Release asset name for the running platform, e.g. `hd-cli-x86_64-linux`
+pub fn asset_name() -> String {
+    format!(
+        "hd-cli-{}-{}{}",
+        std::env::consts::ARCH,
+        std::env::consts::OS,
+        std::env::consts::EXE_SUFFIX
+    )
+}
+
+/// Check the latest release and, unless `check_only`, install it over the current executable
+pub fn run(check_only: bool) -> Result<UpdateStatus, String> {
+    let current = env!("CARGO_PKG_VERSION").to_string();
+    let release: Release = serde_json::from_slice(&download(LATEST_RELEASE_URL)?)
+        .map_err(|e| rust_i18n::t!("update.bad_release", error = e.to_string()).to_string())?;
+    let latest = release.tag_name.trim_start_matches('v').to_string();
+
+    if !is_newer(&latest, &current) {
+        return Ok(UpdateStatus::UpToDate { version: current });
+    }
+    if check_only {
+        return Ok(UpdateStatus::Available { current, latest });
+    }
+
+    let name = asset_name();
+    let find_asset = |asset_name: &str| {
+        release
+            .assets
+            .iter()
+            .find(|a| a.name == asset_name)
+            .ok_or_else(|| rust_i18n::t!("update.no_asset", name = asset_name).to_string())
+    };
+    let binary = download(&find_asset(&name)?.browser_download_url)?;
+    let sums = download(&find_asset(CHECKSUMS_ASSET)?.browser_download_url)?;
+
+    let expected = expected_checksum(&String::from_utf8_lossy(&sums), &name)
+        .ok_or_else(|| rust_i18n::t!("update.no_checksum", name = name).to_string())?;
+    let actual = sha256_hex(&binary);
+    if !actual.eq_ignore_ascii_case(&expected) {
+        return Err(rust_i18n::t!("update.checksum_mismatch", name = name).to_string());
+    }
+
+    let exe = std::env::current_exe().map_err(|e| e.to_string())?;
+    replace_executable(&exe, &binary)?;
+    Ok(UpdateStatus::Updated {
+        from: current,
+        to: latest,
+    })
+}
+
+/// Fetch a URL with curl (available on Windows 10+, macOS, Linux), like the build script
+fn download(url: &str) -> Result<Vec<u8>, String> {
+    let output = Command::new("curl")
+        .args([
+            "-fsSL",
+            "--connect-timeout",
+            "15",
+            "--max-time",
+            "300",
+            "-H",
+            "Accept: application/vnd.github+json, application/octet-stream",
+            url,
+        ])
+        .output()
+        .map_err(|e| rust_i18n::t!("update.curl_missing", error = e.to_string()).to_string())?;
+    if !output.status.success() {
+        return Err(rust_i18n::t!(
+            "update.download_failed",
+            url = url,
+            code = output.status.code().unwrap_or(-1)
+        )
+        .to_string());
+    }
+    Ok(output.stdout)
+}
+
+/// Numeric `major.minor.patch` comparison; pre-release suffixes are ignored
+fn is_newer(latest: &str, current: &str) -> bool {
+    let parse = |v: &str| -> Vec<u64> {
+        v.split(['-', '+'])
+            .next()
+            .unwrap_or("")
+            .split('.')
+            .map(|part| part.parse().unwrap_or(0))
+            .collect()
+    };
+    parse(latest) > parse(current)
+}
+
+/// Checksum for `name` from a `sha256sum`-style listing
+fn expected_checksum(sums: &str, name: &str) -> Option<String> {
+    sums.lines().find_map(|line| {
+        let mut parts = line.split_whitespace();
+        let hash = parts.next()?;
+        // `*name` marks binary mode in sha256sum output
+        let file = parts.next()?.trim_start_matches('*');
+        (file == name).then(|| hash.to_string())
+    })
+}
+
+fn sha256_hex(data: &[u8]) -> String {
+    Sha256::digest(data)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// Write the new binary next to `exe` and swap it in. The running executable is moved
+/// aside first, since Windows can't overwrite a file that is in use.
+fn replace_executable(exe: &Path, binary: &[u8]) -> Result<(), String> {
+    let with_suffix = |suffix: &str| -> PathBuf {
+        let mut name = exe.file_name().unwrap_or_default().to_os_string();
+        name.push(suffix);
+        exe.with_file_name(name)
+    };
+    let staged = with_suffix(".new");
+    let backup = with_suffix(".old");
+
+    fs::write(&staged, binary).map_err(|e| e.to_string())?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = fs::metadata(exe)
+            .map(|m| m.permissions().mode())
+            .unwrap_or(0o755);
+        fs::set_permissions(&staged, fs::Permissions::from_mode(mode)).map_err(|e| e.to_string())?;
+    }
+
+    let _ = fs::remove_file(&backup);
+    fs::rename(exe, &backup).map_err(|e| e.to_string())?;
+    if let Err(e) = fs::rename(&staged, exe) {
+        // Put the old binary back so the install stays usable
+        let _ = fs::rename(&backup, exe);
+        let _ = fs::remove_file(&staged);
+        return Err(e.to_string());
+    }
+    // Still locked on Windows while we run; the next update removes it
+    let _ = fs::remove_file(&backup);
+    Ok(())
+}